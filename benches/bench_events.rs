@@ -0,0 +1,63 @@
+#![feature(test)]
+
+#[cfg(test)]
+mod benches {
+    extern crate test;
+
+    use serde::Deserialize;
+    use serde_json::Value;
+    use serenity::model::event::GatewayEvent;
+
+    use self::test::Bencher;
+
+    const MESSAGE_CREATE: &str = r#"{
+        "op": 0,
+        "s": 42,
+        "t": "MESSAGE_CREATE",
+        "d": {
+            "id": "1031539564291411968",
+            "channel_id": "381880193700069377",
+            "guild_id": "381880193251409931",
+            "author": {
+                "id": "114941315417899012",
+                "username": "zeyla",
+                "discriminator": "5479",
+                "avatar": null,
+                "bot": false
+            },
+            "member": {
+                "roles": [],
+                "joined_at": "2017-11-19T21:00:00.000000+00:00",
+                "deaf": false,
+                "mute": false
+            },
+            "content": "hello world",
+            "timestamp": "2022-10-18T12:00:00.000000+00:00",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0
+        }
+    }"#;
+
+    #[bench]
+    fn message_create_through_value(b: &mut Bencher) {
+        b.iter(|| {
+            let value: Value = serde_json::from_str(MESSAGE_CREATE).unwrap();
+            GatewayEvent::deserialize(value).unwrap();
+        })
+    }
+
+    #[bench]
+    fn message_create_from_payload(b: &mut Bencher) {
+        b.iter(|| {
+            let mut payload = MESSAGE_CREATE.to_owned();
+            GatewayEvent::from_payload(&mut payload).unwrap();
+        })
+    }
+}
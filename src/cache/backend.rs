@@ -0,0 +1,261 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::hash::Hash;
+use std::ops::{Deref, DerefMut};
+
+use dashmap::DashMap;
+
+use crate::internal::prelude::*;
+use crate::model::guild::automod::Rule;
+use crate::model::prelude::*;
+
+/// A read guard to a value of a [`CacheMap`].
+pub type CacheRef<'a, V> = Box<dyn Deref<Target = V> + 'a>;
+
+/// A write guard to a value of a [`CacheMap`].
+pub type CacheRefMut<'a, V> = Box<dyn DerefMut<Target = V> + 'a>;
+
+/// A concurrent map storing one family of data of the [`Cache`], such as its
+/// guilds or users.
+///
+/// These are the operations the cache performs when processing events and in
+/// its accessors. Guards returned by a map may lock the entry - or more - so the
+/// cache never holds two guards to the same map at once.
+///
+/// [`Cache`]: super::Cache
+pub trait CacheMap<K, V>: fmt::Debug + Send + Sync {
+    /// Returns a read guard to the value of the key.
+    fn get(&self, key: &K) -> Option<CacheRef<'_, V>>;
+
+    /// Returns a write guard to the value of the key.
+    fn get_mut(&self, key: &K) -> Option<CacheRefMut<'_, V>>;
+
+    /// Returns a write guard to the value of the key, inserting the value
+    /// returned by `default` first if there is none.
+    fn get_or_insert_with(&self, key: K, default: &mut dyn FnMut() -> V) -> CacheRefMut<'_, V>;
+
+    /// Inserts a value, returning the previous value of the key.
+    fn insert(&self, key: K, value: V) -> Option<V>;
+
+    /// Removes the value of the key, returning it.
+    fn remove(&self, key: &K) -> Option<V>;
+
+    /// Whether the map contains a value for the key.
+    fn contains_key(&self, key: &K) -> bool;
+
+    /// The number of values in the map.
+    fn len(&self) -> usize;
+
+    /// Whether the map is empty.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Visits the entries of the map until `f` returns `true`, returning
+    /// whether it did.
+    fn any(&self, f: &mut dyn FnMut(&K, &V) -> bool) -> bool;
+
+    /// Visits the entries of the map mutably until `f` returns `true`,
+    /// returning whether it did.
+    fn any_mut(&self, f: &mut dyn FnMut(&K, &mut V) -> bool) -> bool;
+
+    /// Retains only the entries for which `f` returns `true`.
+    fn retain(&self, f: &mut dyn FnMut(&K, &mut V) -> bool);
+
+    /// Persists the map, if it is backed by storage outside the process.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the map could not be written to its storage.
+    fn flush(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<K, V> dyn CacheMap<K, V> + '_
+where
+    K: Clone,
+    V: Clone,
+{
+    /// Returns a write guard to the value of the key, inserting the default
+    /// value first if there is none.
+    pub fn get_or_default(&self, key: K) -> CacheRefMut<'_, V>
+    where
+        V: Default,
+    {
+        self.get_or_insert_with(key, &mut V::default)
+    }
+
+    /// Clones all keys of the map.
+    pub fn keys(&self) -> Vec<K> {
+        let mut keys = Vec::with_capacity(self.len());
+        self.any(&mut |k, _| {
+            keys.push(k.clone());
+            false
+        });
+
+        keys
+    }
+
+    /// Clones all entries of the map.
+    pub fn entries(&self) -> Vec<(K, V)> {
+        let mut entries = Vec::with_capacity(self.len());
+        self.any(&mut |k, v| {
+            entries.push((k.clone(), v.clone()));
+            false
+        });
+
+        entries
+    }
+
+    /// Clones all values of the map.
+    pub fn values(&self) -> Vec<V> {
+        let mut values = Vec::with_capacity(self.len());
+        self.any(&mut |_, v| {
+            values.push(v.clone());
+            false
+        });
+
+        values
+    }
+
+    /// Returns the first value returned by `f` for an entry of the map.
+    pub fn find_map<T>(&self, mut f: impl FnMut(&K, &V) -> Option<T>) -> Option<T> {
+        let mut found = None;
+        self.any(&mut |k, v| {
+            found = f(k, v);
+            found.is_some()
+        });
+
+        found
+    }
+}
+
+impl<K, V> CacheMap<K, V> for DashMap<K, V>
+where
+    K: Eq + Hash + fmt::Debug + Send + Sync,
+    V: fmt::Debug + Send + Sync,
+{
+    fn get(&self, key: &K) -> Option<CacheRef<'_, V>> {
+        DashMap::get(self, key).map(|v| Box::new(v) as CacheRef<'_, V>)
+    }
+
+    fn get_mut(&self, key: &K) -> Option<CacheRefMut<'_, V>> {
+        DashMap::get_mut(self, key).map(|v| Box::new(v) as CacheRefMut<'_, V>)
+    }
+
+    fn get_or_insert_with(&self, key: K, default: &mut dyn FnMut() -> V) -> CacheRefMut<'_, V> {
+        Box::new(self.entry(key).or_insert_with(default))
+    }
+
+    fn insert(&self, key: K, value: V) -> Option<V> {
+        DashMap::insert(self, key, value)
+    }
+
+    fn remove(&self, key: &K) -> Option<V> {
+        DashMap::remove(self, key).map(|(_, v)| v)
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        DashMap::contains_key(self, key)
+    }
+
+    fn len(&self) -> usize {
+        DashMap::len(self)
+    }
+
+    fn any(&self, f: &mut dyn FnMut(&K, &V) -> bool) -> bool {
+        self.iter().any(|entry| f(entry.key(), entry.value()))
+    }
+
+    fn any_mut(&self, f: &mut dyn FnMut(&K, &mut V) -> bool) -> bool {
+        self.iter_mut().any(|mut entry| {
+            let (k, v) = entry.pair_mut();
+            f(k, v)
+        })
+    }
+
+    fn retain(&self, f: &mut dyn FnMut(&K, &mut V) -> bool) {
+        DashMap::retain(self, f);
+    }
+}
+
+/// The storage of the [`Cache`], creating the map of each family of data.
+///
+/// Every method defaults to an in-memory [`DashMap`], so a backend only needs
+/// to override the families it stores elsewhere. The [`Cache`] creates its maps
+/// once, when it is built.
+///
+/// Refer to [`SnapshotCacheBackend`] for a backend persisting the cache to
+/// files.
+///
+/// [`Cache`]: super::Cache
+/// [`SnapshotCacheBackend`]: super::SnapshotCacheBackend
+pub trait CacheBackend: fmt::Debug + Send + Sync {
+    /// Creates the map of guild channels.
+    fn channels(&self) -> Box<dyn CacheMap<ChannelId, GuildChannel>> {
+        Box::new(DashMap::new())
+    }
+
+    /// Creates the map of channel categories.
+    fn categories(&self) -> Box<dyn CacheMap<ChannelId, ChannelCategory>> {
+        Box::new(DashMap::new())
+    }
+
+    /// Creates the map of guilds.
+    fn guilds(&self) -> Box<dyn CacheMap<GuildId, Guild>> {
+        Box::new(DashMap::new())
+    }
+
+    /// Creates the map of guilds' auto moderation rules.
+    fn automod_rules(&self) -> Box<dyn CacheMap<GuildId, HashMap<RuleId, Rule>>> {
+        Box::new(DashMap::new())
+    }
+
+    /// Creates the map of guilds' integrations.
+    fn integrations(&self) -> Box<dyn CacheMap<GuildId, HashMap<IntegrationId, Integration>>> {
+        Box::new(DashMap::new())
+    }
+
+    /// Creates the map of threads' members.
+    fn thread_members(&self) -> Box<dyn CacheMap<ChannelId, HashMap<UserId, ThreadMember>>> {
+        Box::new(DashMap::new())
+    }
+
+    /// Creates the map of channels' messages.
+    fn messages(&self) -> Box<dyn CacheMap<ChannelId, DashMap<MessageId, Message>>> {
+        Box::new(DashMap::new())
+    }
+
+    /// Creates the map of channels' message queues, ordering their cached
+    /// messages from oldest to newest.
+    fn message_queue(&self) -> Box<dyn CacheMap<ChannelId, VecDeque<MessageId>>> {
+        Box::new(DashMap::new())
+    }
+
+    /// Creates the map of users' presences.
+    fn presences(&self) -> Box<dyn CacheMap<UserId, Presence>> {
+        Box::new(DashMap::new())
+    }
+
+    /// Creates the map of private channels.
+    fn private_channels(&self) -> Box<dyn CacheMap<ChannelId, PrivateChannel>> {
+        Box::new(DashMap::with_capacity(128))
+    }
+
+    /// Creates the set of unavailable guilds.
+    fn unavailable_guilds(&self) -> Box<dyn CacheMap<GuildId, ()>> {
+        Box::new(DashMap::new())
+    }
+
+    /// Creates the map of users.
+    fn users(&self) -> Box<dyn CacheMap<UserId, User>> {
+        Box::new(DashMap::new())
+    }
+}
+
+/// The default [`CacheBackend`], keeping the cache in memory.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InMemoryCacheBackend;
+
+impl CacheBackend for InMemoryCacheBackend {}
@@ -1,12 +1,24 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use super::{Cache, CacheUpdate};
-use crate::model::channel::{Channel, GuildChannel, Message};
+use crate::model::channel::{
+    Channel,
+    GuildChannel,
+    Message,
+    MessageReaction,
+    ReactionType,
+    StageInstance,
+};
 use crate::model::event::{
+    AutoModerationRuleCreateEvent,
+    AutoModerationRuleDeleteEvent,
+    AutoModerationRuleUpdateEvent,
     ChannelCreateEvent,
     ChannelDeleteEvent,
     ChannelPinsUpdateEvent,
     ChannelUpdateEvent,
+    GuildBanAddEvent,
+    GuildBanRemoveEvent,
     GuildCreateEvent,
     GuildDeleteEvent,
     GuildEmojisUpdateEvent,
@@ -17,24 +29,75 @@ use crate::model::event::{
     GuildRoleCreateEvent,
     GuildRoleDeleteEvent,
     GuildRoleUpdateEvent,
+    GuildScheduledEventCreateEvent,
+    GuildScheduledEventDeleteEvent,
+    GuildScheduledEventUpdateEvent,
+    GuildScheduledEventUserAddEvent,
+    GuildScheduledEventUserRemoveEvent,
     GuildStickersUpdateEvent,
     GuildUnavailableEvent,
     GuildUpdateEvent,
+    IntegrationCreateEvent,
+    IntegrationDeleteEvent,
+    IntegrationUpdateEvent,
     MessageCreateEvent,
+    MessageDeleteBulkEvent,
+    MessageDeleteEvent,
     MessageUpdateEvent,
     PresenceUpdateEvent,
     PresencesReplaceEvent,
+    ReactionAddEvent,
+    ReactionRemoveAllEvent,
+    ReactionRemoveEmojiEvent,
+    ReactionRemoveEvent,
     ReadyEvent,
+    StageInstanceCreateEvent,
+    StageInstanceDeleteEvent,
+    StageInstanceUpdateEvent,
     ThreadCreateEvent,
     ThreadDeleteEvent,
+    ThreadListSyncEvent,
+    ThreadMemberUpdateEvent,
+    ThreadMembersUpdateEvent,
     ThreadUpdateEvent,
     UserUpdateEvent,
     VoiceStateUpdateEvent,
 };
-use crate::model::guild::{Guild, Member, Role};
+use crate::model::guild::automod::Rule;
+use crate::model::guild::{Guild, Integration, Member, Role, ScheduledEvent, ThreadMember};
 use crate::model::user::{CurrentUser, OnlineStatus};
 use crate::model::voice::VoiceState;
 
+impl CacheUpdate for AutoModerationRuleCreateEvent {
+    type Output = Rule;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        cache
+            .automod_rules
+            .get_or_default(self.rule.guild_id)
+            .insert(self.rule.id, self.rule.clone())
+    }
+}
+
+impl CacheUpdate for AutoModerationRuleUpdateEvent {
+    type Output = Rule;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        cache
+            .automod_rules
+            .get_or_default(self.rule.guild_id)
+            .insert(self.rule.id, self.rule.clone())
+    }
+}
+
+impl CacheUpdate for AutoModerationRuleDeleteEvent {
+    type Output = Rule;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        cache.automod_rules.get_mut(&self.rule.guild_id)?.remove(&self.rule.id)
+    }
+}
+
 impl CacheUpdate for ChannelCreateEvent {
     type Output = Channel;
 
@@ -178,10 +241,34 @@ impl CacheUpdate for ChannelPinsUpdateEvent {
     }
 }
 
+impl CacheUpdate for GuildBanAddEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        // The member is removed by the `GuildMemberRemoveEvent` sent along
+        // with the ban.
+        cache.update_user_entry(&self.user);
+
+        None
+    }
+}
+
+impl CacheUpdate for GuildBanRemoveEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        cache.update_user_entry(&self.user);
+
+        None
+    }
+}
+
 impl CacheUpdate for GuildCreateEvent {
     type Output = ();
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
+        let settings = cache.settings();
+
         cache.unavailable_guilds.remove(&self.guild.id);
         let mut guild = self.guild.clone();
 
@@ -192,6 +279,28 @@ impl CacheUpdate for GuildCreateEvent {
             }
         }
 
+        // Drop the families of data that are not cached.
+        if !settings.cache_members {
+            guild.members = HashMap::new();
+        }
+
+        if !settings.cache_presences {
+            guild.presences = HashMap::new();
+        }
+
+        if !settings.cache_voice_states {
+            guild.voice_states = HashMap::new();
+        }
+
+        if !settings.cache_emojis {
+            guild.emojis = HashMap::new();
+            guild.stickers = HashMap::new();
+        }
+
+        if !settings.cache_threads {
+            guild.threads = Vec::new();
+        }
+
         for pair in guild.channels.clone() {
             if let Channel::Guild(channel) = pair.1 {
                 cache.channels.insert(pair.0, channel);
@@ -216,7 +325,7 @@ impl CacheUpdate for GuildDeleteEvent {
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         match cache.guilds.remove(&self.guild.id) {
             Some(guild) => {
-                for (channel_id, channel) in &guild.1.channels {
+                for (channel_id, channel) in &guild.channels {
                     match channel {
                         Channel::Guild(_) => {
                             // Remove the channel from the cache.
@@ -233,7 +342,14 @@ impl CacheUpdate for GuildDeleteEvent {
                     }
                 }
 
-                Some(guild.1)
+                for thread in &guild.threads {
+                    cache.thread_members.remove(&thread.id);
+                }
+
+                cache.automod_rules.remove(&self.guild.id);
+                cache.integrations.remove(&self.guild.id);
+
+                Some(guild)
             },
             None => None,
         }
@@ -244,6 +360,10 @@ impl CacheUpdate for GuildEmojisUpdateEvent {
     type Output = ();
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
+        if !cache.settings().cache_emojis {
+            return None;
+        }
+
         if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
             guild.emojis.clone_from(&self.emojis);
         }
@@ -264,7 +384,10 @@ impl CacheUpdate for GuildMemberAddEvent {
 
         if let Some(mut guild) = cache.guilds.get_mut(&self.member.guild_id) {
             guild.member_count += 1;
-            guild.members.insert(user_id, self.member.clone());
+
+            if cache.settings().cache_members {
+                guild.members.insert(user_id, self.member.clone());
+            }
         }
 
         None
@@ -290,6 +413,10 @@ impl CacheUpdate for GuildMemberUpdateEvent {
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         cache.update_user_entry(&self.user);
 
+        if !cache.settings().cache_members {
+            return None;
+        }
+
         if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
             let item = if let Some(member) = guild.members.get_mut(&self.user.id) {
                 let item = Some(member.clone());
@@ -342,6 +469,10 @@ impl CacheUpdate for GuildMembersChunkEvent {
             cache.update_user_entry(&member.user);
         }
 
+        if !cache.settings().cache_members {
+            return None;
+        }
+
         if let Some(mut g) = cache.guilds.get_mut(&self.guild_id) {
             g.members.extend(self.members.clone());
         }
@@ -385,10 +516,88 @@ impl CacheUpdate for GuildRoleUpdateEvent {
     }
 }
 
+impl CacheUpdate for GuildScheduledEventCreateEvent {
+    type Output = ScheduledEvent;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        let mut guild = cache.guilds.get_mut(&self.event.guild_id)?;
+        upsert_scheduled_event(&mut guild.scheduled_events, &self.event)
+    }
+}
+
+impl CacheUpdate for GuildScheduledEventUpdateEvent {
+    type Output = ScheduledEvent;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        let mut guild = cache.guilds.get_mut(&self.event.guild_id)?;
+        upsert_scheduled_event(&mut guild.scheduled_events, &self.event)
+    }
+}
+
+impl CacheUpdate for GuildScheduledEventDeleteEvent {
+    type Output = ScheduledEvent;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        let mut guild = cache.guilds.get_mut(&self.event.guild_id)?;
+        let index = guild.scheduled_events.iter().position(|e| e.id == self.event.id)?;
+
+        Some(guild.scheduled_events.remove(index))
+    }
+}
+
+impl CacheUpdate for GuildScheduledEventUserAddEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        let mut guild = cache.guilds.get_mut(&self.guild_id)?;
+        let event = guild.scheduled_events.iter_mut().find(|e| e.id == self.scheduled_event_id)?;
+
+        if let Some(count) = event.user_count.as_mut() {
+            *count += 1;
+        }
+
+        None
+    }
+}
+
+impl CacheUpdate for GuildScheduledEventUserRemoveEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        let mut guild = cache.guilds.get_mut(&self.guild_id)?;
+        let event = guild.scheduled_events.iter_mut().find(|e| e.id == self.scheduled_event_id)?;
+
+        if let Some(count) = event.user_count.as_mut() {
+            *count = count.saturating_sub(1);
+        }
+
+        None
+    }
+}
+
+/// Replaces the scheduled event with the same id, returning it, or adds the
+/// event if there is none.
+fn upsert_scheduled_event(
+    events: &mut Vec<ScheduledEvent>,
+    event: &ScheduledEvent,
+) -> Option<ScheduledEvent> {
+    if let Some(old) = events.iter_mut().find(|e| e.id == event.id) {
+        return Some(std::mem::replace(old, event.clone()));
+    }
+
+    events.push(event.clone());
+
+    None
+}
+
 impl CacheUpdate for GuildStickersUpdateEvent {
     type Output = ();
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
+        if !cache.settings().cache_emojis {
+            return None;
+        }
+
         if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
             guild.stickers.clone_from(&self.stickers);
         }
@@ -401,7 +610,7 @@ impl CacheUpdate for GuildUnavailableEvent {
     type Output = ();
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
-        cache.unavailable_guilds.insert(self.guild_id);
+        cache.unavailable_guilds.insert(self.guild_id, ());
         cache.guilds.remove(&self.guild_id);
 
         None
@@ -446,21 +655,50 @@ impl CacheUpdate for GuildUpdateEvent {
     }
 }
 
+impl CacheUpdate for IntegrationCreateEvent {
+    type Output = Integration;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        cache
+            .integrations
+            .get_or_default(self.integration.guild_id)
+            .insert(self.integration.id, self.integration.clone())
+    }
+}
+
+impl CacheUpdate for IntegrationUpdateEvent {
+    type Output = Integration;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        cache
+            .integrations
+            .get_or_default(self.integration.guild_id)
+            .insert(self.integration.id, self.integration.clone())
+    }
+}
+
+impl CacheUpdate for IntegrationDeleteEvent {
+    type Output = Integration;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        cache.integrations.get_mut(&self.guild_id)?.remove(&self.id)
+    }
+}
+
 impl CacheUpdate for MessageCreateEvent {
     /// The oldest message, if the channel's message cache was already full.
     type Output = Message;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        let max = cache.settings().max_messages;
+        let settings = cache.settings();
+        let max = settings.max_messages;
 
-        if max == 0 {
+        if max == 0 || !settings.cache_messages {
             return None;
         }
 
-        let messages =
-            cache.messages.entry(self.message.channel_id).or_insert_with(Default::default);
-        let mut queue =
-            cache.message_queue.entry(self.message.channel_id).or_insert_with(Default::default);
+        let messages = cache.messages.get_or_default(self.message.channel_id);
+        let mut queue = cache.message_queue.get_or_default(self.message.channel_id);
 
         let mut removed_msg = None;
 
@@ -477,6 +715,34 @@ impl CacheUpdate for MessageCreateEvent {
     }
 }
 
+impl CacheUpdate for MessageDeleteBulkEvent {
+    /// The deleted messages that were cached.
+    type Output = Vec<Message>;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        let messages = cache.messages.get(&self.channel_id)?;
+
+        if let Some(mut queue) = cache.message_queue.get_mut(&self.channel_id) {
+            queue.retain(|id| !self.ids.contains(id));
+        }
+
+        Some(self.ids.iter().filter_map(|id| messages.remove(id)).map(|(_, msg)| msg).collect())
+    }
+}
+
+impl CacheUpdate for MessageDeleteEvent {
+    /// The deleted message, if it was cached.
+    type Output = Message;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        if let Some(mut queue) = cache.message_queue.get_mut(&self.channel_id) {
+            queue.retain(|id| *id != self.message_id);
+        }
+
+        cache.messages.get(&self.channel_id)?.remove(&self.message_id).map(|(_, msg)| msg)
+    }
+}
+
 impl CacheUpdate for MessageUpdateEvent {
     type Output = Message;
 
@@ -520,6 +786,8 @@ impl CacheUpdate for PresenceUpdateEvent {
     type Output = ();
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
+        let settings = cache.settings();
+
         if let Some(user) = self.presence.user.to_user() {
             cache.update_user_entry(&user);
         }
@@ -528,19 +796,23 @@ impl CacheUpdate for PresenceUpdateEvent {
             self.presence.user.update_with_user(user);
         }
 
+        let user_id = self.presence.user.id;
+        let online = self.presence.status != OnlineStatus::Offline;
+
         if let Some(guild_id) = self.presence.guild_id {
             if let Some(mut guild) = cache.guilds.get_mut(&guild_id) {
-                // If the member went offline, remove them from the presence list.
-                if self.presence.status == OnlineStatus::Offline {
-                    guild.presences.remove(&self.presence.user.id);
+                // If the member went offline, or presences are not cached, remove
+                // them from the presence list.
+                if !settings.cache_presences || !online {
+                    guild.presences.remove(&user_id);
                 } else {
-                    guild.presences.insert(self.presence.user.id, self.presence.clone());
+                    guild.presences.insert(user_id, self.presence.clone());
                 }
 
                 // Create a partial member instance out of the presence update
                 // data.
-                if let Some(user) = self.presence.user.to_user() {
-                    guild.members.entry(self.presence.user.id).or_insert_with(|| Member {
+                if let (true, Some(user)) = (settings.cache_members, self.presence.user.to_user()) {
+                    guild.members.entry(user_id).or_insert_with(|| Member {
                         deaf: false,
                         guild_id,
                         joined_at: None,
@@ -556,10 +828,10 @@ impl CacheUpdate for PresenceUpdateEvent {
                     });
                 }
             }
-        } else if self.presence.status == OnlineStatus::Offline {
-            cache.presences.remove(&self.presence.user.id);
+        } else if !settings.cache_presences || !online {
+            cache.presences.remove(&user_id);
         } else {
-            cache.presences.insert(self.presence.user.id, self.presence.clone());
+            cache.presences.insert(user_id, self.presence.clone());
         }
 
         None
@@ -570,6 +842,10 @@ impl CacheUpdate for PresencesReplaceEvent {
     type Output = ();
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
+        if !cache.settings().cache_presences {
+            return None;
+        }
+
         for presence in &self.presences {
             cache.presences.insert(presence.user.id, presence.clone());
         }
@@ -578,6 +854,102 @@ impl CacheUpdate for PresencesReplaceEvent {
     }
 }
 
+/// Whether two reactions are of the same emoji. Custom emojis are compared by
+/// id only, as their names may change.
+fn same_reaction(a: &ReactionType, b: &ReactionType) -> bool {
+    match (a, b) {
+        (
+            ReactionType::Custom {
+                id: a, ..
+            },
+            ReactionType::Custom {
+                id: b, ..
+            },
+        ) => a == b,
+        (ReactionType::Unicode(a), ReactionType::Unicode(b)) => a == b,
+        _ => false,
+    }
+}
+
+impl CacheUpdate for ReactionAddEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        let me = self.reaction.user_id == Some(cache.current_user_id());
+        let messages = cache.messages.get(&self.reaction.channel_id)?;
+        let mut message = messages.get_mut(&self.reaction.message_id)?;
+
+        if let Some(reaction) = message
+            .reactions
+            .iter_mut()
+            .find(|r| same_reaction(&r.reaction_type, &self.reaction.emoji))
+        {
+            reaction.count += 1;
+            reaction.me |= me;
+        } else {
+            message.reactions.push(MessageReaction {
+                count: 1,
+                me,
+                reaction_type: self.reaction.emoji.clone(),
+            });
+        }
+
+        None
+    }
+}
+
+impl CacheUpdate for ReactionRemoveEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        let me = self.reaction.user_id == Some(cache.current_user_id());
+        let messages = cache.messages.get(&self.reaction.channel_id)?;
+        let mut message = messages.get_mut(&self.reaction.message_id)?;
+
+        let index = message
+            .reactions
+            .iter()
+            .position(|r| same_reaction(&r.reaction_type, &self.reaction.emoji))?;
+        let reaction = &mut message.reactions[index];
+
+        reaction.count = reaction.count.saturating_sub(1);
+
+        if me {
+            reaction.me = false;
+        }
+
+        if reaction.count == 0 {
+            message.reactions.remove(index);
+        }
+
+        None
+    }
+}
+
+impl CacheUpdate for ReactionRemoveAllEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        let messages = cache.messages.get(&self.channel_id)?;
+        messages.get_mut(&self.message_id)?.reactions.clear();
+
+        None
+    }
+}
+
+impl CacheUpdate for ReactionRemoveEmojiEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        let messages = cache.messages.get(&self.reaction.channel_id)?;
+        let mut message = messages.get_mut(&self.reaction.message_id)?;
+
+        message.reactions.retain(|r| !same_reaction(&r.reaction_type, &self.reaction.emoji));
+
+        None
+    }
+}
+
 impl CacheUpdate for ReadyEvent {
     type Output = ();
 
@@ -586,7 +958,7 @@ impl CacheUpdate for ReadyEvent {
 
         for unavailable in ready.guilds {
             cache.guilds.remove(&unavailable.id);
-            cache.unavailable_guilds.insert(unavailable.id);
+            cache.unavailable_guilds.insert(unavailable.id, ());
         }
 
         // We may be removed from some guilds between disconnect and ready, so we should handle that.
@@ -594,15 +966,16 @@ impl CacheUpdate for ReadyEvent {
         let ready_guilds_hashset =
             self.ready.guilds.iter().map(|status| status.id).collect::<HashSet<_>>();
         let shard_data = self.ready.shard.unwrap_or([1, 1]);
-        for guild_entry in cache.guilds.iter() {
-            let guild = guild_entry.key();
+        cache.guilds.any(&mut |guild, _| {
             // Only handle data for our shard.
             if crate::utils::shard_id(guild.0, shard_data[1]) == shard_data[0]
                 && !ready_guilds_hashset.contains(guild)
             {
                 guilds_to_remove.push(*guild);
             }
-        }
+
+            false
+        });
         if !guilds_to_remove.is_empty() {
             for guild in guilds_to_remove {
                 cache.guilds.remove(&guild);
@@ -612,6 +985,7 @@ impl CacheUpdate for ReadyEvent {
         // `ready.private_channels` will always be empty, and possibly be removed in the future.
         // So don't handle it at all.
 
+        let cache_presences = cache.settings().cache_presences;
         for (user_id, presence) in &mut ready.presences {
             if let Some(user) = presence.user.to_user() {
                 cache.update_user_entry(&user);
@@ -620,7 +994,9 @@ impl CacheUpdate for ReadyEvent {
                 presence.user.update_with_user(user);
             }
 
-            cache.presences.insert(*user_id, presence.clone());
+            if cache_presences {
+                cache.presences.insert(*user_id, presence.clone());
+            }
         }
 
         *cache.shard_count.write() = ready.shard.map_or(1, |s| s[1]);
@@ -630,12 +1006,65 @@ impl CacheUpdate for ReadyEvent {
     }
 }
 
+impl CacheUpdate for StageInstanceCreateEvent {
+    type Output = StageInstance;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        let mut guild = cache.guilds.get_mut(&self.stage_instance.guild_id)?;
+        upsert_stage_instance(&mut guild.stage_instances, &self.stage_instance)
+    }
+}
+
+impl CacheUpdate for StageInstanceUpdateEvent {
+    type Output = StageInstance;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        let mut guild = cache.guilds.get_mut(&self.stage_instance.guild_id)?;
+        upsert_stage_instance(&mut guild.stage_instances, &self.stage_instance)
+    }
+}
+
+impl CacheUpdate for StageInstanceDeleteEvent {
+    type Output = StageInstance;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        let mut guild = cache.guilds.get_mut(&self.stage_instance.guild_id)?;
+        let index = guild.stage_instances.iter().position(|s| s.id == self.stage_instance.id)?;
+
+        Some(guild.stage_instances.remove(index))
+    }
+}
+
+/// Replaces the stage instance with the same id, returning it, or adds the
+/// stage instance if there is none.
+fn upsert_stage_instance(
+    instances: &mut Vec<StageInstance>,
+    instance: &StageInstance,
+) -> Option<StageInstance> {
+    if let Some(old) = instances.iter_mut().find(|s| s.id == instance.id) {
+        return Some(std::mem::replace(old, instance.clone()));
+    }
+
+    instances.push(instance.clone());
+
+    None
+}
+
 impl CacheUpdate for ThreadCreateEvent {
     type Output = GuildChannel;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        if !cache.settings().cache_threads {
+            return None;
+        }
+
         let (guild_id, thread_id) = (self.thread.guild_id, self.thread.id);
 
+        if let Some(member) = &self.thread.member {
+            let user_id = member.user_id.unwrap_or_else(|| cache.current_user_id());
+            cache.thread_members.get_or_default(thread_id).insert(user_id, member.clone());
+        }
+
         cache.guilds.get_mut(&guild_id).and_then(|mut g| {
             if let Some(i) = g.threads.iter().position(|e| e.id == thread_id) {
                 Some(std::mem::replace(&mut g.threads[i], self.thread.clone()))
@@ -648,11 +1077,25 @@ impl CacheUpdate for ThreadCreateEvent {
 }
 
 impl CacheUpdate for ThreadUpdateEvent {
+    /// The old thread, or the evicted thread if it was archived.
     type Output = GuildChannel;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        if !cache.settings().cache_threads {
+            return None;
+        }
+
         let (guild_id, thread_id) = (self.thread.guild_id, self.thread.id);
 
+        // Archived threads are no longer active, so evict them.
+        if self.thread.thread_metadata.map_or(false, |m| m.archived) {
+            cache.thread_members.remove(&thread_id);
+
+            return cache.guilds.get_mut(&guild_id).and_then(|mut g| {
+                g.threads.iter().position(|e| e.id == thread_id).map(|i| g.threads.remove(i))
+            });
+        }
+
         cache.guilds.get_mut(&guild_id).and_then(|mut g| {
             if let Some(i) = g.threads.iter().position(|e| e.id == thread_id) {
                 Some(std::mem::replace(&mut g.threads[i], self.thread.clone()))
@@ -670,12 +1113,115 @@ impl CacheUpdate for ThreadDeleteEvent {
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         let (guild_id, thread_id) = (self.thread.guild_id, self.thread.id);
 
+        cache.thread_members.remove(&thread_id);
+
         cache.guilds.get_mut(&guild_id).and_then(|mut g| {
             g.threads.iter().position(|e| e.id == thread_id).map(|i| g.threads.remove(i))
         })
     }
 }
 
+impl CacheUpdate for ThreadListSyncEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        if !cache.settings().cache_threads {
+            return None;
+        }
+
+        let current_user_id = cache.current_user_id();
+        let mut guild = cache.guilds.get_mut(&self.guild_id)?;
+
+        // Replace the threads of the synced parent channels, or of the whole
+        // guild if no channels are given.
+        let (channels, threads) = (&self.channels_id, &self.threads);
+        guild.threads.retain(|thread| {
+            let active = threads.iter().any(|t| t.id == thread.id);
+            let synced =
+                channels.is_empty() || thread.parent_id.map_or(false, |id| channels.contains(&id));
+
+            if synced && !active {
+                cache.thread_members.remove(&thread.id);
+            }
+
+            !(synced || active)
+        });
+
+        for thread in &self.threads {
+            let mut thread = thread.clone();
+            thread.member = self.members.iter().find(|m| m.id == Some(thread.id)).cloned();
+
+            guild.threads.push(thread);
+        }
+
+        for member in &self.members {
+            if let Some(thread_id) = member.id {
+                let user_id = member.user_id.unwrap_or(current_user_id);
+                cache.thread_members.get_or_default(thread_id).insert(user_id, member.clone());
+            }
+        }
+
+        None
+    }
+}
+
+impl CacheUpdate for ThreadMemberUpdateEvent {
+    type Output = ThreadMember;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        if !cache.settings().cache_threads {
+            return None;
+        }
+
+        let thread_id = self.member.id?;
+        let user_id = self.member.user_id.unwrap_or_else(|| cache.current_user_id());
+
+        let mut guild = cache.guilds.get_mut(&self.guild_id)?;
+        let thread = guild.threads.iter_mut().find(|t| t.id == thread_id)?;
+        thread.member = Some(self.member.clone());
+        drop(guild);
+
+        cache.thread_members.get_or_default(thread_id).insert(user_id, self.member.clone())
+    }
+}
+
+impl CacheUpdate for ThreadMembersUpdateEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        if !cache.settings().cache_threads {
+            return None;
+        }
+
+        let current_user_id = cache.current_user_id();
+        let mut guild = cache.guilds.get_mut(&self.guild_id)?;
+        let thread = guild.threads.iter_mut().find(|t| t.id == self.id)?;
+        let mut members = cache.thread_members.get_or_default(self.id);
+
+        thread.member_count = Some(self.member_count);
+
+        for member in &self.added_members {
+            if let Some(user_id) = member.user_id {
+                if user_id == current_user_id {
+                    thread.member = Some(member.clone());
+                }
+
+                members.insert(user_id, member.clone());
+            }
+        }
+
+        for user_id in &self.removed_members_ids {
+            if *user_id == current_user_id {
+                thread.member = None;
+            }
+
+            members.remove(user_id);
+        }
+
+        None
+    }
+}
+
 impl CacheUpdate for UserUpdateEvent {
     type Output = CurrentUser;
 
@@ -689,13 +1235,17 @@ impl CacheUpdate for VoiceStateUpdateEvent {
     type Output = VoiceState;
 
     fn update(&mut self, cache: &Cache) -> Option<VoiceState> {
+        let settings = cache.settings();
+
         if let Some(guild_id) = self.voice_state.guild_id {
             if let Some(mut guild) = cache.guilds.get_mut(&guild_id) {
-                if let Some(member) = &self.voice_state.member {
+                if let (true, Some(member)) = (settings.cache_members, &self.voice_state.member) {
                     guild.members.insert(member.user.id, member.clone());
                 }
 
-                if self.voice_state.channel_id.is_some() {
+                if !settings.cache_voice_states {
+                    None
+                } else if self.voice_state.channel_id.is_some() {
                     // Update or add to the voice state list
                     guild.voice_states.insert(self.voice_state.user_id, self.voice_state.clone())
                 } else {
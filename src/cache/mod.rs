@@ -38,7 +38,6 @@ use std::str::FromStr;
 use std::time::Duration;
 
 use dashmap::iter::Iter;
-use dashmap::mapref::entry::Entry;
 use dashmap::mapref::multiple::RefMulti;
 use dashmap::{DashMap, DashSet};
 #[cfg(feature = "temp_cache")]
@@ -46,15 +45,20 @@ use moka::dash::Cache as DashCache;
 use parking_lot::RwLock;
 use tracing::instrument;
 
+use crate::model::guild::automod::Rule;
 use crate::model::prelude::*;
+mod backend;
 mod cache_update;
 mod event;
 mod settings;
+mod snapshot;
 
+pub use self::backend::{CacheBackend, CacheMap, CacheRef, CacheRefMut, InMemoryCacheBackend};
 pub use self::cache_update::CacheUpdate;
 pub use self::settings::Settings;
+pub use self::snapshot::SnapshotCacheBackend;
 
-type MessageCache = DashMap<ChannelId, DashMap<MessageId, Message>>;
+type MessageCache = Box<dyn CacheMap<ChannelId, DashMap<MessageId, Message>>>;
 
 pub trait FromStrAndCache: Sized {
     type Err;
@@ -132,25 +136,42 @@ pub struct Cache {
     /// When a [`Event::GuildDelete`] or [`Event::GuildUnavailable`] is
     /// received and processed by the cache, the relevant channels are also
     /// removed from this map.
-    pub(crate) channels: DashMap<ChannelId, GuildChannel>,
+    pub(crate) channels: Box<dyn CacheMap<ChannelId, GuildChannel>>,
     /// Cache of channels that have been fetched via to_channel.
     ///
     /// Each value has a maximum TTL of 1 hour.
     #[cfg(feature = "temp_cache")]
     pub(crate) temp_channels: DashCache<ChannelId, GuildChannel>,
     /// A map of channel categories.
-    pub(crate) categories: DashMap<ChannelId, ChannelCategory>,
+    pub(crate) categories: Box<dyn CacheMap<ChannelId, ChannelCategory>>,
     /// A map of guilds with full data available. This includes data like
     /// [`Role`]s and [`Emoji`]s that are not available through the REST API.
-    pub(crate) guilds: DashMap<GuildId, Guild>,
+    pub(crate) guilds: Box<dyn CacheMap<GuildId, Guild>>,
+    /// A map of guilds' auto moderation rules.
+    ///
+    /// Discord does not send rules with a guild's data, so only rules created
+    /// or updated while the current user is connected are present.
+    pub(crate) automod_rules: Box<dyn CacheMap<GuildId, HashMap<RuleId, Rule>>>,
+    /// A map of guilds' integrations.
+    ///
+    /// Like [`Self::automod_rules`], only integrations created or updated while
+    /// the current user is connected are present.
+    pub(crate) integrations: Box<dyn CacheMap<GuildId, HashMap<IntegrationId, Integration>>>,
+    /// A map of the known members of cached threads.
+    ///
+    /// Members are added and removed via [`Event::ThreadMembersUpdate`], and
+    /// the current user's membership via [`Event::ThreadListSync`] and
+    /// [`Event::ThreadMemberUpdate`]. A thread's members are removed along
+    /// with the thread once it is deleted or archived.
+    pub(crate) thread_members: Box<dyn CacheMap<ChannelId, HashMap<UserId, ThreadMember>>>,
     pub(crate) messages: MessageCache,
     /// A map of users' presences. This is updated in real-time. Note that
     /// status updates are often "eaten" by the gateway, and this should not
     /// be treated as being entirely 100% accurate.
-    pub(crate) presences: DashMap<UserId, Presence>,
+    pub(crate) presences: Box<dyn CacheMap<UserId, Presence>>,
     /// A map of direct message channels that the current user has open with
     /// other users.
-    pub(crate) private_channels: DashMap<ChannelId, PrivateChannel>,
+    pub(crate) private_channels: Box<dyn CacheMap<ChannelId, PrivateChannel>>,
     /// The total number of shards being used by the bot.
     pub(crate) shard_count: RwLock<u64>,
     /// A list of guilds which are "unavailable". Refer to the documentation for
@@ -159,7 +180,7 @@ pub struct Cache {
     /// Additionally, guilds are always unavailable for bot users when a Ready
     /// is received. Guilds are "sent in" over time through the receiving of
     /// [`Event::GuildCreate`]s.
-    pub(crate) unavailable_guilds: DashSet<GuildId>,
+    pub(crate) unavailable_guilds: Box<dyn CacheMap<GuildId, ()>>,
     /// The current user "logged in" and for which events are being received
     /// for.
     ///
@@ -182,13 +203,13 @@ pub struct Cache {
     /// Note, however, that users are _not_ removed from the map on removal
     /// events such as [`GuildMemberRemove`][`GuildMemberRemoveEvent`], as other
     /// structs such as members or recipients may still exist.
-    pub(crate) users: DashMap<UserId, User>,
+    pub(crate) users: Box<dyn CacheMap<UserId, User>>,
     /// Queue of message IDs for each channel.
     ///
     /// This is simply a vecdeque so we can keep track of the order of messages
     /// inserted into the cache. When a maximum number of messages are in a
     /// channel's cache, we can pop the front and remove that ID from the cache.
-    pub(crate) message_queue: DashMap<ChannelId, VecDeque<MessageId>>,
+    pub(crate) message_queue: Box<dyn CacheMap<ChannelId, VecDeque<MessageId>>>,
     /// Cache of users who have been fetched from `to_user`.
     ///
     /// Each value has a max TTL of 1 hour.
@@ -220,12 +241,63 @@ impl Cache {
     /// ```
     #[instrument]
     pub fn new_with_settings(settings: Settings) -> Self {
+        Self::new_with_backend(settings, &InMemoryCacheBackend)
+    }
+
+    /// Creates a new cache instance with settings applied, storing its data in
+    /// the maps created by the backend.
+    ///
+    /// Refer to [`CacheBackend`] for more information.
+    pub fn new_with_backend(settings: Settings, backend: &dyn CacheBackend) -> Self {
         Self {
+            channels: backend.channels(),
+            #[cfg(feature = "temp_cache")]
+            temp_channels: DashCache::builder().time_to_live(Duration::from_secs(60 * 60)).build(),
+            categories: backend.categories(),
+            guilds: backend.guilds(),
+            automod_rules: backend.automod_rules(),
+            integrations: backend.integrations(),
+            thread_members: backend.thread_members(),
+            messages: backend.messages(),
+            presences: backend.presences(),
+            private_channels: backend.private_channels(),
             settings: RwLock::new(settings),
-            ..Default::default()
+            shard_count: RwLock::new(1),
+            unavailable_guilds: backend.unavailable_guilds(),
+            user: RwLock::new(CurrentUser::default()),
+            users: backend.users(),
+            #[cfg(feature = "temp_cache")]
+            temp_users: DashCache::builder().time_to_live(Duration::from_secs(60 * 60)).build(),
+            message_queue: backend.message_queue(),
         }
     }
 
+    /// Persists the cache's maps, if its [`CacheBackend`] stores them outside
+    /// the process.
+    ///
+    /// This is never called by the [`Client`], so must be called on a schedule
+    /// for the stored maps to stay up to date.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a map could not be written to its storage.
+    ///
+    /// [`Client`]: crate::Client
+    pub fn flush(&self) -> crate::Result<()> {
+        self.channels.flush()?;
+        self.categories.flush()?;
+        self.guilds.flush()?;
+        self.automod_rules.flush()?;
+        self.integrations.flush()?;
+        self.thread_members.flush()?;
+        self.messages.flush()?;
+        self.message_queue.flush()?;
+        self.presences.flush()?;
+        self.private_channels.flush()?;
+        self.unavailable_guilds.flush()?;
+        self.users.flush()
+    }
+
     /// Fetches the number of [`Member`]s that have not had data received.
     ///
     /// The important detail to note here is that this is the number of
@@ -276,15 +348,15 @@ impl Cache {
     pub fn unknown_members(&self) -> u64 {
         let mut total = 0;
 
-        for guild_entry in self.guilds.iter() {
-            let guild = guild_entry.value();
-
+        self.guilds.any(&mut |_, guild| {
             let members = guild.members.len() as u64;
 
             if guild.member_count > members {
                 total += guild.member_count - members;
             }
-        }
+
+            false
+        });
 
         total
     }
@@ -308,7 +380,7 @@ impl Cache {
     /// println!("There are {} private channels", amount);
     /// ```
     pub fn private_channels(&self) -> DashMap<ChannelId, PrivateChannel> {
-        self.private_channels.clone()
+        self.private_channels.entries().into_iter().collect()
     }
 
     /// Fetches a vector of all [`Guild`]s' Ids that are stored in the cache.
@@ -340,8 +412,10 @@ impl Cache {
     /// [`Context`]: crate::client::Context
     /// [`Shard`]: crate::gateway::Shard
     pub fn guilds(&self) -> Vec<GuildId> {
-        let chain = self.unavailable_guilds.clone().into_iter();
-        self.guilds.iter().map(|i| *i.key()).chain(chain).collect()
+        let mut guilds = self.guilds.keys();
+        guilds.extend(self.unavailable_guilds.keys());
+
+        guilds
     }
 
     /// Retrieves a [`Channel`] from the cache based on the given Id.
@@ -679,10 +753,63 @@ impl Cache {
         self.guilds.get(&guild_id).map(|g| g.roles.clone())
     }
 
+    /// Clones all cached auto moderation [`Rule`]s of a guild.
+    ///
+    /// **Note**: Rules are not part of a guild's data, so only rules created or
+    /// updated since the current user connected are cached.
+    #[inline]
+    pub fn automod_rules(&self, guild_id: impl Into<GuildId>) -> Option<Vec<Rule>> {
+        self._automod_rules(guild_id.into())
+    }
+
+    fn _automod_rules(&self, guild_id: GuildId) -> Option<Vec<Rule>> {
+        self.automod_rules.get(&guild_id).map(|rules| rules.values().cloned().collect())
+    }
+
+    /// Clones all cached [`Integration`]s of a guild.
+    ///
+    /// **Note**: Integrations are not part of a guild's data, so only
+    /// integrations created or updated since the current user connected are
+    /// cached.
+    #[inline]
+    pub fn guild_integrations(&self, guild_id: impl Into<GuildId>) -> Option<Vec<Integration>> {
+        self._guild_integrations(guild_id.into())
+    }
+
+    fn _guild_integrations(&self, guild_id: GuildId) -> Option<Vec<Integration>> {
+        self.integrations.get(&guild_id).map(|i| i.values().cloned().collect())
+    }
+
     /// This method clones and returns all unavailable guilds.
     #[inline]
     pub fn unavailable_guilds(&self) -> DashSet<GuildId> {
-        self.unavailable_guilds.clone()
+        self.unavailable_guilds.keys().into_iter().collect()
+    }
+
+    /// Clones all active threads of a guild.
+    ///
+    /// Archived threads are evicted from the cache, so they are not returned.
+    #[inline]
+    pub fn guild_threads(&self, guild_id: impl Into<GuildId>) -> Option<Vec<GuildChannel>> {
+        self._guild_threads(guild_id.into())
+    }
+
+    fn _guild_threads(&self, guild_id: GuildId) -> Option<Vec<GuildChannel>> {
+        self.guilds.get(&guild_id).map(|g| g.threads.clone())
+    }
+
+    /// Clones the known members of a thread.
+    ///
+    /// Discord only sends every member of a thread with the
+    /// [`GatewayIntents::GUILD_MEMBERS`] intent; otherwise only the current
+    /// user's membership is known.
+    #[inline]
+    pub fn thread_members(&self, thread_id: impl Into<ChannelId>) -> Option<Vec<ThreadMember>> {
+        self._thread_members(thread_id.into())
+    }
+
+    fn _thread_members(&self, thread_id: ChannelId) -> Option<Vec<ThreadMember>> {
+        self.thread_members.get(&thread_id).map(|m| m.values().cloned().collect())
     }
 
     /// This method returns all channels from a guild of with the given `guild_id`.
@@ -841,6 +968,55 @@ impl Cache {
         self.guilds.get(&guild_id).and_then(|g| g.roles.get(&role_id).cloned())
     }
 
+    /// Retrieves a [`ScheduledEvent`] of a cached guild.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # let cache = Cache::default();
+    /// if let Some(event) = cache.scheduled_event(7, 77) {
+    ///     println!("Scheduled event with Id 77 is called {}", event.name);
+    /// }
+    /// ```
+    #[inline]
+    pub fn scheduled_event<G, E>(&self, guild_id: G, event_id: E) -> Option<ScheduledEvent>
+    where
+        G: Into<GuildId>,
+        E: Into<ScheduledEventId>,
+    {
+        self._scheduled_event(guild_id.into(), event_id.into())
+    }
+
+    fn _scheduled_event(
+        &self,
+        guild_id: GuildId,
+        event_id: ScheduledEventId,
+    ) -> Option<ScheduledEvent> {
+        let guild = self.guilds.get(&guild_id)?;
+        guild.scheduled_events.iter().find(|e| e.id == event_id).cloned()
+    }
+
+    /// Retrieves a [`StageInstance`] of a cached guild.
+    #[inline]
+    pub fn stage_instance<G, S>(&self, guild_id: G, stage_instance_id: S) -> Option<StageInstance>
+    where
+        G: Into<GuildId>,
+        S: Into<StageInstanceId>,
+    {
+        self._stage_instance(guild_id.into(), stage_instance_id.into())
+    }
+
+    fn _stage_instance(
+        &self,
+        guild_id: GuildId,
+        stage_instance_id: StageInstanceId,
+    ) -> Option<StageInstance> {
+        let guild = self.guilds.get(&guild_id)?;
+        guild.stage_instances.iter().find(|s| s.id == stage_instance_id).cloned()
+    }
+
     /// Returns the settings.
     ///
     /// # Examples
@@ -909,7 +1085,7 @@ impl Cache {
     /// Clones all users and returns them.
     #[inline]
     pub fn users(&self) -> DashMap<UserId, User> {
-        self.users.clone()
+        self.users.entries().into_iter().collect()
     }
 
     /// Returns the amount of cached users.
@@ -931,7 +1107,7 @@ impl Cache {
     /// Clones all categories and returns them.
     #[inline]
     pub fn categories(&self) -> DashMap<ChannelId, ChannelCategory> {
-        self.categories.clone()
+        self.categories.entries().into_iter().collect()
     }
 
     /// Returns the amount of cached categories.
@@ -999,37 +1175,22 @@ impl Cache {
     }
 
     pub(crate) fn update_user_entry(&self, user: &User) {
-        match self.users.entry(user.id) {
-            Entry::Vacant(e) => {
-                e.insert(user.clone());
-            },
-            Entry::Occupied(mut e) => {
-                e.get_mut().clone_from(user);
-            },
+        if !self.settings.read().cache_users {
+            return;
+        }
+
+        if let Some(mut cached) = self.users.get_mut(&user.id) {
+            cached.clone_from(user);
+            return;
         }
+
+        self.users.insert(user.id, user.clone());
     }
 }
 
 impl Default for Cache {
     fn default() -> Cache {
-        Cache {
-            channels: DashMap::default(),
-            #[cfg(feature = "temp_cache")]
-            temp_channels: DashCache::builder().time_to_live(Duration::from_secs(60 * 60)).build(),
-            categories: DashMap::default(),
-            guilds: DashMap::default(),
-            messages: DashMap::default(),
-            presences: DashMap::default(),
-            private_channels: DashMap::with_capacity(128),
-            settings: RwLock::new(Settings::default()),
-            shard_count: RwLock::new(1),
-            unavailable_guilds: DashSet::default(),
-            user: RwLock::new(CurrentUser::default()),
-            users: DashMap::default(),
-            #[cfg(feature = "temp_cache")]
-            temp_users: DashCache::builder().time_to_live(Duration::from_secs(60 * 60)).build(),
-            message_queue: DashMap::default(),
-        }
+        Cache::new_with_backend(Settings::default(), &InMemoryCacheBackend)
     }
 }
 
@@ -1037,10 +1198,105 @@ impl Default for Cache {
 mod test {
     use std::collections::HashMap;
 
-    use crate::cache::{Cache, CacheUpdate, Settings};
+    use crate::cache::{Cache, CacheUpdate, Settings, SnapshotCacheBackend};
     use crate::json::from_number;
     use crate::model::prelude::*;
 
+    fn guild(id: u64) -> Guild {
+        Guild {
+            id: GuildId(id),
+            afk_channel_id: None,
+            afk_timeout: 0,
+            application_id: None,
+            default_message_notifications: DefaultMessageNotificationLevel::All,
+            emojis: HashMap::new(),
+            explicit_content_filter: ExplicitContentFilter::None,
+            features: vec![],
+            icon: None,
+            joined_at: Timestamp::now(),
+            large: false,
+            member_count: 0,
+            members: HashMap::new(),
+            mfa_level: MfaLevel::None,
+            name: String::new(),
+            owner_id: UserId(3),
+            presences: HashMap::new(),
+            roles: HashMap::new(),
+            splash: None,
+            discovery_splash: None,
+            system_channel_id: None,
+            system_channel_flags: SystemChannelFlags::default(),
+            rules_channel_id: None,
+            public_updates_channel_id: None,
+            verification_level: VerificationLevel::Low,
+            voice_states: HashMap::new(),
+            description: None,
+            premium_tier: PremiumTier::Tier0,
+            channels: HashMap::new(),
+            premium_subscription_count: 0,
+            banner: None,
+            vanity_url_code: None,
+            preferred_locale: "en-US".to_string(),
+            welcome_screen: None,
+            approximate_member_count: None,
+            approximate_presence_count: None,
+            nsfw_level: NsfwLevel::Default,
+            max_video_channel_users: None,
+            max_presences: None,
+            max_members: None,
+            widget_enabled: None,
+            widget_channel_id: None,
+            stage_instances: vec![],
+            threads: vec![],
+            scheduled_events: vec![],
+            stickers: HashMap::new(),
+        }
+    }
+
+    fn channel(id: u64, guild_id: u64) -> GuildChannel {
+        GuildChannel {
+            id: ChannelId(id),
+            bitrate: None,
+            parent_id: None,
+            guild_id: GuildId(guild_id),
+            kind: ChannelType::Text,
+            owner_id: None,
+            last_message_id: None,
+            last_pin_timestamp: None,
+            name: String::new(),
+            permission_overwrites: vec![],
+            position: 0,
+            topic: None,
+            user_limit: None,
+            nsfw: false,
+            rate_limit_per_user: Some(0),
+            rtc_region: None,
+            video_quality_mode: None,
+            message_count: None,
+            member_count: None,
+            thread_metadata: None,
+            member: None,
+            default_auto_archive_duration: None,
+            flags: ChannelFlags::empty(),
+            total_message_sent: None,
+            available_tags: Vec::new(),
+            applied_tags: Vec::new(),
+            default_reaction_emoji: None,
+            default_thread_rate_limit_per_user: None,
+            default_sort_order: None,
+        }
+    }
+
+    // A public thread of the channel, in guild 1.
+    fn thread(id: u64, parent_id: u64) -> GuildChannel {
+        GuildChannel {
+            parent_id: Some(ChannelId(parent_id)),
+            kind: ChannelType::PublicThread,
+            rate_limit_per_user: None,
+            ..channel(id, 1)
+        }
+    }
+
     #[test]
     fn test_cache_messages() {
         let mut settings = Settings::new();
@@ -1120,37 +1376,75 @@ mod test {
             assert!(!channel.contains_key(&MessageId(3)));
         }
 
-        let channel = Channel::Guild(GuildChannel {
-            id: event.message.channel_id,
-            bitrate: None,
-            parent_id: None,
-            guild_id: event.message.guild_id.unwrap(),
-            kind: ChannelType::Text,
-            owner_id: None,
-            last_message_id: None,
-            last_pin_timestamp: None,
-            name: String::new(),
-            permission_overwrites: vec![],
-            position: 0,
-            topic: None,
-            user_limit: None,
-            nsfw: false,
-            rate_limit_per_user: Some(0),
-            rtc_region: None,
-            video_quality_mode: None,
-            message_count: None,
-            member_count: None,
-            thread_metadata: None,
+        // Reactions update the cached message's counts and `me` flags.
+        let reaction = Reaction {
+            channel_id: ChannelId(2),
+            emoji: ReactionType::Unicode("👍".to_owned()),
+            message_id: MessageId(5),
+            user_id: Some(cache.current_user_id()),
+            guild_id: Some(GuildId(1)),
             member: None,
-            default_auto_archive_duration: None,
-            flags: ChannelFlags::empty(),
-            total_message_sent: None,
-            available_tags: Vec::new(),
-            applied_tags: Vec::new(),
-            default_reaction_emoji: None,
-            default_thread_rate_limit_per_user: None,
-            default_sort_order: None,
-        });
+        };
+        let mut add = ReactionAddEvent {
+            reaction: reaction.clone(),
+        };
+        cache.update(&mut add);
+        add.reaction.user_id = Some(UserId(7));
+        cache.update(&mut add);
+
+        {
+            let channel = cache.messages.get(&ChannelId(2)).unwrap();
+            let message = channel.get(&MessageId(5)).unwrap();
+            assert_eq!(message.reactions.len(), 1);
+            assert_eq!(message.reactions[0].count, 2);
+            assert!(message.reactions[0].me);
+        }
+
+        let mut remove = ReactionRemoveEvent {
+            reaction: reaction.clone(),
+        };
+        cache.update(&mut remove);
+
+        {
+            let channel = cache.messages.get(&ChannelId(2)).unwrap();
+            let message = channel.get(&MessageId(5)).unwrap();
+            assert_eq!(message.reactions[0].count, 1);
+            assert!(!message.reactions[0].me);
+        }
+
+        let mut remove_emoji = ReactionRemoveEmojiEvent {
+            reaction,
+        };
+        cache.update(&mut remove_emoji);
+        assert!(cache
+            .messages
+            .get(&ChannelId(2))
+            .unwrap()
+            .get(&MessageId(5))
+            .unwrap()
+            .reactions
+            .is_empty());
+
+        // Deleting a message evicts it and hands it back.
+        let mut message_delete = MessageDeleteEvent {
+            guild_id: Some(GuildId(1)),
+            channel_id: ChannelId(2),
+            message_id: MessageId(4),
+        };
+        assert_eq!(cache.update(&mut message_delete).map(|m| m.id), Some(MessageId(4)));
+        assert!(cache.update(&mut message_delete).is_none());
+        assert!(!cache.message_queue.get(&ChannelId(2)).unwrap().contains(&MessageId(4)));
+
+        let mut bulk_delete = MessageDeleteBulkEvent {
+            guild_id: Some(GuildId(1)),
+            channel_id: ChannelId(2),
+            ids: vec![MessageId(4), MessageId(5)],
+        };
+        assert_eq!(cache.update(&mut bulk_delete).map(|m| m.len()), Some(1));
+        assert!(cache.messages.get(&ChannelId(2)).unwrap().is_empty());
+        assert!(!cache.message_queue.get(&ChannelId(2)).unwrap().contains(&MessageId(5)));
+
+        let channel = Channel::Guild(channel(2, 1));
 
         // Add a channel delete event to the cache, the cached messages for that
         // channel should now be gone.
@@ -1168,51 +1462,8 @@ mod test {
 
             GuildCreateEvent {
                 guild: Guild {
-                    id: GuildId(1),
-                    afk_channel_id: None,
-                    afk_timeout: 0,
-                    application_id: None,
-                    default_message_notifications: DefaultMessageNotificationLevel::All,
-                    emojis: HashMap::new(),
-                    explicit_content_filter: ExplicitContentFilter::None,
-                    features: vec![],
-                    icon: None,
-                    joined_at: datetime,
-                    large: false,
-                    member_count: 0,
-                    members: HashMap::new(),
-                    mfa_level: MfaLevel::None,
-                    name: String::new(),
-                    owner_id: UserId(3),
-                    presences: HashMap::new(),
-                    roles: HashMap::new(),
-                    splash: None,
-                    discovery_splash: None,
-                    system_channel_id: None,
-                    system_channel_flags: SystemChannelFlags::default(),
-                    rules_channel_id: None,
-                    public_updates_channel_id: None,
-                    verification_level: VerificationLevel::Low,
-                    voice_states: HashMap::new(),
-                    description: None,
-                    premium_tier: PremiumTier::Tier0,
                     channels,
-                    premium_subscription_count: 0,
-                    banner: None,
-                    vanity_url_code: Some("bruhmoment".to_string()),
-                    preferred_locale: "en-US".to_string(),
-                    welcome_screen: None,
-                    approximate_member_count: None,
-                    approximate_presence_count: None,
-                    nsfw_level: NsfwLevel::Default,
-                    max_video_channel_users: None,
-                    max_presences: None,
-                    max_members: None,
-                    widget_enabled: Some(false),
-                    widget_channel_id: None,
-                    stage_instances: vec![],
-                    threads: vec![],
-                    stickers: HashMap::new(),
+                    ..guild(1)
                 },
             }
         };
@@ -1233,4 +1484,217 @@ mod test {
         // Assert that the channel's message cache no longer exists.
         assert!(!cache.messages.contains_key(&ChannelId(2)));
     }
+
+    #[test]
+    fn test_cache_automod_rules() {
+        use crate::model::guild::automod::{EventType, Rule, Trigger};
+
+        let cache = Cache::new();
+        let mut rule = Rule {
+            id: RuleId(1),
+            guild_id: GuildId(2),
+            name: "no spam".to_owned(),
+            creator_id: UserId(3),
+            event_type: EventType::MessageSend,
+            trigger: Trigger::Spam,
+            actions: vec![],
+            enabled: true,
+            exempt_roles: vec![],
+            exempt_channels: vec![],
+        };
+
+        let mut create = AutoModerationRuleCreateEvent {
+            rule: rule.clone(),
+        };
+        assert!(cache.update(&mut create).is_none());
+        assert_eq!(cache.automod_rules(GuildId(2)), Some(vec![rule.clone()]));
+
+        rule.enabled = false;
+        let mut update = AutoModerationRuleUpdateEvent {
+            rule: rule.clone(),
+        };
+        assert!(cache.update(&mut update).map_or(false, |old| old.enabled));
+
+        let mut delete = AutoModerationRuleDeleteEvent {
+            rule,
+        };
+        assert!(cache.update(&mut delete).map_or(false, |old| !old.enabled));
+        assert_eq!(cache.automod_rules(GuildId(2)), Some(vec![]));
+    }
+
+    #[test]
+    fn test_cache_threads() {
+        let cache = Cache::new();
+        let member = |thread_id, user_id| ThreadMember {
+            id: Some(ChannelId(thread_id)),
+            user_id: Some(UserId(user_id)),
+            join_timestamp: Timestamp::now(),
+            flags: ThreadMemberFlags::empty(),
+        };
+
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                threads: vec![thread(10, 2), thread(11, 3)],
+                ..guild(1)
+            },
+        };
+        cache.update(&mut guild_create);
+
+        // Syncing channel 2 replaces its threads, leaving those of channel 3.
+        let mut sync = ThreadListSyncEvent {
+            guild_id: GuildId(1),
+            channels_id: vec![ChannelId(2)],
+            threads: vec![thread(12, 2)],
+            members: vec![member(12, 0)],
+        };
+        cache.update(&mut sync);
+
+        let mut ids = cache.guild_threads(1).unwrap().iter().map(|t| t.id.0).collect::<Vec<_>>();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![11, 12]);
+        assert_eq!(cache.thread_members(12).unwrap().len(), 1);
+
+        let mut members_update = ThreadMembersUpdateEvent {
+            id: ChannelId(12),
+            guild_id: GuildId(1),
+            member_count: 2,
+            added_members: vec![member(12, 5)],
+            removed_members_ids: vec![UserId(0)],
+        };
+        cache.update(&mut members_update);
+
+        let members = cache.thread_members(12).unwrap();
+        assert_eq!(members.len(), 1);
+        assert_eq!(members[0].user_id, Some(UserId(5)));
+        let synced = cache.guild_threads(1).unwrap().into_iter().find(|t| t.id.0 == 12).unwrap();
+        assert_eq!(synced.member_count, Some(2));
+        assert!(synced.member.is_none());
+
+        // Archiving a thread evicts it and its members.
+        let mut archived = thread(12, 2);
+        archived.thread_metadata = Some(ThreadMetadata {
+            archived: true,
+            auto_archive_duration: None,
+            archive_timestamp: None,
+            locked: false,
+            create_timestamp: None,
+            invitable: false,
+        });
+        let mut thread_update = ThreadUpdateEvent {
+            thread: archived,
+        };
+        assert!(cache.update(&mut thread_update).is_some());
+        assert_eq!(cache.guild_threads(1).unwrap().len(), 1);
+        assert!(cache.thread_members(12).is_none());
+    }
+
+    #[test]
+    fn test_cache_settings() {
+        let mut settings = Settings::new();
+        settings.cache_users(false);
+        let cache = Cache::new_with_settings(settings);
+
+        cache.update_user_entry(&User::default());
+        assert!(cache.users.is_empty());
+
+        cache.settings.write().cache_users = true;
+        cache.update_user_entry(&User::default());
+        assert_eq!(cache.users.len(), 1);
+    }
+
+    #[test]
+    fn test_cache_snapshot_backend() {
+        let directory = std::env::temp_dir().join(format!("serenity-cache-{}", std::process::id()));
+        let backend = SnapshotCacheBackend::new(&directory);
+        let mut settings = Settings::new();
+        settings.max_messages(10);
+
+        let cache = Cache::new_with_backend(settings.clone(), &backend);
+        let user = User {
+            id: UserId(7),
+            ..User::default()
+        };
+        cache.update_user_entry(&user);
+        cache.unavailable_guilds.insert(GuildId(3), ());
+
+        let mut members = HashMap::new();
+        members.insert(UserId(7), Member {
+            deaf: false,
+            guild_id: GuildId(1),
+            joined_at: None,
+            mute: false,
+            nick: Some("member".to_string()),
+            roles: vec![],
+            user: user.clone(),
+            pending: false,
+            premium_since: None,
+            permissions: None,
+            avatar: None,
+            communication_disabled_until: None,
+        });
+
+        let mut guild_create = GuildCreateEvent {
+            guild: Guild {
+                member_count: 1,
+                members,
+                name: "guild".to_string(),
+                owner_id: UserId(7),
+                ..guild(1)
+            },
+        };
+        cache.update(&mut guild_create);
+
+        let mut message_create = MessageCreateEvent {
+            message: Message {
+                id: MessageId(3),
+                attachments: vec![],
+                author: user,
+                channel_id: ChannelId(2),
+                guild_id: Some(GuildId(1)),
+                content: "message".to_string(),
+                edited_timestamp: None,
+                embeds: vec![],
+                kind: MessageType::Regular,
+                member: None,
+                mention_everyone: false,
+                mention_roles: vec![],
+                mention_channels: vec![],
+                mentions: vec![],
+                nonce: from_number(1),
+                pinned: false,
+                reactions: vec![],
+                timestamp: Timestamp::now(),
+                tts: false,
+                webhook_id: None,
+                activity: None,
+                application: None,
+                message_reference: None,
+                flags: None,
+                sticker_items: vec![],
+                referenced_message: None,
+                interaction: None,
+                components: vec![],
+                application_id: None,
+                thread: None,
+            },
+        };
+        cache.update(&mut message_create);
+        cache.flush().unwrap();
+
+        let restored = Cache::new_with_backend(settings, &backend);
+        assert_eq!(restored.user(7).map(|u| u.id), Some(UserId(7)));
+        assert_eq!(restored.guilds(), vec![GuildId(1)]);
+        assert!(restored.unavailable_guilds().is_empty());
+
+        let guild = restored.guild(1).unwrap();
+        assert_eq!(guild.name, "guild");
+        assert_eq!(guild.member_count, 1);
+        assert_eq!(guild.members[&UserId(7)].nick.as_deref(), Some("member"));
+
+        let message = restored.message(2, 3).unwrap();
+        assert_eq!(message.content, "message");
+        assert_eq!(message.author.id, UserId(7));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
 }
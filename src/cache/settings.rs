@@ -10,13 +10,59 @@
 /// let mut settings = CacheSettings::new();
 /// settings.max_messages(10);
 /// ```
-#[derive(Clone, Debug, Default)]
+///
+/// Only cache guilds, their roles and channels:
+///
+/// ```rust
+/// use serenity::cache::Settings as CacheSettings;
+///
+/// let mut settings = CacheSettings::new();
+/// settings
+///     .cache_members(false)
+///     .cache_presences(false)
+///     .cache_users(false)
+///     .cache_messages(false)
+///     .cache_voice_states(false)
+///     .cache_emojis(false)
+///     .cache_threads(false);
+/// ```
+#[derive(Clone, Debug)]
 #[non_exhaustive]
 pub struct Settings {
     /// The maximum number of messages to store in a channel's message cache.
     ///
     /// Defaults to 0.
     pub max_messages: usize,
+    /// Whether to cache the members of guilds.
+    ///
+    /// Defaults to true.
+    pub cache_members: bool,
+    /// Whether to cache the presences of users.
+    ///
+    /// Defaults to true.
+    pub cache_presences: bool,
+    /// Whether to cache users.
+    ///
+    /// Defaults to true.
+    pub cache_users: bool,
+    /// Whether to cache messages, up to [`max_messages`] per channel.
+    ///
+    /// Defaults to true.
+    ///
+    /// [`max_messages`]: #structfield.max_messages
+    pub cache_messages: bool,
+    /// Whether to cache the voice states of guilds.
+    ///
+    /// Defaults to true.
+    pub cache_voice_states: bool,
+    /// Whether to cache the emojis and stickers of guilds.
+    ///
+    /// Defaults to true.
+    pub cache_emojis: bool,
+    /// Whether to cache the active threads of guilds.
+    ///
+    /// Defaults to true.
+    pub cache_threads: bool,
 }
 
 impl Settings {
@@ -48,4 +94,96 @@ impl Settings {
 
         self
     }
+
+    /// Sets whether to cache the members of guilds.
+    ///
+    /// Refer to [`cache_members`] for more information.
+    ///
+    /// [`cache_members`]: #structfield.cache_members
+    pub fn cache_members(&mut self, enabled: bool) -> &mut Self {
+        self.cache_members = enabled;
+
+        self
+    }
+
+    /// Sets whether to cache the presences of users.
+    ///
+    /// Refer to [`cache_presences`] for more information.
+    ///
+    /// [`cache_presences`]: #structfield.cache_presences
+    pub fn cache_presences(&mut self, enabled: bool) -> &mut Self {
+        self.cache_presences = enabled;
+
+        self
+    }
+
+    /// Sets whether to cache users.
+    ///
+    /// Refer to [`cache_users`] for more information.
+    ///
+    /// [`cache_users`]: #structfield.cache_users
+    pub fn cache_users(&mut self, enabled: bool) -> &mut Self {
+        self.cache_users = enabled;
+
+        self
+    }
+
+    /// Sets whether to cache messages.
+    ///
+    /// Refer to [`cache_messages`] for more information.
+    ///
+    /// [`cache_messages`]: #structfield.cache_messages
+    pub fn cache_messages(&mut self, enabled: bool) -> &mut Self {
+        self.cache_messages = enabled;
+
+        self
+    }
+
+    /// Sets whether to cache the voice states of guilds.
+    ///
+    /// Refer to [`cache_voice_states`] for more information.
+    ///
+    /// [`cache_voice_states`]: #structfield.cache_voice_states
+    pub fn cache_voice_states(&mut self, enabled: bool) -> &mut Self {
+        self.cache_voice_states = enabled;
+
+        self
+    }
+
+    /// Sets whether to cache the emojis and stickers of guilds.
+    ///
+    /// Refer to [`cache_emojis`] for more information.
+    ///
+    /// [`cache_emojis`]: #structfield.cache_emojis
+    pub fn cache_emojis(&mut self, enabled: bool) -> &mut Self {
+        self.cache_emojis = enabled;
+
+        self
+    }
+
+    /// Sets whether to cache the active threads of guilds.
+    ///
+    /// Refer to [`cache_threads`] for more information.
+    ///
+    /// [`cache_threads`]: #structfield.cache_threads
+    pub fn cache_threads(&mut self, enabled: bool) -> &mut Self {
+        self.cache_threads = enabled;
+
+        self
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            max_messages: 0,
+            cache_members: true,
+            cache_presences: true,
+            cache_users: true,
+            cache_messages: true,
+            cache_voice_states: true,
+            cache_emojis: true,
+            cache_threads: true,
+        }
+    }
 }
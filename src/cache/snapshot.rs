@@ -0,0 +1,203 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::path::{Path, PathBuf};
+use std::{fmt, fs};
+
+use dashmap::DashMap;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tracing::warn;
+
+use super::backend::{CacheBackend, CacheMap, CacheRef, CacheRefMut};
+use crate::internal::prelude::*;
+use crate::json::{from_str, to_string};
+use crate::model::guild::automod::Rule;
+use crate::model::prelude::*;
+
+/// A [`CacheBackend`] keeping the cache in memory, and persisting it to a
+/// JSON file per family of data in a directory.
+///
+/// The files are loaded when the [`Cache`] is built, so a restarted process
+/// starts with the state of the last snapshot, and are written by
+/// [`Cache::flush`]. Other processes - such as a dashboard - may read the files
+/// to inspect the state of the cache.
+///
+/// The [`Client`] never flushes the cache itself, so [`Cache::flush`] must be
+/// called on a schedule, such as from a spawned task, and before shutting
+/// down.
+///
+/// **Note**: The data of a snapshot may be stale. Guilds are sent again by
+/// Discord after identifying, replacing their snapshotted data. Unavailable
+/// guilds are not persisted, as the guilds a shard waits on are only known from
+/// its ready event.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serenity::cache::{Cache, Settings, SnapshotCacheBackend};
+///
+/// # fn run() -> serenity::Result<()> {
+/// let cache = Cache::new_with_backend(Settings::new(), &SnapshotCacheBackend::new("cache"));
+///
+/// // Later, e.g. periodically or before shutting down:
+/// cache.flush()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Cache`]: super::Cache
+/// [`Cache::flush`]: super::Cache::flush
+/// [`Client`]: crate::Client
+#[derive(Clone, Debug)]
+pub struct SnapshotCacheBackend {
+    directory: PathBuf,
+}
+
+impl SnapshotCacheBackend {
+    /// Creates a backend storing its snapshots in the given directory, which
+    /// is created on the first flush if it does not exist.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    /// The directory the snapshots are stored in.
+    #[must_use]
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn open<K, V>(&self, name: &str) -> SnapshotMap<K, V>
+    where
+        K: Eq + Hash + Serialize + DeserializeOwned,
+        V: Serialize + DeserializeOwned,
+    {
+        SnapshotMap::open(self.directory.join(format!("{}.json", name)))
+    }
+}
+
+macro_rules! snapshot_maps {
+    ($($name:ident: $key:ty => $value:ty,)*) => {
+        impl CacheBackend for SnapshotCacheBackend {
+            $(
+                fn $name(&self) -> Box<dyn CacheMap<$key, $value>> {
+                    Box::new(self.open(stringify!($name)))
+                }
+            )*
+        }
+    };
+}
+
+snapshot_maps! {
+    channels: ChannelId => GuildChannel,
+    categories: ChannelId => ChannelCategory,
+    guilds: GuildId => Guild,
+    automod_rules: GuildId => HashMap<RuleId, Rule>,
+    integrations: GuildId => HashMap<IntegrationId, Integration>,
+    thread_members: ChannelId => HashMap<UserId, ThreadMember>,
+    messages: ChannelId => DashMap<MessageId, Message>,
+    message_queue: ChannelId => VecDeque<MessageId>,
+    presences: UserId => Presence,
+    private_channels: ChannelId => PrivateChannel,
+    users: UserId => User,
+}
+
+/// A map of a [`SnapshotCacheBackend`], written to its file on flush.
+struct SnapshotMap<K, V> {
+    path: PathBuf,
+    map: DashMap<K, V>,
+}
+
+impl<K, V> SnapshotMap<K, V>
+where
+    K: Eq + Hash + Serialize + DeserializeOwned,
+    V: Serialize + DeserializeOwned,
+{
+    /// Loads the snapshot at the path, starting empty if there is none or it
+    /// is invalid.
+    fn open(path: PathBuf) -> Self {
+        let map = match fs::read_to_string(&path) {
+            Ok(mut contents) => from_str(&mut contents).unwrap_or_else(|why| {
+                warn!("Ignoring invalid cache snapshot {}: {:?}", path.display(), why);
+
+                DashMap::new()
+            }),
+            Err(_) => DashMap::new(),
+        };
+
+        Self {
+            path,
+            map,
+        }
+    }
+}
+
+impl<K: Eq + Hash, V> fmt::Debug for SnapshotMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SnapshotMap")
+            .field("path", &self.path)
+            .field("len", &self.map.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<K, V> CacheMap<K, V> for SnapshotMap<K, V>
+where
+    K: Eq + Hash + fmt::Debug + Send + Sync + Serialize,
+    V: fmt::Debug + Send + Sync + Serialize,
+{
+    fn get(&self, key: &K) -> Option<CacheRef<'_, V>> {
+        CacheMap::get(&self.map, key)
+    }
+
+    fn get_mut(&self, key: &K) -> Option<CacheRefMut<'_, V>> {
+        CacheMap::get_mut(&self.map, key)
+    }
+
+    fn get_or_insert_with(&self, key: K, default: &mut dyn FnMut() -> V) -> CacheRefMut<'_, V> {
+        self.map.get_or_insert_with(key, default)
+    }
+
+    fn insert(&self, key: K, value: V) -> Option<V> {
+        CacheMap::insert(&self.map, key, value)
+    }
+
+    fn remove(&self, key: &K) -> Option<V> {
+        CacheMap::remove(&self.map, key)
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        CacheMap::contains_key(&self.map, key)
+    }
+
+    fn len(&self) -> usize {
+        CacheMap::len(&self.map)
+    }
+
+    fn any(&self, f: &mut dyn FnMut(&K, &V) -> bool) -> bool {
+        CacheMap::any(&self.map, f)
+    }
+
+    fn any_mut(&self, f: &mut dyn FnMut(&K, &mut V) -> bool) -> bool {
+        self.map.any_mut(f)
+    }
+
+    fn retain(&self, f: &mut dyn FnMut(&K, &mut V) -> bool) {
+        CacheMap::retain(&self.map, f);
+    }
+
+    /// Writes the map to a temporary file, then moves it over the snapshot,
+    /// so that readers never see a partially written snapshot.
+    fn flush(&self) -> Result<()> {
+        if let Some(directory) = self.path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+
+        let temporary = self.path.with_extension("json.tmp");
+        fs::write(&temporary, to_string(&self.map)?)?;
+        fs::rename(&temporary, &self.path)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::VecDeque;
+
+use async_tungstenite::tungstenite::Message;
+use tokio::time::{Duration, Instant};
+use tracing::warn;
+
+use super::ChunkGuildFilter;
+use crate::json::Value;
+use crate::model::id::GuildId;
+
+/// The number of commands the gateway accepts per [`WINDOW`].
+const LIMIT: usize = 120;
+/// The window of the gateway's command ratelimit.
+const WINDOW: Duration = Duration::from_secs(60);
+/// The heartbeat interval assumed before the gateway sends HELLO.
+const DEFAULT_HEARTBEAT_INTERVAL: Duration = Duration::from_millis(41_250);
+/// The number of commands that may wait to be sent, enough for a guild member
+/// request for each of the 2500 guilds of a shard, twice over.
+const MAX_QUEUED: usize = 5_000;
+
+/// Metrics about the queue of outbound gateway commands of a shard.
+///
+/// Commands such as presence updates and guild member requests are queued by
+/// the [`ShardRunner`] once the shard would exceed the gateway's limit of 120
+/// commands per 60 seconds, and are sent as the limit allows.
+///
+/// [`ShardRunner`]: super::ShardRunner
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub struct CommandQueueMetrics {
+    /// The number of commands currently waiting to be sent.
+    pub queued: usize,
+    /// The highest number of commands that have been waiting at once.
+    pub peak_queued: usize,
+    /// The total number of commands that had to wait to be sent.
+    pub delayed: u64,
+    /// The total number of commands dropped because the queue was full.
+    pub dropped: u64,
+}
+
+/// An outbound gateway command waiting to be sent.
+#[derive(Debug)]
+pub(crate) enum QueuedCommand {
+    ChunkGuild {
+        guild_id: GuildId,
+        limit: Option<u16>,
+        filter: ChunkGuildFilter,
+        nonce: Option<String>,
+    },
+    Json(Value),
+    Message(Message),
+    /// Sends the shard's presence as it is at the time of sending.
+    UpdatePresence,
+}
+
+/// A per-shard queue of outbound gateway commands, limited to the gateway's
+/// command ratelimit.
+///
+/// Part of the limit is reserved for the heartbeats a shard sends by itself,
+/// as well as an IDENTIFY or RESUME when reconnecting, so that queued commands
+/// can never crowd them out.
+#[derive(Debug, Default)]
+pub(crate) struct CommandQueue {
+    commands: VecDeque<QueuedCommand>,
+    // instants at which commands were sent within the last window
+    sent: VecDeque<Instant>,
+    // the number of commands at the front of the queue counted as delayed
+    delayed: usize,
+    metrics: CommandQueueMetrics,
+}
+
+impl CommandQueue {
+    /// Queues a command behind any others waiting, dropping the oldest one
+    /// if the queue is full.
+    ///
+    /// A presence update is not queued again if one is already waiting, as it
+    /// sends the latest presence anyway.
+    pub(crate) fn push(&mut self, command: QueuedCommand) {
+        if matches!(command, QueuedCommand::UpdatePresence)
+            && self.commands.iter().any(|c| matches!(c, QueuedCommand::UpdatePresence))
+        {
+            return;
+        }
+
+        if self.commands.len() >= MAX_QUEUED {
+            warn!("Gateway command queue is full, dropping the oldest command");
+
+            self.commands.pop_front();
+            self.delayed = self.delayed.saturating_sub(1);
+            self.metrics.dropped += 1;
+        }
+
+        self.commands.push_back(command);
+    }
+
+    /// Forgets the commands sent so far, as the ratelimit applies to each
+    /// connection separately. Called when the shard connects anew.
+    pub(crate) fn reset_window(&mut self) {
+        self.sent.clear();
+    }
+
+    /// Takes the next command if the ratelimit allows it to be sent at
+    /// `now`, counting it as sent.
+    pub(crate) fn pop(
+        &mut self,
+        heartbeat_interval: Option<Duration>,
+        now: Instant,
+    ) -> Option<QueuedCommand> {
+        while self.sent.front().map_or(false, |&sent| now.duration_since(sent) >= WINDOW) {
+            self.sent.pop_front();
+        }
+
+        let command = if self.sent.len() < Self::available(heartbeat_interval) {
+            self.commands.pop_front()
+        } else {
+            // Everything waiting is now known to have been delayed.
+            self.metrics.delayed += (self.commands.len() - self.delayed) as u64;
+            self.delayed = self.commands.len();
+
+            None
+        };
+
+        if command.is_some() {
+            self.sent.push_back(now);
+            self.delayed = self.delayed.saturating_sub(1);
+        }
+
+        self.metrics.queued = self.commands.len();
+        self.metrics.peak_queued = self.metrics.peak_queued.max(self.metrics.queued);
+
+        command
+    }
+
+    pub(crate) fn metrics(&self) -> CommandQueueMetrics {
+        self.metrics
+    }
+
+    /// The number of commands that may be sent per window, less the headroom
+    /// reserved for heartbeats and an IDENTIFY or RESUME.
+    fn available(heartbeat_interval: Option<Duration>) -> usize {
+        let interval = heartbeat_interval
+            .filter(|interval| !interval.is_zero())
+            .unwrap_or(DEFAULT_HEARTBEAT_INTERVAL);
+        let heartbeats = (WINDOW.as_millis() / interval.as_millis()) as usize + 1;
+
+        // One more for a heartbeat requested by the gateway, and one for
+        // identifying or resuming.
+        LIMIT.saturating_sub(heartbeats + 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::time::{Duration, Instant};
+
+    use super::{CommandQueue, QueuedCommand, MAX_QUEUED};
+    use crate::json::NULL;
+
+    #[test]
+    fn test_command_queue() {
+        let interval = Some(Duration::from_millis(41_250));
+        let available = CommandQueue::available(interval);
+        assert_eq!(available, 116);
+
+        let mut queue = CommandQueue::default();
+        let now = Instant::now();
+
+        let mut sent = 0;
+        for _ in 0..available + 2 {
+            queue.push(QueuedCommand::Json(NULL));
+
+            while queue.pop(interval, now).is_some() {
+                sent += 1;
+            }
+        }
+
+        // Only one presence update is queued at a time.
+        queue.push(QueuedCommand::UpdatePresence);
+        queue.push(QueuedCommand::UpdatePresence);
+        assert!(queue.pop(interval, now).is_none());
+
+        assert_eq!(sent, available);
+        assert_eq!(queue.metrics().queued, 3);
+        assert_eq!(queue.metrics().delayed, 3);
+
+        let later = now + Duration::from_secs(60);
+        while queue.pop(interval, later).is_some() {}
+
+        assert_eq!(queue.metrics().queued, 0);
+        assert_eq!(queue.metrics().peak_queued, 3);
+        assert_eq!(queue.metrics().delayed, 3);
+    }
+
+    #[test]
+    fn test_command_queue_bounds() {
+        let interval = Some(Duration::from_millis(41_250));
+        let mut queue = CommandQueue::default();
+        let now = Instant::now();
+
+        for _ in 0..MAX_QUEUED + 2 {
+            queue.push(QueuedCommand::Json(NULL));
+        }
+
+        assert_eq!(queue.metrics().dropped, 2);
+
+        let mut sent = 0;
+        while queue.pop(interval, now).is_some() {
+            sent += 1;
+        }
+
+        assert_eq!(queue.metrics().queued, MAX_QUEUED - sent);
+
+        // A new connection starts with a fresh window.
+        queue.reset_window();
+        assert!(queue.pop(interval, now).is_some());
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+use tokio::time::{sleep_until, Duration, Instant};
+use tracing::debug;
+
+use super::ShardId;
+
+/// The time to wait between IDENTIFYs of the same identify ratelimit key.
+const WAIT_BETWEEN_IDENTIFIES: Duration = Duration::from_secs(5);
+
+/// A gate that shards pass through right before they IDENTIFY with the gateway.
+///
+/// The gateway allows one IDENTIFY per 5 seconds for each identify ratelimit
+/// key, `shard_id % max_concurrency`, across all connections of a bot. The
+/// [`ShardQueuer`] awaits [`Self::wait`] before starting each shard that will
+/// IDENTIFY rather than RESUME, so that processes running separate shard
+/// ranges - e.g. via [`Client::start_shard_range`] - can share one identify
+/// budget by sharing an implementation backed by a coordinator service or a
+/// lock file.
+///
+/// [`ShardQueuer`]: super::ShardQueuer
+/// [`Client::start_shard_range`]: crate::Client::start_shard_range
+#[async_trait]
+pub trait IdentifyQueue: Send + Sync {
+    /// Waits until the shard may IDENTIFY.
+    ///
+    /// Returning claims the identify slot of the shard's key: the next shard
+    /// of the same key must not be let through until 5 seconds later.
+    async fn wait(&self, shard_id: ShardId, max_concurrency: u64);
+}
+
+/// An [`IdentifyQueue`] spacing IDENTIFYs within the current process.
+///
+/// This is the default queue. Sharing it between several [`Client`]s of the
+/// same bot in one process makes them share an identify budget.
+///
+/// [`Client`]: crate::Client
+#[derive(Debug, Default)]
+pub struct LocalIdentifyQueue {
+    // the instants at which the next shard of each key may identify
+    ready_at: Mutex<HashMap<u64, Instant>>,
+}
+
+impl LocalIdentifyQueue {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl IdentifyQueue for LocalIdentifyQueue {
+    async fn wait(&self, shard_id: ShardId, max_concurrency: u64) {
+        let key = shard_id.0 % max_concurrency.max(1);
+
+        // Claim the slot while holding the lock, so that concurrent waiters of
+        // the same key queue up behind each other.
+        let ready_at = {
+            let mut ready = self.ready_at.lock().await;
+            let now = Instant::now();
+            let at = ready.get(&key).map_or(now, |&at| at.max(now));
+
+            ready.insert(key, at + WAIT_BETWEEN_IDENTIFIES);
+
+            at
+        };
+
+        debug!(
+            "[Identify Queue] Shard {} may identify in {:?}",
+            shard_id,
+            ready_at.saturating_duration_since(Instant::now())
+        );
+
+        sleep_until(ready_at).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::time::{Duration, Instant};
+
+    use super::{IdentifyQueue, LocalIdentifyQueue};
+    use crate::client::bridge::gateway::ShardId;
+
+    #[tokio::test]
+    async fn test_local_identify_queue() {
+        let queue = LocalIdentifyQueue::new();
+        let start = Instant::now();
+
+        // Different keys may identify at once.
+        queue.wait(ShardId(0), 2).await;
+        queue.wait(ShardId(1), 2).await;
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        // The next shard of a key claims the slot 5 seconds after the last.
+        let ready = queue.ready_at.lock().await;
+        assert_eq!(ready.len(), 2);
+        assert!(ready.values().all(|&at| at >= start + Duration::from_secs(5)));
+    }
+}
@@ -44,6 +44,9 @@
 
 pub mod event;
 
+mod command_queue;
+mod identify_queue;
+mod resume_state;
 mod shard_manager;
 mod shard_manager_monitor;
 mod shard_messenger;
@@ -54,6 +57,17 @@ mod shard_runner_message;
 use std::fmt;
 use std::time::Duration as StdDuration;
 
+use serde::{Deserialize, Serialize};
+
+pub use self::command_queue::CommandQueueMetrics;
+pub use self::identify_queue::{IdentifyQueue, LocalIdentifyQueue};
+pub use self::resume_state::{
+    InMemoryResumeStateStore,
+    JsonFileResumeStateStore,
+    ResumeState,
+    ResumeStateStore,
+    ShardResumeInfo,
+};
 pub use self::shard_manager::{ShardManager, ShardManagerOptions};
 pub use self::shard_manager_monitor::{ShardManagerError, ShardManagerMonitor};
 pub use self::shard_messenger::ShardMessenger;
@@ -79,7 +93,12 @@ pub enum ShardManagerMessage {
     /// Indicator that a [`ShardManagerMonitor`] should restart a shard.
     Restart(ShardId),
     /// An update from a shard runner,
-    ShardUpdate { id: ShardId, latency: Option<StdDuration>, stage: ConnectionStage },
+    ShardUpdate {
+        id: ShardId,
+        latency: Option<StdDuration>,
+        stage: ConnectionStage,
+        command_queue: CommandQueueMetrics,
+    },
     /// Indicator that a [`ShardManagerMonitor`] should fully shutdown a shard
     /// without bringing it back up.
     Shutdown(ShardId, u16),
@@ -127,7 +146,7 @@ pub enum ShardQueuerMessage {
 
 /// A light tuplestruct wrapper around a u64 to verify type correctness when
 /// working with the IDs of shards.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct ShardId(pub u64);
 
 impl fmt::Display for ShardId {
@@ -150,6 +169,8 @@ pub struct ShardRunnerInfo {
     pub runner_tx: ShardMessenger,
     /// The current connection stage of the shard.
     pub stage: ConnectionStage,
+    /// Metrics about the shard's queue of outbound gateway commands.
+    pub command_queue: CommandQueueMetrics,
 }
 
 impl AsRef<ShardMessenger> for ShardRunnerInfo {
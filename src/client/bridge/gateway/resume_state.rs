@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use super::ShardId;
+use crate::internal::prelude::*;
+use crate::json::{from_str, to_string};
+
+/// The information required to RESUME a shard's gateway session instead of
+/// IDENTIFYing anew.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct ResumeState {
+    /// The ID of the session, as received in the READY payload.
+    pub session_id: String,
+    /// The sequence number of the last dispatch received on the session.
+    pub seq: u64,
+    /// The gateway URL to resume the session on, as received in the READY
+    /// payload.
+    #[serde(default)]
+    pub resume_gateway_url: Option<String>,
+}
+
+impl ResumeState {
+    #[must_use]
+    pub fn new(
+        session_id: impl Into<String>,
+        seq: u64,
+        resume_gateway_url: Option<String>,
+    ) -> Self {
+        Self {
+            session_id: session_id.into(),
+            seq,
+            resume_gateway_url,
+        }
+    }
+}
+
+/// The [`ResumeState`] of a single shard, as returned by
+/// [`ShardManager::suspend_all`] to hand shards over to another process.
+///
+/// [`ShardManager::suspend_all`]: super::ShardManager::suspend_all
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct ShardResumeInfo {
+    /// The ID of the shard the session belongs to.
+    pub shard_id: ShardId,
+    /// The ID of the session.
+    pub session_id: String,
+    /// The sequence number of the last dispatch received on the session.
+    pub seq: u64,
+    /// The gateway URL to resume the session on.
+    #[serde(default)]
+    pub resume_gateway_url: Option<String>,
+}
+
+impl ShardResumeInfo {
+    #[must_use]
+    pub fn new(shard_id: ShardId, state: ResumeState) -> Self {
+        Self {
+            shard_id,
+            session_id: state.session_id,
+            seq: state.seq,
+            resume_gateway_url: state.resume_gateway_url,
+        }
+    }
+
+    /// Returns the state to resume the shard with.
+    #[must_use]
+    pub fn state(&self) -> ResumeState {
+        ResumeState::new(self.session_id.clone(), self.seq, self.resume_gateway_url.clone())
+    }
+}
+
+/// Storage for the [`ResumeState`] of every shard, keyed by [`ShardId`].
+///
+/// The [`ShardQueuer`] loads a shard's state when booting it, and its
+/// [`ShardRunner`] stores the state after every dispatch and when shutting
+/// down. Sharing a store that outlives the process - such as a
+/// [`JsonFileResumeStateStore`] - between runs allows every shard to RESUME
+/// after a restart.
+///
+/// [`ShardQueuer`]: super::ShardQueuer
+/// [`ShardRunner`]: super::ShardRunner
+#[async_trait]
+pub trait ResumeStateStore: Send + Sync {
+    /// Retrieves the last stored state of a shard, if any.
+    async fn load(&self, shard_id: ShardId) -> Option<ResumeState>;
+
+    /// Stores the current state of a shard.
+    ///
+    /// This is called after every dispatch, so it should be cheap.
+    async fn store(&self, shard_id: ShardId, state: ResumeState);
+
+    /// Removes the state of a shard, as its session can no longer be resumed.
+    async fn remove(&self, shard_id: ShardId);
+
+    /// Persists the stored states.
+    ///
+    /// This is called when a shard's session changes and when a shard shuts
+    /// down. Stores which persist every change in [`Self::store`] need not
+    /// implement this.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the states could not be persisted.
+    async fn flush(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A [`ResumeStateStore`] keeping states in memory.
+///
+/// This is the default store, and allows resuming within the same process,
+/// e.g. when the same store is given to a second [`Client`].
+///
+/// [`Client`]: crate::Client
+#[derive(Debug, Default)]
+pub struct InMemoryResumeStateStore {
+    states: Mutex<HashMap<ShardId, ResumeState>>,
+}
+
+impl InMemoryResumeStateStore {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a copy of all stored states.
+    #[must_use]
+    pub fn states(&self) -> HashMap<ShardId, ResumeState> {
+        self.states.lock().expect("Poisoned lock").clone()
+    }
+}
+
+#[async_trait]
+impl ResumeStateStore for InMemoryResumeStateStore {
+    async fn load(&self, shard_id: ShardId) -> Option<ResumeState> {
+        self.states.lock().expect("Poisoned lock").get(&shard_id).cloned()
+    }
+
+    async fn store(&self, shard_id: ShardId, state: ResumeState) {
+        self.states.lock().expect("Poisoned lock").insert(shard_id, state);
+    }
+
+    async fn remove(&self, shard_id: ShardId) {
+        self.states.lock().expect("Poisoned lock").remove(&shard_id);
+    }
+}
+
+/// A [`ResumeStateStore`] persisting states to a JSON file.
+///
+/// States are kept in memory and written to the file on [`flush`], which the
+/// shard runners call whenever a session changes and on shutdown. A sequence
+/// number that is older than the last dispatch is still valid to resume with:
+/// Discord replays the dispatches missed since then.
+///
+/// [`flush`]: ResumeStateStore::flush
+#[derive(Debug)]
+pub struct JsonFileResumeStateStore {
+    path: PathBuf,
+    states: InMemoryResumeStateStore,
+}
+
+impl JsonFileResumeStateStore {
+    /// Opens the store at the given path, reading previously persisted states
+    /// if the file exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the file exists but could not be read, or
+    /// [`Error::Json`] if it does not contain valid states.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let states = InMemoryResumeStateStore::new();
+
+        if path.exists() {
+            let mut contents = std::fs::read_to_string(&path)?;
+            let infos: Vec<ShardResumeInfo> = from_str(&mut contents)?;
+
+            let mut map = states.states.lock().expect("Poisoned lock");
+            for info in infos {
+                map.insert(info.shard_id, info.state());
+            }
+        }
+
+        Ok(Self {
+            path,
+            states,
+        })
+    }
+
+    /// The path of the file the states are persisted to.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[async_trait]
+impl ResumeStateStore for JsonFileResumeStateStore {
+    async fn load(&self, shard_id: ShardId) -> Option<ResumeState> {
+        self.states.load(shard_id).await
+    }
+
+    async fn store(&self, shard_id: ShardId, state: ResumeState) {
+        self.states.store(shard_id, state).await;
+    }
+
+    async fn remove(&self, shard_id: ShardId) {
+        self.states.remove(shard_id).await;
+    }
+
+    async fn flush(&self) -> Result<()> {
+        let mut infos = self
+            .states
+            .states()
+            .into_iter()
+            .map(|(shard_id, state)| ShardResumeInfo::new(shard_id, state))
+            .collect::<Vec<_>>();
+        infos.sort_by_key(|info| info.shard_id);
+
+        debug!("Writing {} resume states to {}", infos.len(), self.path.display());
+
+        tokio::fs::write(&self.path, to_string(&infos)?).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JsonFileResumeStateStore, ResumeState, ResumeStateStore};
+    use crate::client::bridge::gateway::ShardId;
+
+    #[tokio::test]
+    async fn test_json_file_store_roundtrip() {
+        let path =
+            std::env::temp_dir().join(format!("serenity-resume-{}.json", rand::random::<u32>()));
+
+        let store = JsonFileResumeStateStore::open(&path).unwrap();
+        assert_eq!(store.load(ShardId(0)).await, None);
+
+        let url = Some("wss://gateway-us-east1-b.discord.gg".to_string());
+        store.store(ShardId(0), ResumeState::new("a", 5, None)).await;
+        store.store(ShardId(1), ResumeState::new("b", 7, url.clone())).await;
+        store.remove(ShardId(0)).await;
+        store.flush().await.unwrap();
+
+        let reopened = JsonFileResumeStateStore::open(&path).unwrap();
+        assert_eq!(reopened.load(ShardId(0)).await, None);
+        assert_eq!(reopened.load(ShardId(1)).await, Some(ResumeState::new("b", 7, url)));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
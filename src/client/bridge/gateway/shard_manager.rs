@@ -1,4 +1,5 @@
 use std::collections::{HashMap, VecDeque};
+use std::fmt;
 use std::sync::Arc;
 
 use futures::channel::mpsc::{self, UnboundedReceiver as Receiver, UnboundedSender as Sender};
@@ -7,15 +8,16 @@ use tokio::sync::{Mutex, RwLock};
 use tokio::time::timeout;
 use tracing::{info, instrument, warn};
 use typemap_rev::TypeMap;
-use std::sync::atomic::AtomicU64;
-use std::sync::Mutex as NMutex;
 
 use super::{
+    IdentifyQueue,
+    ResumeStateStore,
     ShardId,
     ShardManagerMessage,
     ShardManagerMonitor,
     ShardQueuer,
     ShardQueuerMessage,
+    ShardResumeInfo,
     ShardRunnerInfo,
 };
 #[cfg(feature = "voice")]
@@ -23,6 +25,7 @@ use crate::client::bridge::voice::VoiceGatewayManager;
 use crate::client::{EventHandler, RawEventHandler};
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
+use crate::gateway::GatewayParams;
 use crate::internal::prelude::*;
 use crate::internal::tokio::spawn_named;
 use crate::model::gateway::GatewayIntents;
@@ -53,9 +56,17 @@ use crate::CacheAndHttp;
 /// use std::env;
 /// use std::sync::Arc;
 ///
-/// use serenity::client::bridge::gateway::{ShardManager, ShardManagerOptions};
+/// use serenity::client::bridge::gateway::{
+///     IdentifyQueue,
+///     InMemoryResumeStateStore,
+///     LocalIdentifyQueue,
+///     ResumeStateStore,
+///     ShardManager,
+///     ShardManagerOptions,
+/// };
 /// use serenity::client::{EventHandler, RawEventHandler};
 /// use serenity::framework::{Framework, StandardFramework};
+/// use serenity::gateway::GatewayParams;
 /// use serenity::http::Http;
 /// use serenity::model::gateway::GatewayIntents;
 /// use serenity::prelude::*;
@@ -74,6 +85,8 @@ use crate::CacheAndHttp;
 /// let event_handler = Arc::new(Handler) as Arc<dyn EventHandler>;
 /// let framework =
 ///     Arc::new(StandardFramework::new()) as Arc<dyn Framework + Send + Sync + 'static>;
+/// let resume_state_store = Arc::new(InMemoryResumeStateStore::new()) as Arc<dyn ResumeStateStore>;
+/// let identify_queue = Arc::new(LocalIdentifyQueue::new()) as Arc<dyn IdentifyQueue>;
 ///
 /// ShardManager::new(ShardManagerOptions {
 ///     data: &data,
@@ -89,15 +102,17 @@ use crate::CacheAndHttp;
 ///     # #[cfg(feature = "voice")]
 ///     # voice_manager: &None,
 ///     ws_url: &gateway_url,
+///     gateway_params: GatewayParams::default(),
 ///     # cache_and_http: &cache_and_http,
 ///     intents: GatewayIntents::non_privileged(),
+///     resume_state_store: &resume_state_store,
+///     identify_queue: &identify_queue,
 /// });
 /// #     Ok(())
 /// # }
 /// ```
 ///
 /// [`Client`]: crate::Client
-#[derive(Debug)]
 pub struct ShardManager {
     monitor_tx: Sender<ShardManagerMessage>,
     /// The shard runners currently managed.
@@ -114,6 +129,21 @@ pub struct ShardManager {
     shard_total: u64,
     shard_queuer: Sender<ShardQueuerMessage>,
     shard_shutdown: Receiver<ShardId>,
+    resume_state_store: Arc<dyn ResumeStateStore>,
+}
+
+impl fmt::Debug for ShardManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShardManager")
+            .field("monitor_tx", &self.monitor_tx)
+            .field("runners", &self.runners)
+            .field("shard_index", &self.shard_index)
+            .field("shard_init", &self.shard_init)
+            .field("shard_total", &self.shard_total)
+            .field("shard_queuer", &self.shard_queuer)
+            .field("shard_shutdown", &self.shard_shutdown)
+            .finish_non_exhaustive()
+    }
 }
 
 impl ShardManager {
@@ -132,7 +162,9 @@ impl ShardManager {
             raw_event_handler: opt.raw_event_handler.as_ref().map(Arc::clone),
             #[cfg(feature = "framework")]
             framework: Arc::clone(opt.framework),
-            last_start: None,
+            identify_queue: Arc::clone(opt.identify_queue),
+            session_start_limit: None,
+            session_start_reset: None,
             manager_tx: thread_tx.clone(),
             queue: VecDeque::new(),
             runners: Arc::clone(&runners),
@@ -140,15 +172,13 @@ impl ShardManager {
             #[cfg(feature = "voice")]
             voice_manager: opt.voice_manager.clone(),
             ws_url: Arc::clone(opt.ws_url),
+            gateway_params: opt.gateway_params,
             cache_and_http: Arc::clone(opt.cache_and_http),
             intents: opt.intents,
-            session_id: Arc::clone(&opt.session_id),
-            seq_num: Arc::clone(&opt.seq_num),
+            resume_state_store: Arc::clone(opt.resume_state_store),
         };
 
-        spawn_named("shard_queuer::run", async move {
-            shard_queuer.run().await
-        });
+        spawn_named("shard_queuer::run", async move { shard_queuer.run().await });
 
         let manager = Arc::new(Mutex::new(Self {
             monitor_tx: thread_tx,
@@ -158,6 +188,7 @@ impl ShardManager {
             shard_total: opt.shard_total,
             shard_shutdown: shutdown_recv,
             runners,
+            resume_state_store: Arc::clone(opt.resume_state_store),
         }));
 
         (Arc::clone(&manager), ShardManagerMonitor {
@@ -274,13 +305,18 @@ impl ShardManager {
     /// stopped.
     #[instrument(skip(self))]
     pub async fn shutdown(&mut self, shard_id: ShardId, code: u16) {
+        self.shutdown_shard(shard_id, code).await;
+    }
+
+    // Shuts down a shard, returning whether its runner was seen to stop.
+    async fn shutdown_shard(&mut self, shard_id: ShardId, code: u16) -> bool {
         const TIMEOUT: tokio::time::Duration = tokio::time::Duration::from_secs(5);
 
         info!("Shutting down shard {}", shard_id);
 
         drop(self.shard_queuer.unbounded_send(ShardQueuerMessage::ShutdownShard(shard_id, code)));
 
-        match timeout(TIMEOUT, self.shard_shutdown.next()).await {
+        let stopped = match timeout(TIMEOUT, self.shard_shutdown.next()).await {
             Ok(Some(shutdown_shard_id)) => {
                 if shutdown_shard_id != shard_id {
                     warn!(
@@ -288,14 +324,20 @@ impl ShardManager {
                         shard_id,
                     );
                 }
+
+                shutdown_shard_id == shard_id
             },
-            Ok(None) => (),
+            Ok(None) => false,
             Err(why) => {
                 warn!("Failed to cleanly shutdown shard {}, reached timeout: {:?}", shard_id, why);
+
+                false
             },
-        }
+        };
 
         self.runners.lock().await.remove(&shard_id);
+
+        stopped
     }
 
     /// Sends a shutdown message for all shards that the manager is responsible
@@ -326,6 +368,80 @@ impl ShardManager {
         drop(self.monitor_tx.unbounded_send(ShardManagerMessage::ShutdownInitiated));
     }
 
+    /// Suspends all shards that the manager is responsible for, so that their
+    /// sessions can be resumed by another process.
+    ///
+    /// Unlike [`Self::shutdown_all`], this returns the resume information of
+    /// every shard once its runner has stopped. Shards whose runner does not
+    /// stop in time are left out, as their sessions may still be in use. The sockets are closed with a
+    /// non-1000 close code, which keeps the sessions valid. Give the returned
+    /// information to [`ClientBuilder::resume_shards`] in the new process to
+    /// resume each shard without re-identifying.
+    ///
+    /// # Examples
+    ///
+    /// Handing the shards over to the next process through a file:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// struct Handler;
+    ///
+    /// impl EventHandler for Handler {}
+    ///
+    /// let token = std::env::var("DISCORD_TOKEN")?;
+    /// let client = Client::builder(&token, GatewayIntents::default()).event_handler(Handler).await?;
+    ///
+    /// // ...
+    ///
+    /// let resume_info = client.shard_manager.lock().await.suspend_all().await;
+    /// std::fs::write("shards.json", serde_json::to_string(&resume_info)?)?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ClientBuilder::resume_shards`]: crate::client::ClientBuilder::resume_shards
+    #[instrument(skip(self))]
+    pub async fn suspend_all(&mut self) -> Vec<ShardResumeInfo> {
+        let keys = self.shards_instantiated().await;
+
+        info!("Suspending all shards");
+
+        // A runner that did not stop in time may still be receiving events,
+        // so its session is left out rather than handed over with a stale
+        // sequence.
+        let mut stopped = Vec::with_capacity(keys.len());
+        for shard_id in keys {
+            if self.shutdown_shard(shard_id, 4000).await {
+                stopped.push(shard_id);
+            } else {
+                warn!("Shard {} did not stop, so is not suspended", shard_id);
+            }
+        }
+
+        drop(self.shard_queuer.unbounded_send(ShardQueuerMessage::Shutdown));
+        drop(self.monitor_tx.unbounded_send(ShardManagerMessage::ShutdownInitiated));
+
+        if let Err(why) = self.resume_state_store.flush().await {
+            warn!("Failed to flush resume states: {:?}", why);
+        }
+
+        let mut resume_info = Vec::with_capacity(stopped.len());
+
+        for shard_id in stopped {
+            if let Some(state) = self.resume_state_store.load(shard_id).await {
+                resume_info.push(ShardResumeInfo::new(shard_id, state));
+            } else {
+                warn!("Shard {} has no session to resume", shard_id);
+            }
+        }
+
+        resume_info.sort_by_key(|info| info.shard_id);
+
+        resume_info
+    }
+
     #[instrument(skip(self))]
     fn boot(&mut self, shard_info: [ShardId; 2]) {
         info!("Telling shard queuer to start shard {}", shard_info[0]);
@@ -361,8 +477,9 @@ pub struct ShardManagerOptions<'a> {
     #[cfg(feature = "voice")]
     pub voice_manager: &'a Option<Arc<dyn VoiceGatewayManager + Send + Sync + 'static>>,
     pub ws_url: &'a Arc<Mutex<String>>,
+    pub gateway_params: GatewayParams,
     pub cache_and_http: &'a Arc<CacheAndHttp>,
     pub intents: GatewayIntents,
-    pub session_id: Arc<NMutex<Option<String>>>,
-    pub seq_num: Arc<AtomicU64>,
+    pub resume_state_store: &'a Arc<dyn ResumeStateStore>,
+    pub identify_queue: &'a Arc<dyn IdentifyQueue>,
 }
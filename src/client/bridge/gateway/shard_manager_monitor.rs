@@ -67,6 +67,7 @@ impl ShardManagerMonitor {
                     id,
                     latency,
                     stage,
+                    command_queue,
                 } => {
                     let manager = self.manager.lock().await;
                     let mut runners = manager.runners.lock().await;
@@ -74,6 +75,7 @@ impl ShardManagerMonitor {
                     if let Some(runner) = runners.get_mut(&id) {
                         runner.latency = latency;
                         runner.stage = stage;
+                        runner.command_queue = command_queue;
                     }
                 },
                 ShardManagerMessage::Shutdown(shard_id, code) => {
@@ -1,16 +1,19 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 
 use futures::channel::mpsc::{UnboundedReceiver as Receiver, UnboundedSender as Sender};
+use futures::future::join_all;
 use futures::StreamExt;
 use tokio::sync::{Mutex, RwLock};
-use tokio::time::{sleep, timeout, Duration, Instant};
+use tokio::time::{timeout, Duration, Instant};
 use tracing::{debug, info, instrument, warn};
 use typemap_rev::TypeMap;
-use std::sync::atomic::AtomicU64;
-use std::sync::Mutex as NMutex;
 
 use super::{
+    CommandQueueMetrics,
+    IdentifyQueue,
+    ResumeState,
+    ResumeStateStore,
     ShardClientMessage,
     ShardId,
     ShardManagerMessage,
@@ -25,10 +28,10 @@ use crate::client::bridge::voice::VoiceGatewayManager;
 use crate::client::{EventHandler, RawEventHandler};
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
-use crate::gateway::{ConnectionStage, InterMessage, Shard};
+use crate::gateway::{ConnectionStage, GatewayParams, InterMessage, Shard};
 use crate::internal::prelude::*;
 use crate::internal::tokio::spawn_named;
-use crate::model::gateway::GatewayIntents;
+use crate::model::gateway::{GatewayIntents, SessionStartLimit};
 use crate::CacheAndHttp;
 
 const WAIT_BETWEEN_BOOTS_IN_SECONDS: u64 = 5;
@@ -37,8 +40,8 @@ const WAIT_BETWEEN_BOOTS_IN_SECONDS: u64 = 5;
 /// startup of shards.
 ///
 /// A shard queuer instance _should_ be run in its own thread, due to the
-/// blocking nature of the loop itself as well as a 5 second thread sleep
-/// between shard starts.
+/// blocking nature of the loop itself as well as the wait for the
+/// [`IdentifyQueue`] between shard starts of the same identify ratelimit key.
 pub struct ShardQueuer {
     /// A copy of [`Client::data`] to be given to runners for contextual
     /// dispatching.
@@ -58,10 +61,19 @@ pub struct ShardQueuer {
     /// A copy of the framework
     #[cfg(feature = "framework")]
     pub framework: Arc<dyn Framework + Send + Sync>,
-    /// The instant that a shard was last started.
+    /// The queue every shard waits in before it is started, to space out
+    /// IDENTIFYs of the same identify ratelimit key
+    /// (`shard_id % max_concurrency`).
+    pub identify_queue: Arc<dyn IdentifyQueue>,
+    /// The session start limit, retrieved from the gateway before the first
+    /// shard is started.
     ///
-    /// This is used to determine how long to wait between shard IDENTIFYs.
-    pub last_start: Option<Instant>,
+    /// Its `remaining` count is decremented locally as shards are started
+    /// without a resume state.
+    pub session_start_limit: Option<SessionStartLimit>,
+    /// The instant at which the [`Self::session_start_limit`] resets, or at
+    /// which to retry retrieving it.
+    pub session_start_reset: Option<Instant>,
     /// A copy of the sender channel to communicate with the
     /// [`ShardManagerMonitor`].
     ///
@@ -80,10 +92,12 @@ pub struct ShardQueuer {
     pub voice_manager: Option<Arc<dyn VoiceGatewayManager + Send + Sync + 'static>>,
     /// A copy of the URL to use to connect to the gateway.
     pub ws_url: Arc<Mutex<String>>,
+    /// The query parameters to connect to the gateway with.
+    pub gateway_params: GatewayParams,
     pub cache_and_http: Arc<CacheAndHttp>,
     pub intents: GatewayIntents,
-    pub session_id: Arc<NMutex<Option<String>>>,
-    pub seq_num: Arc<AtomicU64>,
+    /// The store that shards' resume states are loaded from when booting.
+    pub resume_state_store: Arc<dyn ResumeStateStore>,
 }
 
 impl ShardQueuer {
@@ -92,12 +106,21 @@ impl ShardQueuer {
     /// This will loop over the internal [`Self::rx`] for [`ShardQueuerMessage`]s,
     /// blocking for messages on what to do.
     ///
-    /// If a [`ShardQueuerMessage::Start`] is received, this will:
+    /// If a [`ShardQueuerMessage::Start`] is received, the shard is queued.
+    /// Queued shards are then started in batches, where:
     ///
-    /// 1. Check how much time has passed since the last shard was started
-    /// 2. If the amount of time is less than the ratelimit, it will sleep until
-    /// that time has passed
-    /// 3. Start the shard by ID
+    /// 1. Shards are grouped into the identify ratelimit keys of the session
+    /// start limit, `shard_id % max_concurrency`
+    /// 2. One shard per key is started at a time, and the shards of different
+    /// keys are started in parallel
+    /// 3. Each shard waits in the [`Self::identify_queue`] before being
+    /// started to IDENTIFY, which by default lets another shard of the same
+    /// key through only once 5 seconds have passed since the last
+    ///
+    /// If no session starts remain, starting shards is paused until the limit
+    /// resets. Shards with a stored resume state are not grouped and started
+    /// right away instead, as resuming a session does not count against the
+    /// limit.
     ///
     /// If a [`ShardQueuerMessage::Shutdown`] is received, this will return and
     /// the loop will be over.
@@ -106,93 +129,228 @@ impl ShardQueuer {
     /// nature of the loop.
     #[instrument(skip(self))]
     pub async fn run(&mut self) {
-        // The duration to timeout from reads over the Rx channel. This can be
-        // done in a loop, and if the read times out then a shard can be
-        // started if one is presently waiting in the queue.
+        // The duration to timeout from reads over the Rx channel when no
+        // shards are queued.
         const TIMEOUT: Duration = Duration::from_secs(WAIT_BETWEEN_BOOTS_IN_SECONDS);
 
         loop {
-            match timeout(TIMEOUT, self.rx.next()).await {
-                Ok(Some(ShardQueuerMessage::Shutdown)) => {
-                    debug!("[Shard Queuer] Received to shutdown.");
-                    self.shutdown_runners().await;
+            let wait = if self.queue.is_empty() { TIMEOUT } else { self.next_start_in() };
 
-                    break;
-                },
-                Ok(Some(ShardQueuerMessage::ShutdownShard(shard, code))) => {
-                    debug!("[Shard Queuer] Received to shutdown shard {} with {}.", shard.0, code);
-                    self.shutdown(shard, code).await;
-                },
-                Ok(Some(ShardQueuerMessage::Start(id, total))) => {
-                    debug!("[Shard Queuer] Received to start shard {} of {}.", id.0, total.0);
-                    self.checked_start(id.0, total.0).await;
-                },
-                Ok(None) => break,
-                Err(_) => {
-                    if let Some((id, total)) = self.queue.pop_front() {
-                        self.checked_start(id, total).await;
+            match timeout(wait, self.rx.next()).await {
+                Ok(Some(message)) => {
+                    if !self.handle_message(message).await {
+                        break;
                     }
                 },
+                Ok(None) => break,
+                Err(_) => {},
+            }
+
+            // Take in every message that is already waiting, so that shards
+            // requested together can be started together.
+            loop {
+                match self.rx.try_recv() {
+                    Ok(message) => {
+                        if !self.handle_message(message).await {
+                            return;
+                        }
+                    },
+                    Err(why) if why.is_closed() => return,
+                    Err(_) => break,
+                }
+            }
+
+            // Shards that will RESUME are started even while no session
+            // starts remain.
+            if !self.queue.is_empty() {
+                self.start_batch().await;
             }
         }
     }
 
+    // Handles a message, returning whether the queuer should continue.
     #[instrument(skip(self))]
-    async fn check_last_start(&mut self) {
-        let instant = match self.last_start {
-            Some(instant) => instant,
-            None => return,
-        };
+    async fn handle_message(&mut self, message: ShardQueuerMessage) -> bool {
+        match message {
+            ShardQueuerMessage::Shutdown => {
+                debug!("[Shard Queuer] Received to shutdown.");
+                self.shutdown_runners().await;
+
+                return false;
+            },
+            ShardQueuerMessage::ShutdownShard(shard, code) => {
+                debug!("[Shard Queuer] Received to shutdown shard {} with {}.", shard.0, code);
+                self.shutdown(shard, code).await;
+            },
+            ShardQueuerMessage::Start(id, total) => {
+                debug!("[Shard Queuer] Received to start shard {} of {}.", id.0, total.0);
+                self.queue.push_back((id.0, total.0));
+            },
+        }
+
+        true
+    }
+
+    fn max_concurrency(&self) -> u64 {
+        self.session_start_limit.as_ref().map_or(1, |limit| limit.max_concurrency.max(1))
+    }
+
+    // How long to wait until the queued shards may be started, which is only
+    // once the session start limit resets if no starts remain.
+    fn next_start_in(&self) -> Duration {
+        match (&self.session_start_limit, self.session_start_reset) {
+            (Some(limit), Some(reset)) if limit.remaining == 0 => {
+                reset.saturating_duration_since(Instant::now())
+            },
+            _ => Duration::ZERO,
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn refresh_session_start_limit(&mut self) {
+        match self.cache_and_http.http.get_bot_gateway().await {
+            Ok(gateway) => {
+                let limit = gateway.session_start_limit;
+                debug!("[Shard Queuer] Session start limit: {:?}", limit);
+
+                self.session_start_reset =
+                    Some(Instant::now() + Duration::from_millis(limit.reset_after));
+                self.session_start_limit = Some(limit);
+            },
+            Err(why) => {
+                warn!("[Shard Queuer] Err getting the session start limit: {:?}", why);
+
+                // Start shards one at a time until the limit can be retrieved.
+                self.session_start_reset =
+                    Some(Instant::now() + Duration::from_secs(WAIT_BETWEEN_BOOTS_IN_SECONDS));
+            },
+        }
+    }
+
+    // Starts every queued shard that will RESUME, and one queued shard per
+    // identify ratelimit key that will IDENTIFY, in parallel.
+    #[instrument(skip(self))]
+    async fn start_batch(&mut self) {
+        let mut batch = Vec::new();
+
+        // Resuming a session does not use a session start, so shards with a
+        // stored resume state are neither limited nor counted.
+        for (id, total) in std::mem::take(&mut self.queue) {
+            match self.resume_state_store.load(ShardId(id)).await {
+                Some(resume_state) => batch.push((id, total, Some(resume_state))),
+                None => self.queue.push_back((id, total)),
+            }
+        }
 
-        // We must wait 5 seconds between IDENTIFYs to avoid session
-        // invalidations.
-        let duration = Duration::from_secs(WAIT_BETWEEN_BOOTS_IN_SECONDS);
-        let elapsed = instant.elapsed();
+        let identifying = self.take_identify_batch().await;
+        let identify_count = identifying.len() as u64;
+        batch.extend(identifying.into_iter().map(|(id, total)| (id, total, None)));
 
-        if elapsed >= duration {
-            return;
+        let max_concurrency = self.max_concurrency();
+        let results = join_all(batch.iter().map(|(id, total, resume_state)| {
+            self.checked_start(*id, *total, max_concurrency, resume_state.clone())
+        }))
+        .await;
+
+        for (&(id, total, _), started) in batch.iter().zip(results) {
+            if !started {
+                info!("[Shard Queuer] Re-queueing start of shard {}", id);
+
+                self.queue.push_back((id, total));
+            }
+        }
+
+        if let Some(limit) = &mut self.session_start_limit {
+            limit.remaining = limit.remaining.saturating_sub(identify_count);
+        }
+    }
+
+    // Takes one queued shard per identify ratelimit key out of the queue, as
+    // far as the session start limit allows.
+    async fn take_identify_batch(&mut self) -> Vec<(u64, u64)> {
+        if self.queue.is_empty() {
+            return Vec::new();
+        }
+
+        let reset_passed = self.session_start_reset.map_or(true, |reset| reset <= Instant::now());
+        let exhausted = self.session_start_limit.as_ref().map_or(true, |l| l.remaining == 0);
+
+        if exhausted && reset_passed {
+            self.refresh_session_start_limit().await;
         }
 
-        let to_sleep = duration - elapsed;
+        let remaining = match &self.session_start_limit {
+            Some(limit) if limit.remaining == 0 => {
+                warn!("[Shard Queuer] No session starts remaining; pausing until the limit resets");
+
+                return Vec::new();
+            },
+            Some(limit) => limit.remaining,
+            None => u64::MAX,
+        };
+
+        let max_concurrency = self.max_concurrency();
+        let mut keys = HashSet::new();
+        let mut batch = Vec::new();
 
-        sleep(to_sleep).await;
+        let mut i = 0;
+        while i < self.queue.len() && (batch.len() as u64) < remaining {
+            let (id, _) = self.queue[i];
+
+            if keys.insert(id % max_concurrency) {
+                batch.extend(self.queue.remove(i));
+            } else {
+                i += 1;
+            }
+        }
+
+        batch
     }
 
+    // Starts a shard, once the identify queue lets it through if it will
+    // IDENTIFY, returning whether it was started successfully.
     #[instrument(skip(self))]
-    async fn checked_start(&mut self, id: u64, total: u64) {
+    async fn checked_start(
+        &self,
+        id: u64,
+        total: u64,
+        max_concurrency: u64,
+        resume_state: Option<ResumeState>,
+    ) -> bool {
         debug!("[Shard Queuer] Checked start for shard {} out of {}", id, total);
-        self.check_last_start().await;
 
-        if let Err(why) = self.start(id, total).await {
+        // A RESUME does not use an identify slot.
+        if resume_state.is_none() {
+            self.identify_queue.wait(ShardId(id), max_concurrency).await;
+        }
+
+        if let Err(why) = self.start(id, total, resume_state).await {
             warn!("[Shard Queuer] Err starting shard {}: {:?}", id, why);
-            info!("[Shard Queuer] Re-queueing start of shard {}", id);
 
-            self.queue.push_back((id, total));
+            return false;
         }
 
-        self.last_start = Some(Instant::now());
+        true
     }
 
     //#[instrument(skip(self))]
-    async fn start(&mut self, shard_id: u64, shard_total: u64) -> Result<()> {
+    async fn start(
+        &self,
+        shard_id: u64,
+        shard_total: u64,
+        resume_state: Option<ResumeState>,
+    ) -> Result<()> {
         let shard_info = [shard_id, shard_total];
 
-        /*let mut shard = Shard::new(
-            Arc::clone(&self.ws_url),
-            &self.cache_and_http.http.token,
-            shard_info,
-            self.intents,
-        )
-        .await?;*/
-
         let mut shard = Shard::new_resume(
             Arc::clone(&self.ws_url),
             &self.cache_and_http.http.token,
             shard_info,
             self.intents,
-            Arc::clone(&self.session_id),
-            Arc::clone(&self.seq_num),
-        ).await?;
+            self.gateway_params,
+            resume_state,
+        )
+        .await?;
 
         shard.set_http(Arc::clone(&self.cache_and_http.http));
 
@@ -207,12 +365,14 @@ impl ShardQueuer {
             voice_manager: self.voice_manager.clone(),
             shard,
             cache_and_http: Arc::clone(&self.cache_and_http),
+            resume_state_store: Arc::clone(&self.resume_state_store),
         });
 
         let runner_info = ShardRunnerInfo {
             latency: None,
             runner_tx: ShardMessenger::new(runner.runner_tx()),
             stage: ConnectionStage::Disconnected,
+            command_queue: CommandQueueMetrics::default(),
         };
 
         spawn_named("shard_queuer::stop", async move {
@@ -6,13 +6,21 @@ use async_tungstenite::tungstenite::error::Error as TungsteniteError;
 use async_tungstenite::tungstenite::protocol::frame::CloseFrame;
 use futures::channel::mpsc::{self, UnboundedReceiver as Receiver, UnboundedSender as Sender};
 use futures::{SinkExt, StreamExt};
-use serde::Deserialize;
 use tokio::sync::RwLock;
+use tokio::time::{Duration, Instant};
 use tracing::{debug, error, info, instrument, trace, warn};
 use typemap_rev::TypeMap;
 
+use super::command_queue::{CommandQueue, QueuedCommand};
 use super::event::{ClientEvent, ShardStageUpdateEvent};
-use super::{ShardClientMessage, ShardId, ShardManagerMessage, ShardRunnerMessage};
+use super::{
+    ResumeState,
+    ResumeStateStore,
+    ShardClientMessage,
+    ShardId,
+    ShardManagerMessage,
+    ShardRunnerMessage,
+};
 #[cfg(feature = "voice")]
 use crate::client::bridge::voice::VoiceGatewayManager;
 use crate::client::dispatch::{dispatch, DispatchEvent};
@@ -29,9 +37,16 @@ use crate::collector::{
 };
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
-use crate::gateway::{GatewayError, InterMessage, ReconnectType, Shard, ShardAction};
+use crate::gateway::{
+    ConnectionStage,
+    GatewayError,
+    InterMessage,
+    ReconnectType,
+    Shard,
+    ShardAction,
+};
 use crate::internal::prelude::*;
-use crate::internal::ws_impl::{ReceiverExt, SenderExt};
+use crate::internal::ws_impl::SenderExt;
 #[cfg(feature = "collector")]
 use crate::model::application::interaction::Interaction;
 use crate::model::event::{Event, GatewayEvent};
@@ -53,6 +68,11 @@ pub struct ShardRunner {
     #[cfg(feature = "voice")]
     voice_manager: Option<Arc<dyn VoiceGatewayManager + Send + Sync + 'static>>,
     cache_and_http: Arc<CacheAndHttp>,
+    resume_state_store: Arc<dyn ResumeStateStore>,
+    // the resume state last written to the store
+    persisted_resume_state: Option<ResumeState>,
+    // outbound gateway commands waiting for the command ratelimit
+    commands: CommandQueue,
     #[cfg(feature = "collector")]
     event_filters: Vec<EventFilter>,
     #[cfg(feature = "collector")]
@@ -69,6 +89,7 @@ impl ShardRunner {
     /// Creates a new runner for a Shard.
     pub fn new(opt: ShardRunnerOptions) -> Self {
         let (tx, rx) = mpsc::unbounded();
+        let persisted_resume_state = opt.shard.resume_state();
 
         Self {
             runner_rx: rx,
@@ -83,6 +104,9 @@ impl ShardRunner {
             #[cfg(feature = "voice")]
             voice_manager: opt.voice_manager,
             cache_and_http: opt.cache_and_http,
+            resume_state_store: opt.resume_state_store,
+            persisted_resume_state,
+            commands: CommandQueue::default(),
             #[cfg(feature = "collector")]
             event_filters: Vec::new(),
             #[cfg(feature = "collector")]
@@ -101,7 +125,9 @@ impl ShardRunner {
     /// This runs a loop that performs the following in each iteration:
     ///
     /// 1. checks the receiver for [`ShardRunnerMessage`]s, possibly from the
-    /// [`ShardManager`], and if there is one, acts on it.
+    /// [`ShardManager`], and if there is one, acts on it. Gateway commands, such
+    /// as presence updates, are queued and sent as the gateway's command
+    /// ratelimit allows, reserving room for heartbeats.
     ///
     /// 2. checks if a heartbeat should be sent to the discord Gateway, and if
     /// so, sends one.
@@ -129,6 +155,10 @@ impl ShardRunner {
                 return Ok(());
             }
 
+            if !self.send_commands().await {
+                return Ok(());
+            }
+
             // check heartbeat
             if !self.shard.check_heartbeat().await {
                 warn!("[ShardRunner {:?}] Error heartbeating", self.shard.shard_info(),);
@@ -140,6 +170,8 @@ impl ShardRunner {
             let (event, action, successful) = self.recv_event().await?;
             let post = self.shard.stage();
 
+            self.persist_resume_state().await;
+
             if post != pre {
                 self.update_manager();
 
@@ -149,7 +181,7 @@ impl ShardRunner {
                     shard_id: ShardId(self.shard.shard_info()[0]),
                 });
 
-                self.dispatch(DispatchEvent::Client(e)).await;
+                self.dispatch(DispatchEvent::Client(e), false).await;
             }
 
             match action {
@@ -168,7 +200,7 @@ impl ShardRunner {
                         match reconnection_type {
                             ReconnectType::Reidentify => return self.request_restart().await,
                             ReconnectType::Resume => {
-                                if let Err(why) = self.shard.resume().await {
+                                if let Err(why) = self.resume().await {
                                     warn!(
                                         "[ShardRunner {:?}] Resume failed, reidentifying: {:?}",
                                         self.shard.shard_info(),
@@ -184,13 +216,20 @@ impl ShardRunner {
                 None => {},
             }
 
-            if let Some(event) = event {
+            if let Some(mut event) = event {
                 #[cfg(feature = "collector")]
                 {
                     self.handle_filters(&event);
                 }
 
-                self.dispatch(DispatchEvent::Model(event)).await;
+                // Dispatches received while still resuming are replays of
+                // those missed while disconnected, up until RESUMED.
+                let replayed = post == ConnectionStage::Resuming;
+                if let Event::Resumed(ref mut resumed) = event {
+                    resumed.replayed = self.shard.replayed();
+                }
+
+                self.dispatch(DispatchEvent::Model(event), replayed).await;
             }
 
             if !successful && !self.shard.stage().is_connecting() {
@@ -262,7 +301,7 @@ impl ShardRunner {
     async fn action(&mut self, action: &ShardAction) -> Result<()> {
         match *action {
             ShardAction::Reconnect(ReconnectType::Reidentify) => self.request_restart().await,
-            ShardAction::Reconnect(ReconnectType::Resume) => self.shard.resume().await,
+            ShardAction::Reconnect(ReconnectType::Resume) => self.resume().await,
             ShardAction::Heartbeat => self.shard.heartbeat().await,
             ShardAction::Identify => self.shard.identify().await,
         }
@@ -285,6 +324,17 @@ impl ShardRunner {
             return true;
         }
 
+        // A 1000 close code invalidates the session, so it can not be resumed
+        // afterwards.
+        if close_code == 1000 {
+            self.resume_state_store.remove(id).await;
+            self.persisted_resume_state = None;
+        } else {
+            self.persist_resume_state().await;
+        }
+
+        self.flush_resume_states().await;
+
         // Send a Close Frame to Discord, which allows a bot to "log off"
         drop(
             self.shard
@@ -308,6 +358,7 @@ impl ShardRunner {
                     );
                     break;
                 },
+                None => break,
                 _ => continue,
             }
         }
@@ -324,9 +375,48 @@ impl ShardRunner {
         false
     }
 
+    // Writes the shard's resume state to the store if it changed since the
+    // last write, removing it once the session can no longer be resumed.
+    //
+    // The store is flushed whenever the session itself changed.
+    #[instrument(skip(self))]
+    async fn persist_resume_state(&mut self) {
+        let state = self.shard.resume_state();
+
+        if state == self.persisted_resume_state {
+            return;
+        }
+
+        let shard_id = ShardId(self.shard.shard_info()[0]);
+        let session_changed = state.as_ref().map(|s| &s.session_id)
+            != self.persisted_resume_state.as_ref().map(|s| &s.session_id);
+
+        match &state {
+            Some(state) => self.resume_state_store.store(shard_id, state.clone()).await,
+            None => self.resume_state_store.remove(shard_id).await,
+        }
+
+        self.persisted_resume_state = state;
+
+        if session_changed {
+            self.flush_resume_states().await;
+        }
+    }
+
+    #[instrument(skip(self))]
+    async fn flush_resume_states(&self) {
+        if let Err(why) = self.resume_state_store.flush().await {
+            warn!(
+                "[ShardRunner {:?}] Failed to flush resume states: {:?}",
+                self.shard.shard_info(),
+                why
+            );
+        }
+    }
+
     #[inline]
     #[instrument(skip(self, event))]
-    async fn dispatch(&self, event: DispatchEvent) {
+    async fn dispatch(&self, event: DispatchEvent, replayed: bool) {
         dispatch(
             event,
             #[cfg(feature = "framework")]
@@ -336,6 +426,7 @@ impl ShardRunner {
             &self.raw_event_handler,
             &self.runner_tx,
             self.shard.shard_info()[0],
+            replayed,
             Arc::clone(&self.cache_and_http),
         )
         .await;
@@ -390,7 +481,14 @@ impl ShardRunner {
                     filter,
                     nonce,
                 }) => {
-                    self.shard.chunk_guild(guild_id, limit, filter, nonce.as_deref()).await.is_ok()
+                    self.commands.push(QueuedCommand::ChunkGuild {
+                        guild_id,
+                        limit,
+                        filter,
+                        nonce,
+                    });
+
+                    self.send_commands().await
                 },
                 ShardClientMessage::Runner(ShardRunnerMessage::Close(code, reason)) => {
                     let reason = reason.unwrap_or_default();
@@ -401,7 +499,9 @@ impl ShardRunner {
                     self.shard.client.close(Some(close)).await.is_ok()
                 },
                 ShardClientMessage::Runner(ShardRunnerMessage::Message(msg)) => {
-                    self.shard.client.send(msg).await.is_ok()
+                    self.commands.push(QueuedCommand::Message(msg));
+
+                    self.send_commands().await
                 },
                 ShardClientMessage::Runner(ShardRunnerMessage::SetActivity(activity)) => {
                     // To avoid a clone of `activity`, we do a little bit of
@@ -412,24 +512,24 @@ impl ShardRunner {
                     // activity we received over the channel as well as the
                     // online status that the shard already had.
                     //
-                    // We then (attempt to) send the websocket message with the
-                    // status update, expressively returning:
-                    //
-                    // - whether the message successfully sent
-                    // - the original activity we received over the channel
+                    // We then queue the status update, which sends the
+                    // presence the shard has at the time of sending.
                     self.shard.set_activity(activity);
+                    self.commands.push(QueuedCommand::UpdatePresence);
 
-                    self.shard.update_presence().await.is_ok()
+                    self.send_commands().await
                 },
                 ShardClientMessage::Runner(ShardRunnerMessage::SetPresence(status, activity)) => {
                     self.shard.set_presence(status, activity);
+                    self.commands.push(QueuedCommand::UpdatePresence);
 
-                    self.shard.update_presence().await.is_ok()
+                    self.send_commands().await
                 },
                 ShardClientMessage::Runner(ShardRunnerMessage::SetStatus(status)) => {
                     self.shard.set_status(status);
+                    self.commands.push(QueuedCommand::UpdatePresence);
 
-                    self.shard.update_presence().await.is_ok()
+                    self.send_commands().await
                 },
                 #[cfg(feature = "collector")]
                 ShardClientMessage::Runner(ShardRunnerMessage::SetEventFilter(collector)) => {
@@ -468,11 +568,53 @@ impl ShardRunner {
             },
             InterMessage::Json(value) => {
                 // Value must be forwarded over the websocket
-                self.shard.client.send_json(&value).await.is_ok()
+                self.commands.push(QueuedCommand::Json(value));
+
+                self.send_commands().await
             },
         }
     }
 
+    // Sends queued gateway commands for as long as the command ratelimit
+    // allows, leaving the rest queued.
+    //
+    // Returns whether all of the commands were sent successfully.
+    #[instrument(skip(self))]
+    async fn send_commands(&mut self) -> bool {
+        let metrics = self.commands.metrics();
+        let interval = self.shard.heartbeat_interval().map(|&ms| Duration::from_millis(ms));
+
+        while let Some(command) = self.commands.pop(interval, Instant::now()) {
+            let sent = match command {
+                QueuedCommand::ChunkGuild {
+                    guild_id,
+                    limit,
+                    filter,
+                    nonce,
+                } => self.shard.chunk_guild(guild_id, limit, filter, nonce.as_deref()).await,
+                QueuedCommand::Json(value) => {
+                    let encoding = self.shard.gateway_params().encoding;
+
+                    self.shard.client.send_payload(&value, encoding).await
+                },
+                QueuedCommand::Message(msg) => {
+                    self.shard.client.send(msg).await.map_err(From::from)
+                },
+                QueuedCommand::UpdatePresence => self.shard.update_presence().await,
+            };
+
+            if sent.is_err() {
+                return false;
+            }
+        }
+
+        if self.commands.metrics() != metrics {
+            self.update_manager();
+        }
+
+        true
+    }
+
     #[cfg(feature = "voice")]
     #[instrument(skip(self))]
     async fn handle_voice_event(&self, event: &Event) {
@@ -538,16 +680,15 @@ impl ShardRunner {
     /// present event was successful.
     #[instrument(skip(self))]
     async fn recv_event(&mut self) -> Result<(Option<Event>, Option<ShardAction>, bool)> {
-        let gw_event = match self.shard.client.recv_json().await {
-            Ok(Some(value)) => GatewayEvent::deserialize(value).map(Some).map_err(From::from),
-            Ok(None) => Ok(None),
+        let gw_event = match self.shard.recv_event().await {
+            Ok(event) => Ok(event),
             Err(Error::Tungstenite(TungsteniteError::Io(_))) => {
                 debug!("Attempting to auto-reconnect");
 
                 match self.shard.reconnection_type() {
                     ReconnectType::Reidentify => return Ok((None, None, false)),
                     ReconnectType::Resume => {
-                        if let Err(why) = self.shard.resume().await {
+                        if let Err(why) = self.resume().await {
                             warn!("Failed to resume: {:?}", why);
 
                             return Ok((None, None, false));
@@ -634,6 +775,14 @@ impl ShardRunner {
         Ok((event, action, true))
     }
 
+    // Resumes the session on a new connection, which starts with a fresh
+    // command ratelimit.
+    async fn resume(&mut self) -> Result<()> {
+        self.commands.reset_window();
+
+        self.shard.resume().await
+    }
+
     #[instrument(skip(self))]
     async fn request_restart(&mut self) -> Result<()> {
         self.update_manager();
@@ -660,6 +809,7 @@ impl ShardRunner {
             id: ShardId(self.shard.shard_info()[0]),
             latency: self.shard.latency(),
             stage: self.shard.stage(),
+            command_queue: self.commands.metrics(),
         }));
     }
 }
@@ -676,4 +826,5 @@ pub struct ShardRunnerOptions {
     #[cfg(feature = "voice")]
     pub voice_manager: Option<Arc<dyn VoiceGatewayManager + Send + Sync>>,
     pub cache_and_http: Arc<CacheAndHttp>,
+    pub resume_state_store: Arc<dyn ResumeStateStore>,
 }
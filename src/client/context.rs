@@ -40,6 +40,12 @@ pub struct Context {
     pub shard: ShardMessenger,
     /// The ID of the shard this context is related to.
     pub shard_id: u64,
+    /// Whether the event was replayed by Discord after the shard resumed its
+    /// session, having been missed while the shard was disconnected.
+    ///
+    /// The number of replayed events is given in [`ResumedEvent::replayed`]
+    /// once replaying is done.
+    pub replayed: bool,
     pub http: Arc<Http>,
     #[cfg(feature = "cache")]
     pub cache: Arc<Cache>,
@@ -52,12 +58,14 @@ impl Context {
         data: Arc<RwLock<TypeMap>>,
         runner_tx: Sender<InterMessage>,
         shard_id: u64,
+        replayed: bool,
         http: Arc<Http>,
         cache: Arc<Cache>,
     ) -> Context {
         Context {
             shard: ShardMessenger::new(runner_tx),
             shard_id,
+            replayed,
             data,
             http,
             cache,
@@ -68,6 +76,7 @@ impl Context {
     pub fn easy(data: Arc<RwLock<TypeMap>>, shard_id: u64, http: Arc<Http>) -> Context {
         Context {
             shard_id,
+            replayed: false,
             data,
             http,
         }
@@ -79,11 +88,13 @@ impl Context {
         data: Arc<RwLock<TypeMap>>,
         runner_tx: Sender<InterMessage>,
         shard_id: u64,
+        replayed: bool,
         http: Arc<Http>,
     ) -> Context {
         Context {
             shard: ShardMessenger::new(runner_tx),
             shard_id,
+            replayed,
             data,
             http,
         }
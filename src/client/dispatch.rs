@@ -23,8 +23,6 @@ use crate::internal::tokio::spawn_named;
 use crate::model::channel::{Channel, Message};
 use crate::model::event::Event;
 use crate::model::guild::Member;
-#[cfg(feature = "cache")]
-use crate::model::id::GuildId;
 use crate::CacheAndHttp;
 
 #[inline]
@@ -47,10 +45,18 @@ fn context(
     data: &Arc<RwLock<TypeMap>>,
     runner_tx: &Sender<InterMessage>,
     shard_id: u64,
+    replayed: bool,
     http: &Arc<Http>,
     cache: &Arc<Cache>,
 ) -> Context {
-    Context::new(Arc::clone(data), runner_tx.clone(), shard_id, Arc::clone(http), Arc::clone(cache))
+    Context::new(
+        Arc::clone(data),
+        runner_tx.clone(),
+        shard_id,
+        replayed,
+        Arc::clone(http),
+        Arc::clone(cache),
+    )
 }
 
 #[cfg(not(feature = "cache"))]
@@ -58,9 +64,10 @@ fn context(
     data: &Arc<RwLock<TypeMap>>,
     runner_tx: &Sender<InterMessage>,
     shard_id: u64,
+    replayed: bool,
     http: &Arc<Http>,
 ) -> Context {
-    Context::new(Arc::clone(data), runner_tx.clone(), shard_id, Arc::clone(http))
+    Context::new(Arc::clone(data), runner_tx.clone(), shard_id, replayed, Arc::clone(http))
 }
 
 // Once we can use `Box` as part of a pattern, we will reconsider boxing.
@@ -75,6 +82,15 @@ impl DispatchEvent {
     #[instrument(skip(self, cache_and_http))]
     fn update(&mut self, cache_and_http: &Arc<CacheAndHttp>) {
         match self {
+            Self::Model(Event::AutoModerationRuleCreate(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::AutoModerationRuleDelete(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::AutoModerationRuleUpdate(ref mut event)) => {
+                update(cache_and_http, event);
+            },
             Self::Model(Event::ChannelCreate(ref mut event)) => {
                 update(cache_and_http, event);
             },
@@ -84,6 +100,27 @@ impl DispatchEvent {
             Self::Model(Event::ChannelUpdate(ref mut event)) => {
                 update(cache_and_http, event);
             },
+            Self::Model(Event::GuildBanAdd(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::GuildBanRemove(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::GuildScheduledEventCreate(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::GuildScheduledEventDelete(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::GuildScheduledEventUpdate(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::GuildScheduledEventUserAdd(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::GuildScheduledEventUserRemove(ref mut event)) => {
+                update(cache_and_http, event);
+            },
             Self::Model(Event::GuildCreate(ref mut event)) => {
                 update(cache_and_http, event);
             },
@@ -123,11 +160,47 @@ impl DispatchEvent {
             Self::Model(Event::GuildUpdate(ref mut event)) => {
                 update(cache_and_http, event);
             },
+            Self::Model(Event::IntegrationCreate(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::IntegrationDelete(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::IntegrationUpdate(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::StageInstanceCreate(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::StageInstanceDelete(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::StageInstanceUpdate(ref mut event)) => {
+                update(cache_and_http, event);
+            },
             // Already handled by the framework check macro
             Self::Model(Event::MessageCreate(_)) => {},
             Self::Model(Event::MessageUpdate(ref mut event)) => {
                 update(cache_and_http, event);
             },
+            Self::Model(Event::MessageDelete(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::MessageDeleteBulk(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::ReactionAdd(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::ReactionRemove(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::ReactionRemoveAll(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::ReactionRemoveEmoji(ref mut event)) => {
+                update(cache_and_http, event);
+            },
             Self::Model(Event::PresencesReplace(ref mut event)) => {
                 update(cache_and_http, event);
             },
@@ -152,6 +225,15 @@ impl DispatchEvent {
             Self::Model(Event::ThreadDelete(ref mut event)) => {
                 update(cache_and_http, event);
             },
+            Self::Model(Event::ThreadListSync(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::ThreadMemberUpdate(ref mut event)) => {
+                update(cache_and_http, event);
+            },
+            Self::Model(Event::ThreadMembersUpdate(ref mut event)) => {
+                update(cache_and_http, event);
+            },
             _ => (),
         }
     }
@@ -167,6 +249,7 @@ pub(crate) fn dispatch<'rec>(
     raw_event_handler: &'rec Option<Arc<dyn RawEventHandler>>,
     runner_tx: &'rec Sender<InterMessage>,
     shard_id: u64,
+    replayed: bool,
     cache_and_http: Arc<CacheAndHttp>,
 ) -> BoxFuture<'rec, ()> {
     async move {
@@ -177,12 +260,14 @@ pub(crate) fn dispatch<'rec>(
                 #[cfg(feature = "framework")]
                 if let DispatchEvent::Model(Event::MessageCreate(event)) = event {
                     #[cfg(not(feature = "cache"))]
-                    let context = context(data, runner_tx, shard_id, &cache_and_http.http);
+                    let context =
+                        context(data, runner_tx, shard_id, replayed, &cache_and_http.http);
                     #[cfg(feature = "cache")]
                     let context = context(
                         data,
                         runner_tx,
                         shard_id,
+                        replayed,
                         &cache_and_http.http,
                         &cache_and_http.cache,
                     );
@@ -199,12 +284,14 @@ pub(crate) fn dispatch<'rec>(
                     update(&cache_and_http, &mut event);
 
                     #[cfg(not(feature = "cache"))]
-                    let context = context(data, runner_tx, shard_id, &cache_and_http.http);
+                    let context =
+                        context(data, runner_tx, shard_id, replayed, &cache_and_http.http);
                     #[cfg(feature = "cache")]
                     let context = context(
                         data,
                         runner_tx,
                         shard_id,
+                        replayed,
                         &cache_and_http.http,
                         &cache_and_http.cache,
                     );
@@ -227,7 +314,8 @@ pub(crate) fn dispatch<'rec>(
                     }
                 },
                 other => {
-                    handle_event(other, data, h, runner_tx, shard_id, cache_and_http).await;
+                    handle_event(other, data, h, runner_tx, shard_id, replayed, cache_and_http)
+                        .await;
                 },
             },
             (None, Some(ref rh)) => {
@@ -237,12 +325,14 @@ pub(crate) fn dispatch<'rec>(
                     let event_handler = Arc::clone(rh);
 
                     #[cfg(not(feature = "cache"))]
-                    let context = context(data, runner_tx, shard_id, &cache_and_http.http);
+                    let context =
+                        context(data, runner_tx, shard_id, replayed, &cache_and_http.http);
                     #[cfg(feature = "cache")]
                     let context = context(
                         data,
                         runner_tx,
                         shard_id,
+                        replayed,
                         &cache_and_http.http,
                         &cache_and_http.cache,
                     );
@@ -276,10 +366,16 @@ pub(crate) fn dispatch<'rec>(
             // and passing no framework, as we dispatch once we are done right here.
             (Some(ref handler), Some(ref raw_handler)) => {
                 #[cfg(not(feature = "cache"))]
-                let context = context(data, runner_tx, shard_id, &cache_and_http.http);
+                let context = context(data, runner_tx, shard_id, replayed, &cache_and_http.http);
                 #[cfg(feature = "cache")]
-                let context =
-                    context(data, runner_tx, shard_id, &cache_and_http.http, &cache_and_http.cache);
+                let context = context(
+                    data,
+                    runner_tx,
+                    shard_id,
+                    replayed,
+                    &cache_and_http.http,
+                    &cache_and_http.cache,
+                );
 
                 if let DispatchEvent::Model(ref event) = event {
                     raw_handler.raw_event(context.clone(), event.clone()).await;
@@ -305,8 +401,16 @@ pub(crate) fn dispatch<'rec>(
                         }
                     },
                     other => {
-                        handle_event(other, data, handler, runner_tx, shard_id, cache_and_http)
-                            .await;
+                        handle_event(
+                            other,
+                            data,
+                            handler,
+                            runner_tx,
+                            shard_id,
+                            replayed,
+                            cache_and_http,
+                        )
+                        .await;
                     },
                 }
             },
@@ -341,12 +445,14 @@ async fn handle_event(
     event_handler: &Arc<dyn EventHandler>,
     runner_tx: &Sender<InterMessage>,
     shard_id: u64,
+    replayed: bool,
     cache_and_http: Arc<CacheAndHttp>,
 ) {
     #[cfg(not(feature = "cache"))]
-    let context = context(data, runner_tx, shard_id, &cache_and_http.http);
+    let context = context(data, runner_tx, shard_id, replayed, &cache_and_http.http);
     #[cfg(feature = "cache")]
-    let context = context(data, runner_tx, shard_id, &cache_and_http.http, &cache_and_http.cache);
+    let context =
+        context(data, runner_tx, shard_id, replayed, &cache_and_http.http, &cache_and_http.cache);
 
     let event_handler = Arc::clone(event_handler);
 
@@ -376,17 +482,27 @@ async fn handle_event(
                 },
             );
         },
-        Event::AutoModerationRuleCreate(event) => {
+        Event::AutoModerationRuleCreate(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::auto_moderation_rule_create", async move {
                 event_handler.auto_moderation_rule_create(context, event.rule).await;
             });
         },
-        Event::AutoModerationRuleUpdate(event) => {
+        Event::AutoModerationRuleUpdate(mut event) => {
+            let _before = update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::auto_moderation_rule_update", async move {
-                event_handler.auto_moderation_rule_update(context, event.rule).await;
+                feature_cache! {{
+                    event_handler.auto_moderation_rule_update(context, _before, event.rule).await;
+                } else {
+                    event_handler.auto_moderation_rule_update(context, event.rule).await;
+                }}
             });
         },
-        Event::AutoModerationRuleDelete(event) => {
+        Event::AutoModerationRuleDelete(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::auto_moderation_rule_delete", async move {
                 event_handler.auto_moderation_rule_delete(context, event.rule).await;
             });
@@ -449,19 +565,23 @@ async fn handle_event(
                 }}
             });
         },
-        Event::GuildBanAdd(event) => {
+        Event::GuildBanAdd(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::guild_ban_addition", async move {
                 event_handler.guild_ban_addition(context, event.guild_id, event.user).await;
             });
         },
-        Event::GuildBanRemove(event) => {
+        Event::GuildBanRemove(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::guild_ban_removal", async move {
                 event_handler.guild_ban_removal(context, event.guild_id, event.user).await;
             });
         },
         Event::GuildCreate(mut event) => {
             #[cfg(feature = "cache")]
-            let _is_new = !cache_and_http.cache.unavailable_guilds.contains(&event.guild.id);
+            let _is_new = !cache_and_http.cache.unavailable_guilds.contains_key(&event.guild.id);
 
             update(&cache_and_http, &mut event);
 
@@ -470,12 +590,7 @@ async fn handle_event(
                 let context = context.clone();
 
                 if cache_and_http.cache.unavailable_guilds.is_empty() {
-                    let guild_amount = cache_and_http
-                        .cache
-                        .guilds
-                        .iter()
-                        .map(|i| *i.key())
-                        .collect::<Vec<GuildId>>();
+                    let guild_amount = cache_and_http.cache.guilds.keys();
                     let event_handler = Arc::clone(&event_handler);
 
                     spawn_named("dispatch::event_handler::cache_ready", async move {
@@ -629,18 +744,46 @@ async fn handle_event(
         },
         // Already handled by the framework check macro
         Event::MessageCreate(_) => {},
-        Event::MessageDeleteBulk(event) => {
+        Event::MessageDeleteBulk(mut event) => {
+            let _deleted = update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::message_delete_bulk", async move {
-                event_handler
-                    .message_delete_bulk(context, event.channel_id, event.ids, event.guild_id)
-                    .await;
+                feature_cache! {{
+                    event_handler
+                        .message_delete_bulk(
+                            context,
+                            event.channel_id,
+                            event.ids,
+                            event.guild_id,
+                            _deleted.unwrap_or_default(),
+                        )
+                        .await;
+                } else {
+                    event_handler
+                        .message_delete_bulk(context, event.channel_id, event.ids, event.guild_id)
+                        .await;
+                }}
             });
         },
-        Event::MessageDelete(event) => {
+        Event::MessageDelete(mut event) => {
+            let _deleted = update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::message_delete", async move {
-                event_handler
-                    .message_delete(context, event.channel_id, event.message_id, event.guild_id)
-                    .await;
+                feature_cache! {{
+                    event_handler
+                        .message_delete(
+                            context,
+                            event.channel_id,
+                            event.message_id,
+                            event.guild_id,
+                            _deleted,
+                        )
+                        .await;
+                } else {
+                    event_handler
+                        .message_delete(context, event.channel_id, event.message_id, event.guild_id)
+                        .await;
+                }}
             });
         },
         Event::MessageUpdate(mut event) => {
@@ -669,23 +812,36 @@ async fn handle_event(
                 event_handler.presence_update(context, event.presence).await;
             });
         },
-        Event::ReactionAdd(event) => {
+        Event::ReactionAdd(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::reaction_add", async move {
                 event_handler.reaction_add(context, event.reaction).await;
             });
         },
-        Event::ReactionRemove(event) => {
+        Event::ReactionRemove(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::reaction_remove", async move {
                 event_handler.reaction_remove(context, event.reaction).await;
             });
         },
-        Event::ReactionRemoveAll(event) => {
+        Event::ReactionRemoveAll(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::remove_all", async move {
                 event_handler
                     .reaction_remove_all(context, event.channel_id, event.message_id)
                     .await;
             });
         },
+        Event::ReactionRemoveEmoji(mut event) => {
+            update(&cache_and_http, &mut event);
+
+            spawn_named("dispatch::event_handler::remove_emoji", async move {
+                event_handler.reaction_remove_emoji(context, event.reaction).await;
+            });
+        },
         Event::Ready(mut event) => {
             update(&cache_and_http, &mut event);
             spawn_named("dispatch::event_handler::ready", async move {
@@ -744,34 +900,66 @@ async fn handle_event(
                 event_handler.interaction_create(context, event.interaction).await;
             });
         },
-        Event::IntegrationCreate(event) => {
+        Event::IntegrationCreate(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::integration_create", async move {
                 event_handler.integration_create(context, event.integration).await;
             });
         },
-        Event::IntegrationUpdate(event) => {
+        Event::IntegrationUpdate(mut event) => {
+            let _before = update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::integration_update", async move {
-                event_handler.integration_update(context, event.integration).await;
+                feature_cache! {{
+                    event_handler.integration_update(context, _before, event.integration).await;
+                } else {
+                    event_handler.integration_update(context, event.integration).await;
+                }}
             });
         },
-        Event::IntegrationDelete(event) => {
+        Event::IntegrationDelete(mut event) => {
+            let _integration = update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::integration_delete", async move {
-                event_handler
-                    .integration_delete(context, event.id, event.guild_id, event.application_id)
-                    .await;
+                feature_cache! {{
+                    event_handler
+                        .integration_delete(
+                            context,
+                            event.id,
+                            event.guild_id,
+                            event.application_id,
+                            _integration,
+                        )
+                        .await;
+                } else {
+                    event_handler
+                        .integration_delete(context, event.id, event.guild_id, event.application_id)
+                        .await;
+                }}
             });
         },
-        Event::StageInstanceCreate(event) => {
+        Event::StageInstanceCreate(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::stage_instance_create", async move {
                 event_handler.stage_instance_create(context, event.stage_instance).await;
             });
         },
-        Event::StageInstanceUpdate(event) => {
+        Event::StageInstanceUpdate(mut event) => {
+            let _before = update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::stage_instance_update", async move {
-                event_handler.stage_instance_update(context, event.stage_instance).await;
+                feature_cache! {{
+                    event_handler.stage_instance_update(context, _before, event.stage_instance).await;
+                } else {
+                    event_handler.stage_instance_update(context, event.stage_instance).await;
+                }}
             });
         },
-        Event::StageInstanceDelete(event) => {
+        Event::StageInstanceDelete(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::stage_instance_delete", async move {
                 event_handler.stage_instance_delete(context, event.stage_instance).await;
             });
@@ -797,42 +985,62 @@ async fn handle_event(
                 event_handler.thread_delete(context, event.thread).await;
             });
         },
-        Event::ThreadListSync(event) => {
+        Event::ThreadListSync(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::thread_list_sync", async move {
                 event_handler.thread_list_sync(context, event).await;
             });
         },
-        Event::ThreadMemberUpdate(event) => {
+        Event::ThreadMemberUpdate(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::thread_member_update", async move {
                 event_handler.thread_member_update(context, event.member).await;
             });
         },
-        Event::ThreadMembersUpdate(event) => {
+        Event::ThreadMembersUpdate(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::thread_members_update", async move {
                 event_handler.thread_members_update(context, event).await;
             });
         },
-        Event::GuildScheduledEventCreate(event) => {
+        Event::GuildScheduledEventCreate(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::guild_scheduled_event_create", async move {
                 event_handler.guild_scheduled_event_create(context, event.event).await;
             });
         },
-        Event::GuildScheduledEventUpdate(event) => {
+        Event::GuildScheduledEventUpdate(mut event) => {
+            let _before = update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::guild_scheduled_event_update", async move {
-                event_handler.guild_scheduled_event_update(context, event.event).await;
+                feature_cache! {{
+                    event_handler.guild_scheduled_event_update(context, _before, event.event).await;
+                } else {
+                    event_handler.guild_scheduled_event_update(context, event.event).await;
+                }}
             });
         },
-        Event::GuildScheduledEventDelete(event) => {
+        Event::GuildScheduledEventDelete(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::guild_scheduled_event_delete", async move {
                 event_handler.guild_scheduled_event_delete(context, event.event).await;
             });
         },
-        Event::GuildScheduledEventUserAdd(event) => {
+        Event::GuildScheduledEventUserAdd(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::guild_scheduled_event_user_add", async move {
                 event_handler.guild_scheduled_event_user_add(context, event).await;
             });
         },
-        Event::GuildScheduledEventUserRemove(event) => {
+        Event::GuildScheduledEventUserRemove(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::guild_scheduled_event_user_remove", async move {
                 event_handler.guild_scheduled_event_user_remove(context, event).await;
             });
@@ -29,9 +29,22 @@ pub trait EventHandler: Send + Sync {
     /// Provides said rule's data.
     async fn auto_moderation_rule_create(&self, _ctx: Context, _rule: Rule) {}
 
+    /// Dispatched when an auto moderation rule was updated.
+    ///
+    /// Provides the rule's old data (if available) and its new data.
+    #[cfg(feature = "cache")]
+    async fn auto_moderation_rule_update(
+        &self,
+        _ctx: Context,
+        _old_if_available: Option<Rule>,
+        _new: Rule,
+    ) {
+    }
+
     /// Dispatched when an auto moderation rule was updated.
     ///
     /// Provides said rule's data.
+    #[cfg(not(feature = "cache"))]
     async fn auto_moderation_rule_update(&self, _ctx: Context, _rule: Rule) {}
 
     /// Dispatched when an auto moderation rule was deleted.
@@ -319,9 +332,25 @@ pub trait EventHandler: Send + Sync {
     /// Provides the message's data.
     async fn message(&self, _ctx: Context, _new_message: Message) {}
 
+    /// Dispatched when a message is deleted.
+    ///
+    /// Provides the guild's id, the channel's id, the message's id and the
+    /// deleted message if it was cached.
+    #[cfg(feature = "cache")]
+    async fn message_delete(
+        &self,
+        _ctx: Context,
+        _channel_id: ChannelId,
+        _deleted_message_id: MessageId,
+        _guild_id: Option<GuildId>,
+        _deleted_message_if_available: Option<Message>,
+    ) {
+    }
+
     /// Dispatched when a message is deleted.
     ///
     /// Provides the guild's id, the channel's id and the message's id.
+    #[cfg(not(feature = "cache"))]
     async fn message_delete(
         &self,
         _ctx: Context,
@@ -331,9 +360,25 @@ pub trait EventHandler: Send + Sync {
     ) {
     }
 
+    /// Dispatched when multiple messages were deleted at once.
+    ///
+    /// Provides the guild's id, channel's id, the deleted messages' ids and
+    /// those of the deleted messages that were cached.
+    #[cfg(feature = "cache")]
+    async fn message_delete_bulk(
+        &self,
+        _ctx: Context,
+        _channel_id: ChannelId,
+        _multiple_deleted_messages_ids: Vec<MessageId>,
+        _guild_id: Option<GuildId>,
+        _deleted_messages_if_available: Vec<Message>,
+    ) {
+    }
+
     /// Dispatched when multiple messages were deleted at once.
     ///
     /// Provides the guild's id, channel's id and the deleted messages' ids.
+    #[cfg(not(feature = "cache"))]
     async fn message_delete_bulk(
         &self,
         _ctx: Context,
@@ -385,6 +430,11 @@ pub trait EventHandler: Send + Sync {
     ) {
     }
 
+    /// Dispatched when all reactions of an emoji are detached from a message.
+    ///
+    /// Provides the reaction's data, without a user or member.
+    async fn reaction_remove_emoji(&self, _ctx: Context, _removed_reactions: Reaction) {}
+
     /// This event is legacy, and likely no longer sent by discord.
     async fn presence_replace(&self, _ctx: Context, _: Vec<Presence>) {}
 
@@ -402,6 +452,9 @@ pub trait EventHandler: Send + Sync {
     async fn ready(&self, _ctx: Context, _data_about_bot: Ready) {}
 
     /// Dispatched upon reconnection.
+    ///
+    /// The events missed while disconnected are dispatched before this, with
+    /// [`Context::replayed`] set; [`ResumedEvent::replayed`] holds their count.
     async fn resume(&self, _ctx: Context, _: ResumedEvent) {}
 
     /// Dispatched when a shard's connection stage is updated
@@ -468,14 +521,44 @@ pub trait EventHandler: Send + Sync {
     /// Provides the created integration.
     async fn integration_create(&self, _ctx: Context, _integration: Integration) {}
 
+    /// Dispatched when a guild integration is updated.
+    ///
+    /// Provides the integration's old data (if available) and the updated
+    /// integration.
+    #[cfg(feature = "cache")]
+    async fn integration_update(
+        &self,
+        _ctx: Context,
+        _old_if_available: Option<Integration>,
+        _new: Integration,
+    ) {
+    }
+
     /// Dispatched when a guild integration is updated.
     ///
     /// Provides the updated integration.
+    #[cfg(not(feature = "cache"))]
     async fn integration_update(&self, _ctx: Context, _integration: Integration) {}
 
+    /// Dispatched when a guild integration is deleted.
+    ///
+    /// Provides the integration's id, the id of the guild it belongs to, its associated application id
+    /// and the integration's data if it was cached.
+    #[cfg(feature = "cache")]
+    async fn integration_delete(
+        &self,
+        _ctx: Context,
+        _integration_id: IntegrationId,
+        _guild_id: GuildId,
+        _application_id: Option<ApplicationId>,
+        _removed_integration_data_if_available: Option<Integration>,
+    ) {
+    }
+
     /// Dispatched when a guild integration is deleted.
     ///
     /// Provides the integration's id, the id of the guild it belongs to, and its associated application id
+    #[cfg(not(feature = "cache"))]
     async fn integration_delete(
         &self,
         _ctx: Context,
@@ -490,9 +573,23 @@ pub trait EventHandler: Send + Sync {
     /// Provides the created stage instance.
     async fn stage_instance_create(&self, _ctx: Context, _stage_instance: StageInstance) {}
 
+    /// Dispatched when a stage instance is updated.
+    ///
+    /// Provides the stage instance's old data (if available) and the updated
+    /// stage instance.
+    #[cfg(feature = "cache")]
+    async fn stage_instance_update(
+        &self,
+        _ctx: Context,
+        _old_if_available: Option<StageInstance>,
+        _new: StageInstance,
+    ) {
+    }
+
     /// Dispatched when a stage instance is updated.
     ///
     /// Provides the updated stage instance.
+    #[cfg(not(feature = "cache"))]
     async fn stage_instance_update(&self, _ctx: Context, _stage_instance: StageInstance) {}
 
     /// Dispatched when a stage instance is deleted.
@@ -546,9 +643,22 @@ pub trait EventHandler: Send + Sync {
     /// Provides data about the scheduled event.
     async fn guild_scheduled_event_create(&self, _ctx: Context, _event: ScheduledEvent) {}
 
+    /// Dispatched when a scheduled event is updated.
+    ///
+    /// Provides the scheduled event's old data (if available) and its new data.
+    #[cfg(feature = "cache")]
+    async fn guild_scheduled_event_update(
+        &self,
+        _ctx: Context,
+        _old_if_available: Option<ScheduledEvent>,
+        _new: ScheduledEvent,
+    ) {
+    }
+
     /// Dispatched when a scheduled event is updated.
     ///
     /// Provides data about the scheduled event.
+    #[cfg(not(feature = "cache"))]
     async fn guild_scheduled_event_update(&self, _ctx: Context, _event: ScheduledEvent) {}
 
     /// Dispatched when a scheduled event is deleted.
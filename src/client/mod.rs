@@ -34,15 +34,18 @@ use futures::future::BoxFuture;
 use tokio::sync::{Mutex, RwLock};
 use tracing::{debug, error, info, instrument, warn};
 use typemap_rev::{TypeMap, TypeMapKey};
-use std::sync::atomic::AtomicU64;
-use std::sync::Mutex as NMutex;
 
 #[cfg(feature = "gateway")]
 use self::bridge::gateway::{
+    IdentifyQueue,
+    InMemoryResumeStateStore,
+    LocalIdentifyQueue,
+    ResumeStateStore,
     ShardManager,
     ShardManagerError,
     ShardManagerMonitor,
     ShardManagerOptions,
+    ShardResumeInfo,
 };
 #[cfg(feature = "voice")]
 use self::bridge::voice::VoiceGatewayManager;
@@ -51,11 +54,13 @@ pub use self::error::Error as ClientError;
 #[cfg(feature = "gateway")]
 pub use self::event_handler::{EventHandler, RawEventHandler};
 #[cfg(feature = "gateway")]
-use super::gateway::GatewayError;
+use super::gateway::{GatewayEncoding, GatewayError, GatewayParams, TransportCompression};
 #[cfg(feature = "cache")]
 pub use crate::cache::Cache;
 #[cfg(feature = "cache")]
 use crate::cache::Settings as CacheSettings;
+#[cfg(feature = "cache")]
+use crate::cache::{CacheBackend, InMemoryCacheBackend};
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
 use crate::http::Http;
@@ -77,14 +82,19 @@ pub struct ClientBuilder {
     intents: GatewayIntents,
     #[cfg(feature = "cache")]
     cache_settings: Option<CacheSettings>,
+    #[cfg(feature = "cache")]
+    cache_backend: Arc<dyn CacheBackend>,
     #[cfg(feature = "framework")]
     framework: Option<Arc<dyn Framework + Send + Sync + 'static>>,
     #[cfg(feature = "voice")]
     voice_manager: Option<Arc<dyn VoiceGatewayManager + Send + Sync + 'static>>,
     event_handler: Option<Arc<dyn EventHandler>>,
     raw_event_handler: Option<Arc<dyn RawEventHandler>>,
-    session_id: Arc<NMutex<Option<String>>>,
-    seq_num: Arc<AtomicU64>,
+    resume_state_store: Arc<dyn ResumeStateStore>,
+    resume_info: Vec<ShardResumeInfo>,
+    identify_queue: Arc<dyn IdentifyQueue>,
+    gateway_url: Option<String>,
+    gateway_params: GatewayParams,
 }
 
 #[cfg(feature = "gateway")]
@@ -97,14 +107,19 @@ impl ClientBuilder {
             intents,
             #[cfg(feature = "cache")]
             cache_settings: Some(CacheSettings::new()),
+            #[cfg(feature = "cache")]
+            cache_backend: Arc::new(InMemoryCacheBackend),
             #[cfg(feature = "framework")]
             framework: None,
             #[cfg(feature = "voice")]
             voice_manager: None,
             event_handler: None,
             raw_event_handler: None,
-            session_id: Arc::new(NMutex::new(None)),
-            seq_num: Arc::new(AtomicU64::new(0)),
+            resume_state_store: Arc::new(InMemoryResumeStateStore::new()),
+            resume_info: Vec::new(),
+            identify_queue: Arc::new(LocalIdentifyQueue::new()),
+            gateway_url: None,
+            gateway_params: GatewayParams::default(),
         }
     }
 
@@ -138,23 +153,75 @@ impl ClientBuilder {
         self
     }
 
-    /// Set a session id to resume immediately with, and register a receiver for session_id updates.
-    /// Will only work correctly with 1 shard.
-    pub fn session_id(mut self, session_id: Arc<NMutex<Option<String>>>) -> Self {
-        self.session_id = session_id;
+    /// Sets the store that shards load their resume state from when booting,
+    /// and keep updated while running.
+    ///
+    /// Shards with a stored state will RESUME their session instead of
+    /// IDENTIFYing. By default, an [`InMemoryResumeStateStore`] is used.
+    ///
+    /// *Info*:
+    /// If a reference to the store is required, e.g. to reuse it for another
+    /// client, use the [`Self::resume_state_store_arc`]-method instead.
+    pub fn resume_state_store<S: ResumeStateStore + 'static>(mut self, store: S) -> Self {
+        self.resume_state_store = Arc::new(store);
 
         self
     }
 
-    pub fn seq_num(mut self, seq_num: Arc<AtomicU64>) -> Self {
-        self.seq_num = seq_num;
+    /// Sets the resume state store. Passed by Arc.
+    /// See [`Self::resume_state_store`] for more info.
+    pub fn resume_state_store_arc<S: ResumeStateStore + 'static>(mut self, store: Arc<S>) -> Self {
+        self.resume_state_store = store;
 
         self
     }
 
-    /// Gets the thread safe access to the seq number of the single shard.
-    pub fn get_seq_num(&self) -> Arc<AtomicU64> {
-        Arc::clone(&self.seq_num)
+    /// Gets the resume state store. See [`Self::resume_state_store`] for more info.
+    pub fn get_resume_state_store(&self) -> Arc<dyn ResumeStateStore> {
+        Arc::clone(&self.resume_state_store)
+    }
+
+    /// Sets the sessions for shards to resume when they are started, as
+    /// returned by [`ShardManager::suspend_all`] in a previous process.
+    ///
+    /// These are written to the [resume state store] when awaiting the builder,
+    /// replacing any state it holds for the same shards.
+    ///
+    /// [resume state store]: Self::resume_state_store
+    pub fn resume_shards(mut self, resume_info: Vec<ShardResumeInfo>) -> Self {
+        self.resume_info = resume_info;
+
+        self
+    }
+
+    /// Sets the queue that shards wait in before they IDENTIFY with the
+    /// gateway.
+    ///
+    /// By default, a [`LocalIdentifyQueue`] is used, which only spaces out
+    /// IDENTIFYs within this client. When shards are split across several
+    /// processes with [`Client::start_shard_range`], give each a queue backed
+    /// by a shared coordinator so that they share one identify budget.
+    ///
+    /// *Info*:
+    /// If a reference to the queue is required, e.g. to reuse it for another
+    /// client, use the [`Self::identify_queue_arc`]-method instead.
+    pub fn identify_queue<Q: IdentifyQueue + 'static>(mut self, queue: Q) -> Self {
+        self.identify_queue = Arc::new(queue);
+
+        self
+    }
+
+    /// Sets the identify queue. Passed by Arc.
+    /// See [`Self::identify_queue`] for more info.
+    pub fn identify_queue_arc<Q: IdentifyQueue + 'static>(mut self, queue: Arc<Q>) -> Self {
+        self.identify_queue = queue;
+
+        self
+    }
+
+    /// Gets the identify queue. See [`Self::identify_queue`] for more info.
+    pub fn get_identify_queue(&self) -> Arc<dyn IdentifyQueue> {
+        Arc::clone(&self.identify_queue)
     }
 
     /// Gets the current token used for the [`Http`] client.
@@ -225,6 +292,28 @@ impl ClientBuilder {
         self.cache_settings.as_ref()
     }
 
+    /// Sets the storage backend of the cache, creating the maps it stores its
+    /// data in. By default, an [`InMemoryCacheBackend`] is used.
+    ///
+    /// Refer to [`SnapshotCacheBackend`] for a backend persisting the cache
+    /// to files. The client does not flush the cache, so [`Cache::flush`]
+    /// must be called on a schedule.
+    ///
+    /// [`SnapshotCacheBackend`]: crate::cache::SnapshotCacheBackend
+    /// [`Cache::flush`]: crate::cache::Cache::flush
+    #[cfg(feature = "cache")]
+    pub fn cache_backend<B: CacheBackend + 'static>(mut self, backend: B) -> Self {
+        self.cache_backend = Arc::new(backend);
+
+        self
+    }
+
+    /// Gets the cache backend. See [`Self::cache_backend`] for more info.
+    #[cfg(feature = "cache")]
+    pub fn get_cache_backend(&self) -> Arc<dyn CacheBackend> {
+        Arc::clone(&self.cache_backend)
+    }
+
     /// Sets the command framework to be used. It will receive messages sent
     /// over the gateway and then consider - based on its settings - whether to
     /// dispatch a command.
@@ -370,6 +459,83 @@ impl ClientBuilder {
     pub fn get_raw_event_handler(&self) -> Option<Arc<dyn RawEventHandler>> {
         self.raw_event_handler.clone()
     }
+
+    /// Sets the URL that shards connect to the gateway with, instead of
+    /// retrieving it through [`Http::get_gateway`].
+    ///
+    /// The URL must not contain a query; the parameters are set through
+    /// [`Self::gateway_version`] and [`Self::gateway_encoding`].
+    ///
+    /// **Note**: [`Client::start_autosharded`] still retrieves the recommended
+    /// shard count from the REST API, see [`HttpBuilder::api_base`].
+    ///
+    /// [`HttpBuilder::api_base`]: crate::http::HttpBuilder::api_base
+    ///
+    /// # Examples
+    ///
+    /// Connect to a gateway running on the local machine:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # struct Handler;
+    /// # impl EventHandler for Handler {}
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::builder("token", GatewayIntents::default())
+    ///     .event_handler(Handler)
+    ///     .gateway_url("ws://localhost:8080")
+    ///     .await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn gateway_url(mut self, gateway_url: impl Into<String>) -> Self {
+        self.gateway_url = Some(gateway_url.into());
+
+        self
+    }
+
+    /// Gets the gateway URL override, if set. See [`Self::gateway_url`] for
+    /// more info.
+    pub fn get_gateway_url(&self) -> Option<&str> {
+        self.gateway_url.as_deref()
+    }
+
+    /// Sets the gateway version to connect with. Defaults to
+    /// [`constants::GATEWAY_VERSION`].
+    ///
+    /// **Note**: The models of this library are written against the default
+    /// version; other versions may fail to deserialize.
+    ///
+    /// [`constants::GATEWAY_VERSION`]: crate::constants::GATEWAY_VERSION
+    pub fn gateway_version(mut self, version: u8) -> Self {
+        self.gateway_params.version = version;
+
+        self
+    }
+
+    /// Sets the encoding of gateway payloads. Defaults to
+    /// [`GatewayEncoding::Json`].
+    pub fn gateway_encoding(mut self, encoding: GatewayEncoding) -> Self {
+        self.gateway_params.encoding = encoding;
+
+        self
+    }
+
+    /// Sets the compression of shards' gateway connections. Defaults to none,
+    /// in which case only large payloads are compressed, individually.
+    ///
+    /// [`TransportCompression::ZlibStream`] compresses the whole connection,
+    /// which considerably reduces bandwidth at the cost of keeping an inflate
+    /// context per shard.
+    pub fn transport_compression(mut self, compress: Option<TransportCompression>) -> Self {
+        self.gateway_params.compress = compress;
+
+        self
+    }
+
+    /// Gets the query parameters shards will connect to the gateway with.
+    pub fn get_gateway_params(&self) -> GatewayParams {
+        self.gateway_params
+    }
 }
 
 #[cfg(feature = "gateway")]
@@ -403,19 +569,32 @@ impl Future for ClientBuilder {
 
             let cache_and_http = Arc::new(CacheAndHttp {
                 #[cfg(feature = "cache")]
-                cache: Arc::new(Cache::new_with_settings(self.cache_settings.take().unwrap())),
+                cache: Arc::new(Cache::new_with_backend(
+                    self.cache_settings.take().unwrap(),
+                    &*self.cache_backend,
+                )),
                 http: Arc::clone(&http),
             });
 
-            let session_id = Arc::clone(&self.session_id);
-            let seq_num = Arc::clone(&self.seq_num);
+            let resume_state_store = Arc::clone(&self.resume_state_store);
+            let resume_info = std::mem::take(&mut self.resume_info);
+            let identify_queue = Arc::clone(&self.identify_queue);
+            let gateway_url = self.gateway_url.take();
+            let gateway_params = self.gateway_params;
 
             self.fut = Some(Box::pin(async move {
-                let ws_url = Arc::new(Mutex::new(match http.get_gateway().await {
-                    Ok(response) => response.url,
-                    Err(err) => {
-                        tracing::warn!("HTTP request to get gateway URL failed: {}", err);
-                        "wss://gateway.discord.gg".to_string()
+                for info in resume_info {
+                    resume_state_store.store(info.shard_id, info.state()).await;
+                }
+
+                let ws_url = Arc::new(Mutex::new(match gateway_url {
+                    Some(url) => url,
+                    None => match http.get_gateway().await {
+                        Ok(response) => response.url,
+                        Err(err) => {
+                            tracing::warn!("HTTP request to get gateway URL failed: {}", err);
+                            "wss://gateway.discord.gg".to_string()
+                        },
                     },
                 }));
 
@@ -432,10 +611,11 @@ impl Future for ClientBuilder {
                         #[cfg(feature = "voice")]
                         voice_manager: &voice_manager,
                         ws_url: &ws_url,
+                        gateway_params,
                         cache_and_http: &cache_and_http,
                         intents,
-                        session_id,
-                        seq_num,
+                        resume_state_store: &resume_state_store,
+                        identify_queue: &identify_queue,
                     })
                     .await
                 };
@@ -448,9 +628,6 @@ impl Future for ClientBuilder {
                     voice_manager,
                     ws_url,
                     cache_and_http,
-                    /*session_id: self.session_id,
-                    session_id_sender: self.session_id_sender,
-                    seq_num: self.seq_num,*/
                 })
             }));
         }
@@ -697,9 +874,6 @@ pub struct Client {
     pub ws_url: Arc<Mutex<String>>,
     /// A container for an optional cache and HTTP client.
     pub cache_and_http: Arc<CacheAndHttp>,
-    /*session_id: Option<String>,
-    session_id_recv: Receiver<Option<String>>,
-    seq_num: Arc<AtomicU64>,*/
 }
 
 impl Client {
@@ -986,10 +1160,6 @@ impl Client {
     /// an error.
     #[instrument(skip(self))]
     async fn start_connection(&mut self, shard_data: [u64; 3]) -> Result<()> {
-        if shard_data[2] != 1 {
-            warn!("This version of serenity has modifications unsuitable for multiple shards!");
-        }
-
         #[cfg(feature = "voice")]
         if let Some(voice_manager) = &self.voice_manager {
             let user = self.cache_and_http.http.get_current_user().await?;
@@ -22,6 +22,15 @@ pub const MESSAGE_CODE_LIMIT: usize = 2000;
 /// The maximum number of members the bot can fetch at once
 pub const MEMBER_FETCH_LIMIT: u64 = 1000;
 
+/// The base URL of Discord's REST API.
+pub const API_URL: &str = "https://discord.com/api/v10";
+
+/// The base URL of Discord's CDN.
+pub const CDN_URL: &str = "https://cdn.discordapp.com";
+
+/// The base URL of Discord's status page API.
+pub const STATUS_URL: &str = "https://status.discord.com/api/v2";
+
 /// The [UserAgent] sent along with every request.
 ///
 /// [UserAgent]: ::reqwest::header::USER_AGENT
@@ -13,6 +13,8 @@ use tracing::instrument;
 use crate::client::ClientError;
 #[cfg(feature = "collector")]
 use crate::collector::CollectorError;
+#[cfg(feature = "etf")]
+use crate::gateway::EtfError;
 #[cfg(feature = "gateway")]
 use crate::gateway::GatewayError;
 #[cfg(feature = "http")]
@@ -91,6 +93,9 @@ pub enum Error {
     /// [`gateway`]: crate::gateway
     #[cfg(feature = "gateway")]
     Gateway(GatewayError),
+    /// An error encoding or decoding a payload of the `etf` gateway encoding.
+    #[cfg(feature = "etf")]
+    Etf(EtfError),
     /// An error from the [`http`] module.
     ///
     /// [`http`]: crate::http
@@ -114,6 +119,13 @@ impl From<FormatError> for Error {
     }
 }
 
+#[cfg(feature = "etf")]
+impl From<EtfError> for Error {
+    fn from(e: EtfError) -> Error {
+        Error::Etf(e)
+    }
+}
+
 #[cfg(feature = "gateway")]
 impl From<GatewayError> for Error {
     fn from(e: GatewayError) -> Error {
@@ -186,6 +198,8 @@ impl fmt::Display for Error {
             Self::Collector(inner) => fmt::Display::fmt(&inner, f),
             #[cfg(feature = "gateway")]
             Self::Gateway(inner) => fmt::Display::fmt(&inner, f),
+            #[cfg(feature = "etf")]
+            Self::Etf(inner) => fmt::Display::fmt(&inner, f),
             #[cfg(feature = "http")]
             Self::Http(inner) => fmt::Display::fmt(&inner, f),
             #[cfg(feature = "gateway")]
@@ -208,6 +222,8 @@ impl StdError for Error {
             Self::Collector(inner) => Some(inner),
             #[cfg(feature = "gateway")]
             Self::Gateway(inner) => Some(inner),
+            #[cfg(feature = "etf")]
+            Self::Etf(inner) => Some(inner),
             #[cfg(feature = "http")]
             Self::Http(inner) => Some(inner),
             #[cfg(feature = "gateway")]
@@ -0,0 +1,977 @@
+//! A minimal implementation of Erlang's [External Term Format], used by the
+//! gateway's `etf` encoding.
+//!
+//! Only the terms Discord sends and accepts are supported: integers, floats,
+//! atoms, binaries, lists, tuples and maps. The atoms `nil`, `true` and `false`
+//! map to `null`, `true` and `false` respectively, and binaries map to strings.
+//!
+//! [External Term Format]: https://www.erlang.org/doc/apps/erts/erl_ext_dist.html
+
+use std::convert::TryFrom;
+use std::error::Error as StdError;
+use std::{fmt, str};
+
+use serde::de::value::{BorrowedStrDeserializer, SeqDeserializer};
+use serde::de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor};
+use serde::ser::{self, Serialize};
+use serde::{forward_to_deserialize_any, Deserialize};
+
+const VERSION: u8 = 131;
+
+const NEW_FLOAT_EXT: u8 = 70;
+const SMALL_INTEGER_EXT: u8 = 97;
+const INTEGER_EXT: u8 = 98;
+const FLOAT_EXT: u8 = 99;
+const ATOM_EXT: u8 = 100;
+const SMALL_TUPLE_EXT: u8 = 104;
+const LARGE_TUPLE_EXT: u8 = 105;
+const NIL_EXT: u8 = 106;
+const STRING_EXT: u8 = 107;
+const LIST_EXT: u8 = 108;
+const BINARY_EXT: u8 = 109;
+const SMALL_BIG_EXT: u8 = 110;
+const LARGE_BIG_EXT: u8 = 111;
+const SMALL_ATOM_EXT: u8 = 115;
+const MAP_EXT: u8 = 116;
+const ATOM_UTF8_EXT: u8 = 118;
+const SMALL_ATOM_UTF8_EXT: u8 = 119;
+
+/// The name [`RawTerm`] deserializes through to request the raw bytes of a term.
+const RAW_TERM_TOKEN: &str = "$serenity::gateway::etf::RawTerm";
+
+/// An error that occurred while encoding or decoding a term.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The payload ended before a term was complete.
+    UnexpectedEof,
+    /// The payload did not start with the version byte of the format.
+    InvalidVersion(u8),
+    /// A term of an unsupported type was encountered.
+    UnsupportedTag(u8),
+    /// An integer did not fit into 64 bits.
+    IntegerOutOfRange,
+    /// A float term was not a valid number.
+    InvalidFloat,
+    /// An atom was not valid UTF-8.
+    InvalidAtom,
+    /// Bytes remained after the payload's term.
+    TrailingBytes,
+    /// A custom error, such as a missing field of a model.
+    Custom(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEof => f.write_str("Unexpected end of term"),
+            Self::InvalidVersion(version) => write!(f, "Invalid format version {}", version),
+            Self::UnsupportedTag(tag) => write!(f, "Unsupported term tag {}", tag),
+            Self::IntegerOutOfRange => f.write_str("Integer out of range"),
+            Self::InvalidFloat => f.write_str("Invalid float"),
+            Self::InvalidAtom => f.write_str("Invalid atom"),
+            Self::TrailingBytes => f.write_str("Trailing bytes after term"),
+            Self::Custom(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl StdError for Error {}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// Deserializes a payload, which must start with the version byte.
+pub(crate) fn from_slice<'a, T: Deserialize<'a>>(input: &'a [u8]) -> Result<T> {
+    let input = match input.split_first() {
+        Some((&VERSION, rest)) => rest,
+        Some((&version, _)) => return Err(Error::InvalidVersion(version)),
+        None => return Err(Error::UnexpectedEof),
+    };
+
+    let mut deserializer = Deserializer::new(input);
+    let value = T::deserialize(&mut deserializer)?;
+    deserializer.end()?;
+
+    Ok(value)
+}
+
+/// Serializes a payload, prefixed with the version byte.
+pub(crate) fn to_vec<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>> {
+    let mut serializer = Serializer {
+        output: vec![VERSION],
+    };
+    value.serialize(&mut serializer)?;
+
+    Ok(serializer.output)
+}
+
+/// The bytes of a single term whose decoding has been deferred, borrowing from
+/// the input.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct RawTerm<'a>(&'a [u8]);
+
+impl<'a> RawTerm<'a> {
+    /// Deserializes the term.
+    pub(crate) fn deserialize<T: Deserialize<'a>>(self) -> Result<T> {
+        let mut deserializer = self.deserializer();
+        let value = T::deserialize(&mut deserializer)?;
+        deserializer.end()?;
+
+        Ok(value)
+    }
+
+    /// Returns a deserializer over the term.
+    pub(crate) fn deserializer(self) -> Deserializer<'a> {
+        Deserializer::new(self.0)
+    }
+}
+
+impl<'de: 'a, 'a> Deserialize<'de> for RawTerm<'a> {
+    fn deserialize<D: de::Deserializer<'de>>(
+        deserializer: D,
+    ) -> std::result::Result<Self, D::Error> {
+        struct RawTermVisitor;
+
+        impl<'de> Visitor<'de> for RawTermVisitor {
+            type Value = RawTerm<'de>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a raw term")
+            }
+
+            fn visit_borrowed_bytes<E: de::Error>(
+                self,
+                v: &'de [u8],
+            ) -> std::result::Result<Self::Value, E> {
+                Ok(RawTerm(v))
+            }
+        }
+
+        deserializer.deserialize_newtype_struct(RAW_TERM_TOKEN, RawTermVisitor)
+    }
+}
+
+pub(crate) struct Deserializer<'de> {
+    input: &'de [u8],
+}
+
+impl<'de> Deserializer<'de> {
+    fn new(input: &'de [u8]) -> Self {
+        Self {
+            input,
+        }
+    }
+
+    /// Errors if any input remains.
+    pub(crate) fn end(&self) -> Result<()> {
+        if self.input.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::TrailingBytes)
+        }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'de [u8]> {
+        if self.input.len() < len {
+            return Err(Error::UnexpectedEof);
+        }
+
+        let (taken, rest) = self.input.split_at(len);
+        self.input = rest;
+
+        Ok(taken)
+    }
+
+    fn peek(&self) -> Result<u8> {
+        self.input.first().copied().ok_or(Error::UnexpectedEof)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        let bytes = self.take(2)?;
+
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        let bytes = self.take(4)?;
+
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn len(&mut self) -> Result<usize> {
+        Ok(self.u32()? as usize)
+    }
+
+    /// Reads the name of an atom whose tag has already been read.
+    fn atom(&mut self, tag: u8) -> Result<&'de str> {
+        let len = match tag {
+            ATOM_EXT | ATOM_UTF8_EXT => usize::from(self.u16()?),
+            _ => usize::from(self.u8()?),
+        };
+
+        str::from_utf8(self.take(len)?).map_err(|_| Error::InvalidAtom)
+    }
+
+    /// Reads a big integer whose tag has already been read.
+    fn big(&mut self, len: usize) -> Result<(bool, u64)> {
+        let negative = self.u8()? != 0;
+        let digits = self.take(len)?;
+
+        let mut value = 0_u64;
+        for (i, &digit) in digits.iter().enumerate() {
+            if i >= 8 {
+                if digit != 0 {
+                    return Err(Error::IntegerOutOfRange);
+                }
+            } else {
+                value |= u64::from(digit) << (8 * i);
+            }
+        }
+
+        Ok((negative, value))
+    }
+
+    /// Whether the next term is the `nil` atom.
+    fn peek_nil(&self) -> bool {
+        let mut lookahead = Deserializer::new(self.input);
+
+        match lookahead.u8() {
+            Ok(tag @ (ATOM_EXT | ATOM_UTF8_EXT | SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT)) => {
+                matches!(lookahead.atom(tag), Ok("nil"))
+            },
+            _ => false,
+        }
+    }
+
+    /// Skips over the next term.
+    fn skip(&mut self) -> Result<()> {
+        match self.u8()? {
+            SMALL_INTEGER_EXT => {
+                self.take(1)?;
+            },
+            INTEGER_EXT => {
+                self.take(4)?;
+            },
+            NEW_FLOAT_EXT => {
+                self.take(8)?;
+            },
+            FLOAT_EXT => {
+                self.take(31)?;
+            },
+            tag @ (ATOM_EXT | ATOM_UTF8_EXT | SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT) => {
+                self.atom(tag)?;
+            },
+            SMALL_BIG_EXT => {
+                let len = usize::from(self.u8()?);
+                self.take(len + 1)?;
+            },
+            LARGE_BIG_EXT => {
+                let len = self.len()?;
+                self.take(len.saturating_add(1))?;
+            },
+            NIL_EXT => {},
+            STRING_EXT => {
+                let len = usize::from(self.u16()?);
+                self.take(len)?;
+            },
+            BINARY_EXT => {
+                let len = self.len()?;
+                self.take(len)?;
+            },
+            LIST_EXT => {
+                // The elements are followed by the list's tail.
+                for _ in 0..=self.len()? {
+                    self.skip()?;
+                }
+            },
+            SMALL_TUPLE_EXT => {
+                for _ in 0..self.u8()? {
+                    self.skip()?;
+                }
+            },
+            LARGE_TUPLE_EXT => {
+                for _ in 0..self.len()? {
+                    self.skip()?;
+                }
+            },
+            MAP_EXT => {
+                for _ in 0..self.len()? {
+                    self.skip()?;
+                    self.skip()?;
+                }
+            },
+            tag => return Err(Error::UnsupportedTag(tag)),
+        }
+
+        Ok(())
+    }
+
+    fn visit_seq<V: Visitor<'de>>(&mut self, len: usize, visitor: V) -> Result<V::Value> {
+        let mut access = Access {
+            de: self,
+            remaining: len,
+        };
+        let value = visitor.visit_seq(&mut access)?;
+
+        for _ in 0..access.remaining {
+            self.skip()?;
+        }
+
+        Ok(value)
+    }
+}
+
+impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.u8()? {
+            SMALL_INTEGER_EXT => visitor.visit_u64(u64::from(self.u8()?)),
+            INTEGER_EXT => {
+                let value = self.u32()? as i32;
+
+                match u64::try_from(value) {
+                    Ok(value) => visitor.visit_u64(value),
+                    Err(_) => visitor.visit_i64(i64::from(value)),
+                }
+            },
+            NEW_FLOAT_EXT => {
+                let bytes = self.take(8)?;
+                let mut bits = [0; 8];
+                bits.copy_from_slice(bytes);
+
+                visitor.visit_f64(f64::from_bits(u64::from_be_bytes(bits)))
+            },
+            FLOAT_EXT => {
+                let bytes = self.take(31)?;
+                let value = str::from_utf8(bytes)
+                    .ok()
+                    .and_then(|s| s.trim_end_matches('\0').parse().ok())
+                    .ok_or(Error::InvalidFloat)?;
+
+                visitor.visit_f64(value)
+            },
+            tag @ (ATOM_EXT | ATOM_UTF8_EXT | SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT) => {
+                match self.atom(tag)? {
+                    "nil" => visitor.visit_unit(),
+                    "true" => visitor.visit_bool(true),
+                    "false" => visitor.visit_bool(false),
+                    atom => visitor.visit_borrowed_str(atom),
+                }
+            },
+            tag @ (SMALL_BIG_EXT | LARGE_BIG_EXT) => {
+                let len = if tag == SMALL_BIG_EXT { usize::from(self.u8()?) } else { self.len()? };
+
+                match self.big(len)? {
+                    (false, value) => visitor.visit_u64(value),
+                    (true, value) => {
+                        let value = i64::try_from(value).map(|value| -value).or_else(|_| {
+                            if value == i64::MIN.unsigned_abs() {
+                                Ok(i64::MIN)
+                            } else {
+                                Err(Error::IntegerOutOfRange)
+                            }
+                        })?;
+
+                        visitor.visit_i64(value)
+                    },
+                }
+            },
+            NIL_EXT => self.visit_seq(0, visitor),
+            STRING_EXT => {
+                // A list of small integers, packed into bytes.
+                let len = usize::from(self.u16()?);
+                let bytes = self.take(len)?;
+
+                visitor.visit_seq(SeqDeserializer::<_, Error>::new(bytes.iter().copied()))
+            },
+            BINARY_EXT => {
+                let len = self.len()?;
+                let bytes = self.take(len)?;
+
+                match str::from_utf8(bytes) {
+                    Ok(s) => visitor.visit_borrowed_str(s),
+                    Err(_) => visitor.visit_borrowed_bytes(bytes),
+                }
+            },
+            LIST_EXT => {
+                let len = self.len()?;
+                let value = self.visit_seq(len, visitor)?;
+
+                // The tail of a proper list is always `NIL_EXT`.
+                self.skip()?;
+
+                Ok(value)
+            },
+            SMALL_TUPLE_EXT => {
+                let len = usize::from(self.u8()?);
+
+                self.visit_seq(len, visitor)
+            },
+            LARGE_TUPLE_EXT => {
+                let len = self.len()?;
+
+                self.visit_seq(len, visitor)
+            },
+            MAP_EXT => {
+                let len = self.len()?;
+                let mut access = Access {
+                    de: self,
+                    remaining: len,
+                };
+                let value = visitor.visit_map(&mut access)?;
+
+                for _ in 0..access.remaining {
+                    self.skip()?;
+                    self.skip()?;
+                }
+
+                Ok(value)
+            },
+            tag => Err(Error::UnsupportedTag(tag)),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.peek_nil() {
+            self.skip()?;
+
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        if name == RAW_TERM_TOKEN {
+            let input = self.input;
+            self.skip()?;
+
+            visitor.visit_borrowed_bytes(&input[..input.len() - self.input.len()])
+        } else {
+            visitor.visit_newtype_struct(self)
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        // Unit variants are encoded as atoms or binaries.
+        let tag = self.peek()?;
+        let mut lookahead = Deserializer::new(&self.input[1..]);
+
+        let variant = match tag {
+            ATOM_EXT | ATOM_UTF8_EXT | SMALL_ATOM_EXT | SMALL_ATOM_UTF8_EXT => {
+                lookahead.atom(tag)?
+            },
+            BINARY_EXT => {
+                let len = lookahead.len()?;
+
+                str::from_utf8(lookahead.take(len)?).map_err(|_| Error::InvalidAtom)?
+            },
+            _ => return self.deserialize_any(visitor),
+        };
+
+        self.input = lookahead.input;
+
+        visitor.visit_enum(BorrowedStrDeserializer::new(variant))
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.skip()?;
+
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier
+    }
+}
+
+/// The elements of a list or tuple, or the entries of a map.
+struct Access<'a, 'de> {
+    de: &'a mut Deserializer<'de>,
+    remaining: usize,
+}
+
+impl<'de> SeqAccess<'de> for Access<'_, 'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+impl<'de> MapAccess<'de> for Access<'_, 'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+
+        self.remaining -= 1;
+
+        seed.deserialize(&mut *self.de).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        seed.deserialize(&mut *self.de)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+struct Serializer {
+    output: Vec<u8>,
+}
+
+impl Serializer {
+    fn atom(&mut self, name: &str) {
+        self.output.push(SMALL_ATOM_UTF8_EXT);
+        // Only the fixed atoms `nil`, `true` and `false` are written.
+        self.output.push(name.len() as u8);
+        self.output.extend_from_slice(name.as_bytes());
+    }
+
+    fn binary(&mut self, bytes: &[u8]) -> Result<()> {
+        let len = u32::try_from(bytes.len()).map_err(|_| Error::IntegerOutOfRange)?;
+
+        self.output.push(BINARY_EXT);
+        self.output.extend_from_slice(&len.to_be_bytes());
+        self.output.extend_from_slice(bytes);
+
+        Ok(())
+    }
+
+    fn integer(&mut self, value: i128) {
+        if let Ok(value) = u8::try_from(value) {
+            self.output.push(SMALL_INTEGER_EXT);
+            self.output.push(value);
+        } else if let Ok(value) = i32::try_from(value) {
+            self.output.push(INTEGER_EXT);
+            self.output.extend_from_slice(&value.to_be_bytes());
+        } else {
+            let digits = value.unsigned_abs().to_le_bytes();
+            let len = digits.iter().rposition(|&digit| digit != 0).map_or(0, |i| i + 1);
+
+            self.output.push(SMALL_BIG_EXT);
+            self.output.push(len as u8);
+            self.output.push(u8::from(value < 0));
+            self.output.extend_from_slice(&digits[..len]);
+        }
+    }
+
+    /// Starts a list or map whose length is written once it is complete.
+    fn compound(&mut self, tag: u8) -> Compound<'_> {
+        self.output.push(tag);
+        let start = self.output.len();
+        self.output.extend_from_slice(&[0; 4]);
+
+        Compound {
+            ser: self,
+            start,
+            len: 0,
+        }
+    }
+
+    /// Starts a single-entry map keyed by the variant's name.
+    fn variant(&mut self, variant: &str) -> Result<()> {
+        self.output.push(MAP_EXT);
+        self.output.extend_from_slice(&1_u32.to_be_bytes());
+
+        self.binary(variant.as_bytes())
+    }
+}
+
+struct Compound<'a> {
+    ser: &'a mut Serializer,
+    start: usize,
+    len: u32,
+}
+
+impl Compound<'_> {
+    fn end_list(self) {
+        if self.len == 0 {
+            // An empty list is written as `NIL_EXT` alone.
+            self.ser.output.truncate(self.start - 1);
+        } else {
+            self.ser.output[self.start..self.start + 4].copy_from_slice(&self.len.to_be_bytes());
+        }
+
+        self.ser.output.push(NIL_EXT);
+    }
+
+    fn end_map(self) {
+        self.ser.output[self.start..self.start + 4].copy_from_slice(&self.len.to_be_bytes());
+    }
+}
+
+impl<'a> ser::Serializer for &'a mut Serializer {
+    type Ok = ();
+    type Error = Error;
+
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        self.atom(if v { "true" } else { "false" });
+
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<()> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<()> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<()> {
+        self.serialize_i64(i64::from(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<()> {
+        self.integer(i128::from(v));
+
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<()> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<()> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<()> {
+        self.serialize_u64(u64::from(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<()> {
+        self.integer(i128::from(v));
+
+        Ok(())
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<()> {
+        self.serialize_f64(f64::from(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<()> {
+        self.output.push(NEW_FLOAT_EXT);
+        self.output.extend_from_slice(&v.to_bits().to_be_bytes());
+
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.binary(v.encode_utf8(&mut [0; 4]).as_bytes())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.binary(v.as_bytes())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        self.binary(v)
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        self.atom("nil");
+
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        self.variant(variant)?;
+
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Compound<'a>> {
+        Ok(self.compound(LIST_EXT))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Compound<'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<Compound<'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Compound<'a>> {
+        self.variant(variant)?;
+
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Compound<'a>> {
+        Ok(self.compound(MAP_EXT))
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Compound<'a>> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Compound<'a>> {
+        self.variant(variant)?;
+
+        self.serialize_map(Some(len))
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        self.len += 1;
+
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<()> {
+        self.end_list();
+
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<()> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<()> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<()> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<()> {
+        self.len += 1;
+
+        key.serialize(&mut *self.ser)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<()> {
+        value.serialize(&mut *self.ser)
+    }
+
+    fn end(self) -> Result<()> {
+        self.end_map();
+
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        ser::SerializeMap::serialize_entry(self, key, value)
+    }
+
+    fn end(self) -> Result<()> {
+        ser::SerializeMap::end(self)
+    }
+}
+
+impl ser::SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        ser::SerializeMap::serialize_entry(self, key, value)
+    }
+
+    fn end(self) -> Result<()> {
+        ser::SerializeMap::end(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::Deserialize;
+
+    use super::{from_slice, to_vec, RawTerm};
+    use crate::json::{json, Value};
+
+    #[test]
+    fn test_round_trip() {
+        let value = json!({
+            "op": 2,
+            "d": {
+                "token": "abc",
+                "large_threshold": 250,
+                "shard": [0, 1],
+                "presence": {"since": null, "activities": [], "afk": false},
+                "id": 1_031_539_564_291_411_968_u64,
+                "offset": -70_000,
+                "ratio": 0.5,
+            },
+        });
+
+        let bytes = to_vec(&value).unwrap();
+        assert_eq!(from_slice::<Value>(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn test_decode_terms() {
+        // {op => 10, d => #{<<"heartbeat_interval">> => 41250}}, with atom keys.
+        let bytes = [
+            131, 116, 0, 0, 0, 2, 119, 2, b'o', b'p', 97, 10, 119, 1, b'd', 116, 0, 0, 0, 1, 109,
+            0, 0, 0, 18, b'h', b'e', b'a', b'r', b't', b'b', b'e', b'a', b't', b'_', b'i', b'n',
+            b't', b'e', b'r', b'v', b'a', b'l', 98, 0, 0, 161, 34,
+        ];
+
+        #[derive(Deserialize)]
+        struct Payload<'a> {
+            op: u8,
+            #[serde(borrow)]
+            d: RawTerm<'a>,
+        }
+
+        let payload: Payload<'_> = from_slice(&bytes).unwrap();
+        assert_eq!(payload.op, 10);
+
+        let d: HashMap<String, u64> = payload.d.deserialize().unwrap();
+        assert_eq!(d["heartbeat_interval"], 41250);
+    }
+}
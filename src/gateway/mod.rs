@@ -47,16 +47,21 @@
 //! [docs]: https://discordapp.com/developers/docs/topics/gateway#sharding
 
 mod error;
+#[cfg(feature = "etf")]
+pub(crate) mod etf;
 mod shard;
 mod ws_client_ext;
 
 use std::fmt;
 
 pub use self::error::Error as GatewayError;
+#[cfg(feature = "etf")]
+pub use self::etf::Error as EtfError;
 pub use self::shard::Shard;
 pub use self::ws_client_ext::WebSocketGatewayClientExt;
 #[cfg(feature = "client")]
 use crate::client::bridge::gateway::ShardClientMessage;
+use crate::constants;
 use crate::json::Value;
 use crate::model::gateway::Activity;
 use crate::model::user::OnlineStatus;
@@ -142,6 +147,105 @@ impl fmt::Display for ConnectionStage {
     }
 }
 
+/// The query parameters a [`Shard`] connects to the gateway with.
+///
+/// # Examples
+///
+/// Pin the gateway version during a migration:
+///
+/// ```rust
+/// use serenity::gateway::GatewayParams;
+///
+/// let mut params = GatewayParams::default();
+/// params.version = 9;
+///
+/// assert_eq!(params.query(), "v=9&encoding=json");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct GatewayParams {
+    /// The gateway version, sent as the `v` parameter.
+    ///
+    /// Defaults to [`constants::GATEWAY_VERSION`].
+    pub version: u8,
+    /// The payload encoding, sent as the `encoding` parameter.
+    pub encoding: GatewayEncoding,
+    /// The transport compression, sent as the `compress` parameter.
+    ///
+    /// Defaults to none, in which case large payloads are compressed
+    /// individually instead.
+    pub compress: Option<TransportCompression>,
+}
+
+impl GatewayParams {
+    /// Returns the query string for these parameters, without a leading `?`.
+    #[must_use]
+    pub fn query(&self) -> String {
+        let query = format!("v={}&encoding={}", self.version, self.encoding);
+
+        match self.compress {
+            Some(compress) => format!("{}&compress={}", query, compress),
+            None => query,
+        }
+    }
+}
+
+impl Default for GatewayParams {
+    fn default() -> Self {
+        Self {
+            version: constants::GATEWAY_VERSION,
+            encoding: GatewayEncoding::Json,
+            compress: None,
+        }
+    }
+}
+
+/// The encoding of payloads sent and received over the gateway.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum GatewayEncoding {
+    /// Payloads are JSON text.
+    Json,
+    /// Payloads are binary, in Erlang's [External Term Format], which is
+    /// smaller and cheaper to parse than JSON.
+    ///
+    /// Payloads are not compressed individually with this encoding, so
+    /// consider using [`TransportCompression::ZlibStream`] as well.
+    ///
+    /// [External Term Format]: https://www.erlang.org/doc/apps/erts/erl_ext_dist.html
+    #[cfg(feature = "etf")]
+    Etf,
+}
+
+impl fmt::Display for GatewayEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match *self {
+            Self::Json => "json",
+            #[cfg(feature = "etf")]
+            Self::Etf => "etf",
+        })
+    }
+}
+
+/// The compression of the whole gateway connection.
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway#transport-compression).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum TransportCompression {
+    /// The connection is a single zlib stream, with each payload ending in a
+    /// `Z_SYNC_FLUSH`.
+    ZlibStream,
+}
+
+impl fmt::Display for TransportCompression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match *self {
+            Self::ZlibStream => "zlib-stream",
+        })
+    }
+}
+
 /// A message to be passed around within the library.
 ///
 /// As a user you usually don't need to worry about this, but when working with
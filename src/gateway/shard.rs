@@ -1,5 +1,4 @@
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration as StdDuration, Instant};
 
 use async_tungstenite::tungstenite::error::Error as TungsteniteError;
@@ -7,22 +6,23 @@ use async_tungstenite::tungstenite::protocol::frame::CloseFrame;
 use tokio::sync::Mutex;
 use tracing::{debug, error, info, instrument, trace, warn};
 use url::Url;
-use std::sync::Mutex as NMutex;
 
 use super::{
     ConnectionStage,
     CurrentPresence,
+    GatewayEncoding,
     GatewayError,
+    GatewayParams,
     ReconnectType,
     ShardAction,
     WebSocketGatewayClientExt,
     WsStream,
 };
-use crate::client::bridge::gateway::ChunkGuildFilter;
-use crate::constants::{self, close_codes};
+use crate::client::bridge::gateway::{ChunkGuildFilter, ResumeState};
+use crate::constants::close_codes;
 use crate::http::Http;
 use crate::internal::prelude::*;
-use crate::internal::ws_impl::create_client;
+use crate::internal::ws_impl::{create_client, Inflater, ReceiverExt};
 use crate::model::event::{Event, GatewayEvent};
 use crate::model::gateway::{Activity, GatewayIntents};
 use crate::model::id::GuildId;
@@ -79,8 +79,12 @@ pub struct Shard {
     // This _must_ be set to `true` in `Shard::handle_event`'s
     // `Ok(GatewayEvent::HeartbeatAck)` arm.
     last_heartbeat_acknowledged: bool,
-    seq: Arc<AtomicU64>,
-    session_id: Arc<NMutex<Option<String>>>,
+    seq: u64,
+    /// The number of dispatches replayed since the last RESUME was sent.
+    replayed: u64,
+    session_id: Option<String>,
+    /// The gateway URL to resume the session on, if Discord provided one.
+    resume_ws_url: Option<String>,
     shard_info: [u64; 2],
     stage: ConnectionStage,
     /// Instant of when the shard was started.
@@ -89,54 +93,14 @@ pub struct Shard {
     pub started: Instant,
     pub token: String,
     ws_url: Arc<Mutex<String>>,
+    gateway_params: GatewayParams,
+    /// The inflate context of the connection, if it uses transport
+    /// compression.
+    inflater: Option<Inflater>,
     pub intents: GatewayIntents,
 }
 
 impl Shard {
-    pub async fn new_resume(
-        ws_url: Arc<Mutex<String>>,
-        token: &str,
-        shard_info: [u64; 2],
-        intents: GatewayIntents,
-        session_id: Arc<NMutex<Option<String>>>,
-        seq: Arc<AtomicU64>,
-    ) -> Result<Shard> {
-        let url = ws_url.lock().await.clone();
-        let client = connect(&url).await?;
-
-        let current_presence = (None, OnlineStatus::Online);
-        let heartbeat_instants = (None, None);
-        let heartbeat_interval = None;
-        let last_heartbeat_acknowledged = true;
-        let stage = if session_id.lock().expect("Poison").is_some() {
-            ConnectionStage::Resuming
-        } else {
-            ConnectionStage::Handshake
-        };
-        debug!("Connection stage: {}", stage);
-
-        let mut shard = Shard {
-            client,
-            current_presence,
-            heartbeat_instants,
-            heartbeat_interval,
-            http: None,
-            last_heartbeat_acknowledged,
-            seq,
-            stage,
-            started: Instant::now(),
-            token: token.to_string(),
-            session_id,
-            shard_info,
-            ws_url,
-            intents,
-        };
-        if stage == ConnectionStage::Resuming {
-            shard.resume().await?;
-        }
-        Ok(shard)
-    }
-
     /// Instantiates a new instance of a Shard, bypassing the client.
     ///
     /// **Note**: You should likely never need to do this yourself.
@@ -172,24 +136,53 @@ impl Shard {
     ///
     /// On Error, will return either [`Error::Gateway`], [`Error::Tungstenite`]
     /// or a Rustls/native TLS error.
-    /*pub async fn new(
+    pub async fn new(
+        ws_url: Arc<Mutex<String>>,
+        token: &str,
+        shard_info: [u64; 2],
+        intents: GatewayIntents,
+    ) -> Result<Shard> {
+        Self::new_resume(ws_url, token, shard_info, intents, GatewayParams::default(), None).await
+    }
+
+    /// Instantiates a new instance of a Shard, connecting with the given
+    /// gateway query parameters and resuming the session described by
+    /// `resume_state` if one is given.
+    ///
+    /// # Errors
+    ///
+    /// On Error, will return either [`Error::Gateway`], [`Error::Tungstenite`]
+    /// or a Rustls/native TLS error.
+    pub async fn new_resume(
         ws_url: Arc<Mutex<String>>,
         token: &str,
         shard_info: [u64; 2],
         intents: GatewayIntents,
+        gateway_params: GatewayParams,
+        resume_state: Option<ResumeState>,
     ) -> Result<Shard> {
-        let url = ws_url.lock().await.clone();
-        let client = connect(&url).await?;
+        let (session_id, seq, resume_ws_url) = match resume_state {
+            Some(state) => (Some(state.session_id), state.seq, state.resume_gateway_url),
+            None => (None, 0, None),
+        };
+        let url = match &resume_ws_url {
+            Some(url) if session_id.is_some() => url.clone(),
+            _ => ws_url.lock().await.clone(),
+        };
+        let client = connect(&url, &gateway_params).await?;
 
         let current_presence = (None, OnlineStatus::Online);
         let heartbeat_instants = (None, None);
         let heartbeat_interval = None;
         let last_heartbeat_acknowledged = true;
-        let stage = ConnectionStage::Handshake;
-        let session_id = None;
-        let seq = Arc::new(AtomicU64::new(0));
+        let stage = if session_id.is_some() {
+            ConnectionStage::Resuming
+        } else {
+            ConnectionStage::Handshake
+        };
+        debug!("Connection stage: {}", stage);
 
-        Ok(Shard {
+        let mut shard = Shard {
             client,
             current_presence,
             heartbeat_instants,
@@ -197,15 +190,23 @@ impl Shard {
             http: None,
             last_heartbeat_acknowledged,
             seq,
+            replayed: 0,
             stage,
             started: Instant::now(),
             token: token.to_string(),
             session_id,
+            resume_ws_url,
             shard_info,
             ws_url,
+            gateway_params,
+            inflater: gateway_params.compress.map(|_| Inflater::new()),
             intents,
-        })
-    }*/
+        };
+        if stage == ConnectionStage::Resuming {
+            shard.send_resume().await?;
+        }
+        Ok(shard)
+    }
 
     /// Sets the associated [`Http`] client.
     ///
@@ -252,7 +253,15 @@ impl Shard {
     /// a heartbeat.
     #[instrument(skip(self))]
     pub async fn heartbeat(&mut self) -> Result<()> {
-        match self.client.send_heartbeat(&self.shard_info, Some(self.seq())).await {
+        match self
+            .client
+            .send_heartbeat_with_encoding(
+                &self.shard_info,
+                Some(self.seq()),
+                self.gateway_params.encoding,
+            )
+            .await
+        {
             Ok(()) => {
                 self.heartbeat_instants.0 = Some(Instant::now());
                 self.last_heartbeat_acknowledged = false;
@@ -288,12 +297,43 @@ impl Shard {
 
     #[inline]
     pub fn seq(&self) -> u64 {
-        self.seq.load(Ordering::Relaxed)
+        self.seq
+    }
+
+    /// The query parameters the shard connects to the gateway with.
+    #[inline]
+    pub fn gateway_params(&self) -> &GatewayParams {
+        &self.gateway_params
+    }
+
+    /// The number of dispatches Discord replayed after the last RESUME.
+    ///
+    /// While the shard is [`ConnectionStage::Resuming`], every dispatch
+    /// received is one that was missed while disconnected. The count is final
+    /// once the RESUMED event is received.
+    #[inline]
+    pub fn replayed(&self) -> u64 {
+        self.replayed
     }
 
     #[inline]
-    pub fn session_id(&self) -> Option<String> {
-        (*self.session_id.lock().expect("Poison")).clone()
+    pub fn session_id(&self) -> Option<&String> {
+        self.session_id.as_ref()
+    }
+
+    /// The gateway URL that the current session will be resumed on, as
+    /// received in the READY payload.
+    #[inline]
+    pub fn resume_ws_url(&self) -> Option<&String> {
+        self.resume_ws_url.as_ref()
+    }
+
+    /// Returns the state needed to resume the shard's current session, if
+    /// there is one.
+    pub fn resume_state(&self) -> Option<ResumeState> {
+        self.session_id.as_ref().map(|session_id| {
+            ResumeState::new(session_id.clone(), self.seq, self.resume_ws_url.clone())
+        })
     }
 
     #[inline]
@@ -366,17 +406,22 @@ impl Shard {
 
     #[instrument(skip(self))]
     fn handle_gateway_dispatch(&mut self, seq: u64, event: &Event) -> Option<ShardAction> {
-        let our_seq = self.seq.fetch_add(1, Ordering::AcqRel);
-        if seq > our_seq + 1 {
-            warn!("[Shard {:?}] Sequence off; them: {}, expected: {}", self.shard_info, seq, our_seq);
-            self.seq.store(seq, Ordering::Release);
+        if seq > self.seq + 1 {
+            warn!("[Shard {:?}] Sequence off; them: {}, us: {}", self.shard_info, seq, self.seq);
+        }
+
+        self.seq = seq;
+
+        if self.stage == ConnectionStage::Resuming && !matches!(event, Event::Resumed(_)) {
+            self.replayed += 1;
         }
 
         match event {
             Event::Ready(ref ready) => {
                 debug!("[Shard {:?}] Received Ready", self.shard_info);
 
-                *self.session_id.lock().expect("Poison") = Some(ready.ready.session_id.clone());
+                self.session_id = Some(ready.ready.session_id.clone());
+                self.resume_ws_url.clone_from(&ready.ready.resume_gateway_url);
                 self.stage = ConnectionStage::Connected;
 
                 if let Some(ref http) = self.http {
@@ -401,7 +446,7 @@ impl Shard {
         info!("[Shard {:?}] Received shard heartbeat", self.shard_info);
 
         // Received seq is off -- attempt to resume.
-        let our_seq = self.seq();
+        let our_seq = self.seq;
         if s > our_seq + 1 {
             info!(
                 "[Shard {:?}] Received off sequence (them: {}; us: {}); resuming",
@@ -458,7 +503,7 @@ impl Shard {
             Some(close_codes::INVALID_SEQUENCE) => {
                 warn!("[Shard {:?}] Sent invalid seq: {}.", self.shard_info, self.seq());
 
-                self.seq.store(0, Ordering::Release);
+                self.seq = 0;
             },
             Some(close_codes::RATE_LIMITED) => {
                 warn!("[Shard {:?}] Gateway ratelimited.", self.shard_info);
@@ -476,7 +521,8 @@ impl Shard {
             Some(4006 | close_codes::SESSION_TIMEOUT) => {
                 info!("[Shard {:?}] Invalid session.", self.shard_info);
 
-                *self.session_id.lock().expect("Poison") = None;
+                self.session_id = None;
+                self.resume_ws_url = None;
             },
             Some(close_codes::INVALID_GATEWAY_INTENTS) => {
                 error!("[Shard {:?}] Invalid gateway intents have been provided.", self.shard_info);
@@ -503,7 +549,7 @@ impl Shard {
         }
 
         let resume = num
-            .map_or(true, |x| x != close_codes::AUTHENTICATION_FAILED && self.session_id.lock().expect("Poison").is_some());
+            .map_or(true, |x| x != close_codes::AUTHENTICATION_FAILED && self.session_id.is_some());
 
         Ok(Some(if resume {
             ShardAction::Reconnect(ReconnectType::Resume)
@@ -576,6 +622,10 @@ impl Shard {
                 Ok(Some(if resumable {
                     ShardAction::Reconnect(ReconnectType::Resume)
                 } else {
+                    // The session must not be resumed after the restart.
+                    self.session_id = None;
+                    self.resume_ws_url = None;
+
                     ShardAction::Reconnect(ReconnectType::Reidentify)
                 }))
             },
@@ -769,7 +819,32 @@ impl Shard {
     ) -> Result<()> {
         debug!("[Shard {:?}] Requesting member chunks", self.shard_info);
 
-        self.client.send_chunk_guild(guild_id, &self.shard_info, limit, filter, nonce).await
+        self.client
+            .send_chunk_guild_with_encoding(
+                guild_id,
+                &self.shard_info,
+                limit,
+                filter,
+                nonce,
+                self.gateway_params.encoding,
+            )
+            .await
+    }
+
+    /// Receives the next payload from the gateway, inflating it if the
+    /// connection uses transport compression.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection failed, or the payload could not be
+    /// decompressed or deserialized.
+    pub(crate) async fn recv_event(&mut self) -> Result<Option<GatewayEvent>> {
+        match &mut self.inflater {
+            Some(inflater) => {
+                self.client.recv_event_inflated(inflater, self.gateway_params.encoding).await
+            },
+            None => self.client.recv_event(self.gateway_params.encoding).await,
+        }
     }
 
     /// Sets the shard as going into identifying stage, which sets:
@@ -778,7 +853,19 @@ impl Shard {
     /// - the `stage` to [`ConnectionStage::Identifying`]
     #[instrument(skip(self))]
     pub async fn identify(&mut self) -> Result<()> {
-        self.client.send_identify(&self.shard_info, &self.token, self.intents).await?;
+        // Payload compression is redundant with transport compression, and
+        // compressed payloads can't be told apart from binary ETF payloads.
+        let compress = self.gateway_params.compress.is_none()
+            && self.gateway_params.encoding == GatewayEncoding::Json;
+        self.client
+            .send_identify_with_encoding(
+                &self.shard_info,
+                &self.token,
+                self.intents,
+                compress,
+                self.gateway_params.encoding,
+            )
+            .await?;
 
         self.heartbeat_instants.0 = Some(Instant::now());
         self.stage = ConnectionStage::Identifying;
@@ -792,6 +879,12 @@ impl Shard {
     /// the client.
     #[instrument(skip(self))]
     pub async fn initialize(&mut self) -> Result<WsStream> {
+        let url = self.ws_url.lock().await.clone();
+
+        self.initialize_with_url(&url).await
+    }
+
+    async fn initialize_with_url(&mut self, url: &str) -> Result<WsStream> {
         debug!("[Shard {:?}] Initializing.", self.shard_info);
 
         // We need to do two, sort of three things here:
@@ -804,8 +897,8 @@ impl Shard {
         // accurate when a Hello is received.
         self.stage = ConnectionStage::Connecting;
         self.started = Instant::now();
-        let url = &self.ws_url.lock().await.clone();
-        let client = connect(url).await?;
+        let client = connect(url, &self.gateway_params).await?;
+        self.inflater = self.gateway_params.compress.map(|_| Inflater::new());
         self.stage = ConnectionStage::Handshake;
 
         Ok(client)
@@ -816,21 +909,44 @@ impl Shard {
         self.heartbeat_instants = (Some(Instant::now()), None);
         self.heartbeat_interval = None;
         self.last_heartbeat_acknowledged = true;
-        *self.session_id.lock().expect("Poison") = None;
+        self.session_id = None;
+        self.resume_ws_url = None;
         self.stage = ConnectionStage::Disconnected;
-        self.seq.store(0, Ordering::Release); // I think this may need to be release.
+        self.seq = 0;
     }
 
+    /// Reconnects to the gateway and resumes the current session.
+    ///
+    /// The new connection is made to the session's [`Self::resume_ws_url`] if
+    /// there is one.
     //#[instrument(skip(self))]
     pub async fn resume(&mut self) -> Result<()> {
         debug!("[Shard {:?}] Attempting to resume", self.shard_info);
 
-        self.client = self.initialize().await?;
+        let url = match &self.resume_ws_url {
+            Some(url) => url.clone(),
+            None => self.ws_url.lock().await.clone(),
+        };
+        self.client = self.initialize_with_url(&url).await?;
+
+        self.send_resume().await
+    }
+
+    async fn send_resume(&mut self) -> Result<()> {
         self.stage = ConnectionStage::Resuming;
+        self.replayed = 0;
 
-        match self.session_id() {
+        match &self.session_id {
             Some(session_id) => {
-                self.client.send_resume(&self.shard_info, &session_id, self.seq(), &self.token).await
+                self.client
+                    .send_resume_with_encoding(
+                        &self.shard_info,
+                        session_id,
+                        self.seq,
+                        &self.token,
+                        self.gateway_params.encoding,
+                    )
+                    .await
             },
             None => Err(Error::Gateway(GatewayError::NoSessionId)),
         }
@@ -848,17 +964,22 @@ impl Shard {
 
     #[instrument(skip(self))]
     pub async fn update_presence(&mut self) -> Result<()> {
-        self.client.send_presence_update(&self.shard_info, &self.current_presence).await
+        self.client
+            .send_presence_update_with_encoding(
+                &self.shard_info,
+                &self.current_presence,
+                self.gateway_params.encoding,
+            )
+            .await
     }
 }
 
-async fn connect(base_url: &str) -> Result<WsStream> {
-    let url =
-        Url::parse(&format!("{}?v={}", base_url, constants::GATEWAY_VERSION)).map_err(|why| {
-            warn!("Error building gateway URL with base `{}`: {:?}", base_url, why);
+async fn connect(base_url: &str, params: &GatewayParams) -> Result<WsStream> {
+    let url = Url::parse(&format!("{}?{}", base_url, params.query())).map_err(|why| {
+        warn!("Error building gateway URL with base `{}`: {:?}", base_url, why);
 
-            Error::Gateway(GatewayError::BuildingUrl)
-        })?;
+        Error::Gateway(GatewayError::BuildingUrl)
+    })?;
 
     create_client(url).await
 }
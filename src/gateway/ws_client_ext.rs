@@ -6,7 +6,7 @@ use tracing::{debug, instrument, trace};
 
 use crate::client::bridge::gateway::ChunkGuildFilter;
 use crate::constants::{self, OpCode};
-use crate::gateway::{CurrentPresence, WsStream};
+use crate::gateway::{CurrentPresence, GatewayEncoding, WsStream};
 use crate::internal::prelude::*;
 use crate::internal::ws_impl::SenderExt;
 use crate::json::json;
@@ -22,21 +22,80 @@ pub trait WebSocketGatewayClientExt {
         limit: Option<u16>,
         filter: ChunkGuildFilter,
         nonce: Option<&str>,
+    ) -> Result<()> {
+        self.send_chunk_guild_with_encoding(
+            guild_id,
+            shard_info,
+            limit,
+            filter,
+            nonce,
+            GatewayEncoding::Json,
+        )
+        .await
+    }
+
+    /// Like [`Self::send_chunk_guild`], sending the payload in the given
+    /// encoding.
+    async fn send_chunk_guild_with_encoding(
+        &mut self,
+        guild_id: GuildId,
+        shard_info: &[u64; 2],
+        limit: Option<u16>,
+        filter: ChunkGuildFilter,
+        nonce: Option<&str>,
+        encoding: GatewayEncoding,
     ) -> Result<()>;
 
-    async fn send_heartbeat(&mut self, shard_info: &[u64; 2], seq: Option<u64>) -> Result<()>;
+    async fn send_heartbeat(&mut self, shard_info: &[u64; 2], seq: Option<u64>) -> Result<()> {
+        self.send_heartbeat_with_encoding(shard_info, seq, GatewayEncoding::Json).await
+    }
+
+    /// Like [`Self::send_heartbeat`], sending the payload in the given
+    /// encoding.
+    async fn send_heartbeat_with_encoding(
+        &mut self,
+        shard_info: &[u64; 2],
+        seq: Option<u64>,
+        encoding: GatewayEncoding,
+    ) -> Result<()>;
 
     async fn send_identify(
         &mut self,
         shard_info: &[u64; 2],
         token: &str,
         intents: GatewayIntents,
+    ) -> Result<()> {
+        self.send_identify_with_encoding(shard_info, token, intents, true, GatewayEncoding::Json)
+            .await
+    }
+
+    /// Like [`Self::send_identify`], sending the payload in the given encoding,
+    /// with `compress` enabling per-payload compression.
+    async fn send_identify_with_encoding(
+        &mut self,
+        shard_info: &[u64; 2],
+        token: &str,
+        intents: GatewayIntents,
+        compress: bool,
+        encoding: GatewayEncoding,
     ) -> Result<()>;
 
     async fn send_presence_update(
         &mut self,
         shard_info: &[u64; 2],
         current_presence: &CurrentPresence,
+    ) -> Result<()> {
+        self.send_presence_update_with_encoding(shard_info, current_presence, GatewayEncoding::Json)
+            .await
+    }
+
+    /// Like [`Self::send_presence_update`], sending the payload in the given
+    /// encoding.
+    async fn send_presence_update_with_encoding(
+        &mut self,
+        shard_info: &[u64; 2],
+        current_presence: &CurrentPresence,
+        encoding: GatewayEncoding,
     ) -> Result<()>;
 
     async fn send_resume(
@@ -45,19 +104,33 @@ pub trait WebSocketGatewayClientExt {
         session_id: &str,
         seq: u64,
         token: &str,
+    ) -> Result<()> {
+        self.send_resume_with_encoding(shard_info, session_id, seq, token, GatewayEncoding::Json)
+            .await
+    }
+
+    /// Like [`Self::send_resume`], sending the payload in the given encoding.
+    async fn send_resume_with_encoding(
+        &mut self,
+        shard_info: &[u64; 2],
+        session_id: &str,
+        seq: u64,
+        token: &str,
+        encoding: GatewayEncoding,
     ) -> Result<()>;
 }
 
 #[async_trait]
 impl WebSocketGatewayClientExt for WsStream {
     #[instrument(skip(self))]
-    async fn send_chunk_guild(
+    async fn send_chunk_guild_with_encoding(
         &mut self,
         guild_id: GuildId,
         shard_info: &[u64; 2],
         limit: Option<u16>,
         filter: ChunkGuildFilter,
         nonce: Option<&str>,
+        encoding: GatewayEncoding,
     ) -> Result<()> {
         debug!("[Shard {:?}] Requesting member chunks", shard_info);
 
@@ -79,94 +152,115 @@ impl WebSocketGatewayClientExt for WsStream {
             },
         };
 
-        self.send_json(&payload).await.map_err(From::from)
+        self.send_payload(&payload, encoding).await.map_err(From::from)
     }
 
     #[instrument(skip(self))]
-    async fn send_heartbeat(&mut self, shard_info: &[u64; 2], seq: Option<u64>) -> Result<()> {
+    async fn send_heartbeat_with_encoding(
+        &mut self,
+        shard_info: &[u64; 2],
+        seq: Option<u64>,
+        encoding: GatewayEncoding,
+    ) -> Result<()> {
         trace!("[Shard {:?}] Sending heartbeat d: {:?}", shard_info, seq);
 
-        self.send_json(&json!({
-            "d": seq,
-            "op": OpCode::Heartbeat.num(),
-        }))
+        self.send_payload(
+            &json!({
+                "d": seq,
+                "op": OpCode::Heartbeat.num(),
+            }),
+            encoding,
+        )
         .await
         .map_err(From::from)
     }
 
     #[instrument(skip(self, token))]
-    async fn send_identify(
+    async fn send_identify_with_encoding(
         &mut self,
         shard_info: &[u64; 2],
         token: &str,
         intents: GatewayIntents,
+        compress: bool,
+        encoding: GatewayEncoding,
     ) -> Result<()> {
         debug!("[Shard {:?}] Identifying", shard_info);
 
-        self.send_json(&json!({
-            "op": OpCode::Identify.num(),
-            "d": {
-                "compress": true,
-                "large_threshold": constants::LARGE_THRESHOLD,
-                "shard": shard_info,
-                "token": token,
-                "intents": intents,
-                "v": constants::GATEWAY_VERSION,
-                "properties": {
-                    "$browser": "serenity",
-                    "$device": "serenity",
-                    "$os": consts::OS,
+        self.send_payload(
+            &json!({
+                "op": OpCode::Identify.num(),
+                "d": {
+                    "compress": compress,
+                    "large_threshold": constants::LARGE_THRESHOLD,
+                    "shard": shard_info,
+                    "token": token,
+                    "intents": intents,
+                    "v": constants::GATEWAY_VERSION,
+                    "properties": {
+                        "$browser": "serenity",
+                        "$device": "serenity",
+                        "$os": consts::OS,
+                    },
                 },
-            },
-        }))
+            }),
+            encoding,
+        )
         .await
     }
 
     #[instrument(skip(self))]
-    async fn send_presence_update(
+    async fn send_presence_update_with_encoding(
         &mut self,
         shard_info: &[u64; 2],
         current_presence: &CurrentPresence,
+        encoding: GatewayEncoding,
     ) -> Result<()> {
         let (activity, status) = current_presence;
         let now = SystemTime::now();
 
         debug!("[Shard {:?}] Sending presence update", shard_info);
 
-        self.send_json(&json!({
-            "op": OpCode::StatusUpdate.num(),
-            "d": {
-                "afk": false,
-                "since": now,
-                "status": status.name(),
-                "game": activity.as_ref().map(|x| json!({
-                    "name": x.name,
-                    "type": x.kind,
-                    "url": x.url,
-                })),
-            },
-        }))
+        self.send_payload(
+            &json!({
+                "op": OpCode::StatusUpdate.num(),
+                "d": {
+                    "afk": false,
+                    "since": now,
+                    "status": status.name(),
+                    "game": activity.as_ref().map(|x| json!({
+                        "name": x.name,
+                        "type": x.kind,
+                        "url": x.url,
+                    })),
+                },
+            }),
+            encoding,
+        )
         .await
     }
 
     #[instrument(skip(self, token))]
-    async fn send_resume(
+    async fn send_resume_with_encoding(
         &mut self,
         shard_info: &[u64; 2],
         session_id: &str,
         seq: u64,
         token: &str,
+        encoding: GatewayEncoding,
     ) -> Result<()> {
         debug!("[Shard {:?}] Sending resume; seq: {}", shard_info, seq);
 
-        self.send_json(&json!({
-            "op": OpCode::Resume.num(),
-            "d": {
-                "session_id": session_id,
-                "seq": seq,
-                "token": token,
-            },
-        }))
+        self.send_payload(
+            &json!({
+                "op": OpCode::Resume.num(),
+                "d": {
+                    "session_id": session_id,
+                    "seq": seq,
+                    "token": token,
+                },
+            }),
+            encoding,
+        )
         .await
         .map_err(From::from)
     }
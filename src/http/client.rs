@@ -6,19 +6,21 @@ use std::fmt;
 use std::str::FromStr;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use reqwest::header::{HeaderMap as Headers, HeaderValue, CONTENT_TYPE};
 use reqwest::{Client, ClientBuilder, Response as ReqwestResponse, StatusCode, Url};
 use serde::de::DeserializeOwned;
+use tokio::time::{sleep, timeout};
 use tracing::{debug, instrument, trace};
 
 use super::multipart::Multipart;
-use super::ratelimiting::{RatelimitedRequest, Ratelimiter};
-use super::request::Request;
-use super::routing::RouteInfo;
+use super::ratelimiting::{RatelimitBackend, RatelimitedRequest, Ratelimiter};
+use super::request::{Request, RequestPriority};
+use super::routing::{BaseUrls, RouteInfo};
 use super::typing::Typing;
-use super::{AttachmentType, GuildPagination, HttpError, UserPagination};
+use super::{AttachmentType, GuildPagination, HttpError, RetryPolicy, UserPagination};
 use crate::internal::prelude::*;
 use crate::json::prelude::*;
 use crate::model::application::command::{Command, CommandPermission};
@@ -49,8 +51,12 @@ pub struct HttpBuilder {
     client: Option<Client>,
     ratelimiter: Option<Ratelimiter>,
     ratelimiter_disabled: bool,
+    ratelimit_backend: Option<Arc<dyn RatelimitBackend>>,
+    global_ratelimit: Option<u32>,
+    retry_policy: Option<RetryPolicy>,
+    default_timeout: Option<Duration>,
     token: String,
-    proxy: Option<Url>,
+    base_urls: BaseUrls,
     application_id: Option<u64>,
 }
 
@@ -62,8 +68,12 @@ impl HttpBuilder {
             client: None,
             ratelimiter: None,
             ratelimiter_disabled: false,
+            ratelimit_backend: None,
+            global_ratelimit: None,
+            retry_policy: None,
+            default_timeout: None,
             token: parse_token(token),
-            proxy: None,
+            base_urls: BaseUrls::default(),
             application_id: None,
         }
     }
@@ -103,6 +113,67 @@ impl HttpBuilder {
         self
     }
 
+    /// Sets the backend keeping the ratelimiter's ratelimits. If one isn't
+    /// provided, an [`InMemoryRatelimitBackend`] will be used.
+    ///
+    /// A backend shared between processes, such as a
+    /// [`CoordinatedRatelimitBackend`], makes them share their ratelimits.
+    ///
+    /// [`InMemoryRatelimitBackend`]: super::ratelimiting::InMemoryRatelimitBackend
+    /// [`CoordinatedRatelimitBackend`]: super::ratelimiting::CoordinatedRatelimitBackend
+    #[must_use]
+    pub fn ratelimit_backend<B: RatelimitBackend + 'static>(mut self, backend: B) -> Self {
+        self.ratelimit_backend = Some(Arc::new(backend));
+
+        self
+    }
+
+    /// Sets the ratelimit backend. Passed by Arc.
+    /// See [`Self::ratelimit_backend`] for more info.
+    #[must_use]
+    pub fn ratelimit_backend_arc<B: RatelimitBackend + 'static>(mut self, backend: Arc<B>) -> Self {
+        self.ratelimit_backend = Some(backend);
+
+        self
+    }
+
+    /// Sets the number of requests per second that requests are pre-emptively
+    /// limited to for the global ratelimit. If this isn't used, the default of
+    /// 50 is used.
+    ///
+    /// This should only be raised for bots whose global ratelimit was raised
+    /// by Discord. A limit of 0 disables pre-emptive global ratelimiting.
+    #[must_use]
+    pub fn global_ratelimit(mut self, requests_per_second: u32) -> Self {
+        self.global_ratelimit = Some(requests_per_second);
+
+        self
+    }
+
+    /// Sets the policy for retrying requests that failed for transient
+    /// reasons, such as a server error or a reset connection. If this isn't
+    /// used, requests are not retried.
+    #[must_use]
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+
+        self
+    }
+
+    /// Sets the maximum time to spend on a request, including waiting on
+    /// ratelimits and retries, after which it fails with
+    /// [`HttpError::Timeout`]. If this isn't used, requests have no timeout.
+    ///
+    /// This can be overridden per request with [`RequestBuilder::timeout`].
+    ///
+    /// [`RequestBuilder::timeout`]: super::request::RequestBuilder::timeout
+    #[must_use]
+    pub fn default_timeout(mut self, default_timeout: Duration) -> Self {
+        self.default_timeout = Some(default_timeout);
+
+        self
+    }
+
     /// Sets whether or not the ratelimiter is disabled. By default if this this
     /// not used, it is enabled. In most cases, this should be used in
     /// conjunction with [`Self::proxy`].
@@ -132,15 +203,56 @@ impl HttpBuilder {
     /// requests that use TLS via [`HTTP CONNECT`] method (e.g. using
     /// [`reqwest::Proxy`]).
     ///
+    /// This sets the [API base URL] to the proxy, followed by the path of
+    /// Discord's API base URL, e.g. `http://127.0.0.1:3000/api/v10`.
+    ///
     /// [`twilight-http-proxy`]: https://github.com/twilight-rs/http-proxy
     /// [`HTTP CONNECT`]: https://developer.mozilla.org/en-US/docs/Web/HTTP/Methods/CONNECT
+    /// [API base URL]: Self::api_base
     pub fn proxy(mut self, proxy: impl Into<String>) -> Result<Self> {
         let proxy = Url::from_str(&proxy.into()).map_err(HttpError::Url)?;
-        self.proxy = Some(proxy);
+        self.base_urls.api = BaseUrls::join(&proxy, BaseUrls::default().api.path())?;
 
         Ok(self)
     }
 
+    /// Sets the base URL that REST API requests are sent to, including the
+    /// API version. Defaults to `https://discord.com/api/v10`.
+    ///
+    /// Any scheme and port may be used, e.g. `http://localhost:8080/api/v10`
+    /// for a local mock server.
+    #[must_use]
+    pub fn api_base(mut self, api_base: Url) -> Self {
+        self.base_urls.api = api_base;
+
+        self
+    }
+
+    /// Sets the base URL of the CDN. Defaults to `https://cdn.discordapp.com`.
+    ///
+    /// **Note**: URLs built by models, such as [`User::avatar_url`], have no
+    /// access to the [`Http`] client, so building a client with an API or CDN
+    /// base other than Discord's sets the bases of those for the whole
+    /// process.
+    ///
+    /// [`User::avatar_url`]: crate::model::user::User::avatar_url
+    #[must_use]
+    pub fn cdn_base(mut self, cdn_base: Url) -> Self {
+        self.base_urls.cdn = cdn_base;
+
+        self
+    }
+
+    /// Sets the base URL that status page requests, such as
+    /// [`Http::get_unresolved_incidents`], are sent to. Defaults to
+    /// `https://status.discord.com/api/v2`.
+    #[must_use]
+    pub fn status_base(mut self, status_base: Url) -> Self {
+        self.base_urls.status = status_base;
+
+        self
+    }
+
     /// Use the given configuration to build the `Http` client.
     #[must_use]
     pub fn build(self) -> Http {
@@ -153,10 +265,18 @@ impl HttpBuilder {
             builder.build().expect("Cannot build reqwest::Client")
         });
 
-        let ratelimiter = self.ratelimiter.unwrap_or_else(|| {
+        let mut ratelimiter = self.ratelimiter.unwrap_or_else(|| {
             let client = client.clone();
             Ratelimiter::new(client, token.to_string())
         });
+        set_model_base_urls(&self.base_urls);
+        ratelimiter.set_base_urls(self.base_urls);
+        if let Some(backend) = self.ratelimit_backend {
+            ratelimiter.set_backend(backend);
+        }
+        if let Some(requests_per_second) = self.global_ratelimit {
+            ratelimiter.set_global_limit(requests_per_second);
+        }
 
         let ratelimiter_disabled = self.ratelimiter_disabled;
 
@@ -164,13 +284,24 @@ impl HttpBuilder {
             client,
             ratelimiter,
             ratelimiter_disabled,
-            proxy: self.proxy,
+            retry_policy: self.retry_policy,
+            default_timeout: self.default_timeout,
             token,
             application_id,
         }
     }
 }
 
+// URLs built by models have no access to the client, so API and CDN bases
+// other than Discord's are used for the whole process.
+fn set_model_base_urls(base_urls: &BaseUrls) {
+    let default = BaseUrls::default();
+
+    if base_urls.api != default.api || base_urls.cdn != default.cdn {
+        crate::internal::base_urls::set(base_urls.api.as_str(), base_urls.cdn.as_str());
+    }
+}
+
 fn parse_token(token: impl AsRef<str>) -> String {
     let token = token.as_ref().trim();
 
@@ -200,7 +331,8 @@ pub struct Http {
     pub(crate) client: Client,
     pub ratelimiter: Ratelimiter,
     pub ratelimiter_disabled: bool,
-    pub proxy: Option<Url>,
+    pub retry_policy: Option<RetryPolicy>,
+    pub default_timeout: Option<Duration>,
     pub token: String,
     application_id: AtomicU64,
 }
@@ -211,7 +343,9 @@ impl fmt::Debug for Http {
             .field("client", &self.client)
             .field("ratelimiter", &self.ratelimiter)
             .field("ratelimiter_disabled", &self.ratelimiter_disabled)
-            .field("proxy", &self.proxy)
+            .field("retry_policy", &self.retry_policy)
+            .field("default_timeout", &self.default_timeout)
+            .field("base_urls", self.base_urls())
             .finish()
     }
 }
@@ -230,12 +364,32 @@ impl Http {
             client,
             ratelimiter: Ratelimiter::new(client2, token.to_string()),
             ratelimiter_disabled: false,
-            proxy: None,
+            retry_policy: None,
+            default_timeout: None,
             token,
             application_id: AtomicU64::new(0),
         }
     }
 
+    /// The base URLs that requests are sent to, as kept by the
+    /// [`Ratelimiter`].
+    ///
+    /// **Note**: These replace the former `proxy` field, a proxy now being set
+    /// as the API base URL through [`HttpBuilder::proxy`].
+    #[must_use]
+    pub fn base_urls(&self) -> &BaseUrls {
+        self.ratelimiter.base_urls()
+    }
+
+    /// Sets the base URLs that requests are sent to.
+    ///
+    /// As with [`HttpBuilder::cdn_base`], API and CDN bases other than
+    /// Discord's are used by models for the whole process.
+    pub fn set_base_urls(&mut self, base_urls: BaseUrls) {
+        set_model_base_urls(&base_urls);
+        self.ratelimiter.set_base_urls(base_urls);
+    }
+
     #[must_use]
     pub fn new_with_application_id(token: &str, application_id: u64) -> Self {
         let http = Self::new(token);
@@ -279,6 +433,8 @@ impl Http {
                 body: Some(&body),
                 multipart: None,
                 headers: None,
+                timeout: None,
+                priority: RequestPriority::Normal,
                 route: RouteInfo::AddGuildMember {
                     guild_id,
                     user_id,
@@ -310,6 +466,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::AddMemberRole {
                 guild_id,
                 role_id,
@@ -339,6 +497,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: Some(reason_into_header(reason)),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GuildBanUser {
                 delete_message_days: Some(delete_message_days),
                 guild_id,
@@ -360,6 +520,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::BroadcastTyping {
                 channel_id,
             },
@@ -387,6 +549,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreateChannel {
                 guild_id,
             },
@@ -400,6 +564,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreateStageInstance,
         })
         .await
@@ -419,6 +585,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreatePublicThread {
                 channel_id,
                 message_id,
@@ -439,6 +607,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreatePrivateThread {
                 channel_id,
             },
@@ -464,6 +634,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreateEmoji {
                 guild_id,
             },
@@ -483,6 +655,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::High,
             route: RouteInfo::CreateFollowupMessage {
                 application_id: self.try_application_id()?,
                 interaction_token,
@@ -508,6 +682,8 @@ impl Http {
                 fields: vec![],
             }),
             headers: None,
+            timeout: None,
+            priority: RequestPriority::High,
             route: RouteInfo::CreateFollowupMessage {
                 application_id: self.try_application_id()?,
                 interaction_token,
@@ -532,6 +708,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreateGlobalApplicationCommand {
                 application_id: self.try_application_id()?,
             },
@@ -545,6 +723,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreateGlobalApplicationCommands {
                 application_id: self.try_application_id()?,
             },
@@ -562,6 +742,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreateGuildApplicationCommands {
                 application_id: self.try_application_id()?,
                 guild_id,
@@ -608,6 +790,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreateGuild,
         })
         .await
@@ -629,6 +813,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreateGuildApplicationCommand {
                 application_id: self.try_application_id()?,
                 guild_id,
@@ -656,6 +842,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreateGuildIntegration {
                 guild_id,
                 integration_id,
@@ -668,6 +856,9 @@ impl Http {
     ///
     /// Refer to Discord's [docs] for the object it takes.
     ///
+    /// The response is sent with [`RequestPriority::High`], ahead of other
+    /// requests waiting on the same ratelimit.
+    ///
     /// [`Interaction`]: crate::model::application::interaction::Interaction
     /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#interaction-interaction-response
     pub async fn create_interaction_response(
@@ -680,6 +871,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::High,
             route: RouteInfo::CreateInteractionResponse {
                 interaction_id,
                 interaction_token,
@@ -692,6 +885,9 @@ impl Http {
     ///
     /// Refer to Discord's [docs] for the object it takes.
     ///
+    /// The response is sent with [`RequestPriority::High`], ahead of other
+    /// requests waiting on the same ratelimit.
+    ///
     /// [`Interaction`]: crate::model::application::interaction::Interaction
     /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#interaction-interaction-response
     pub async fn create_interaction_response_with_files(
@@ -709,6 +905,8 @@ impl Http {
                 fields: vec![],
             }),
             headers: None,
+            timeout: None,
+            priority: RequestPriority::High,
             route: RouteInfo::CreateInteractionResponse {
                 interaction_id,
                 interaction_token,
@@ -739,6 +937,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreateInvite {
                 channel_id,
             },
@@ -759,6 +959,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreatePermission {
                 channel_id,
                 target_id,
@@ -775,6 +977,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreatePrivateChannel,
         })
         .await
@@ -791,6 +995,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreateReaction {
                 reaction: &reaction_type.as_data(),
                 channel_id,
@@ -813,6 +1019,8 @@ impl Http {
                 body: Some(&body),
                 multipart: None,
                 headers: audit_log_reason.map(reason_into_header),
+                timeout: None,
+                priority: RequestPriority::Normal,
                 route: RouteInfo::CreateRole {
                     guild_id,
                 },
@@ -846,6 +1054,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreateScheduledEvent {
                 guild_id,
             },
@@ -885,6 +1095,8 @@ impl Http {
                 payload_json: None,
             }),
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreateSticker {
                 guild_id,
             },
@@ -933,6 +1145,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreateWebhook {
                 channel_id,
             },
@@ -946,6 +1160,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteChannel {
                 channel_id,
             },
@@ -959,6 +1175,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteStageInstance {
                 channel_id,
             },
@@ -972,6 +1190,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteEmoji {
                 guild_id,
                 emoji_id,
@@ -990,6 +1210,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::High,
             route: RouteInfo::DeleteFollowupMessage {
                 application_id: self.try_application_id()?,
                 interaction_token,
@@ -1005,6 +1227,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteGlobalApplicationCommand {
                 application_id: self.try_application_id()?,
                 command_id,
@@ -1019,6 +1243,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteGuild {
                 guild_id,
             },
@@ -1036,6 +1262,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteGuildApplicationCommand {
                 application_id: self.try_application_id()?,
                 guild_id,
@@ -1051,6 +1279,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteGuildIntegration {
                 guild_id,
                 integration_id,
@@ -1065,6 +1295,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteInvite {
                 code,
             },
@@ -1079,6 +1311,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteMessage {
                 channel_id,
                 message_id,
@@ -1093,6 +1327,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteMessages {
                 channel_id,
             },
@@ -1123,6 +1359,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteMessageReactions {
                 channel_id,
                 message_id,
@@ -1142,6 +1380,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteMessageReactionEmoji {
                 reaction: &reaction_type.as_data(),
                 channel_id,
@@ -1160,6 +1400,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::High,
             route: RouteInfo::DeleteOriginalInteractionResponse {
                 application_id: self.try_application_id()?,
                 interaction_token,
@@ -1174,6 +1416,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeletePermission {
                 channel_id,
                 target_id,
@@ -1197,6 +1441,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteReaction {
                 reaction: &reaction_type.as_data(),
                 user: &user,
@@ -1213,6 +1459,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteRole {
                 guild_id,
                 role_id,
@@ -1232,6 +1480,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteScheduledEvent {
                 guild_id,
                 event_id,
@@ -1255,6 +1505,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteSticker {
                 guild_id,
                 sticker_id,
@@ -1289,6 +1541,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteWebhook {
                 webhook_id,
             },
@@ -1321,6 +1575,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteWebhookWithToken {
                 token,
                 webhook_id,
@@ -1342,6 +1598,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditChannel {
                 channel_id,
             },
@@ -1355,6 +1613,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditStageInstance {
                 channel_id,
             },
@@ -1376,6 +1636,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditEmoji {
                 guild_id,
                 emoji_id,
@@ -1399,6 +1661,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::High,
             route: RouteInfo::EditFollowupMessage {
                 application_id: self.try_application_id()?,
                 interaction_token,
@@ -1428,6 +1692,8 @@ impl Http {
                 fields: vec![],
             }),
             headers: None,
+            timeout: None,
+            priority: RequestPriority::High,
             route: RouteInfo::EditFollowupMessage {
                 application_id: self.try_application_id()?,
                 interaction_token,
@@ -1451,6 +1717,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::High,
             route: RouteInfo::GetFollowupMessage {
                 application_id: self.try_application_id()?,
                 interaction_token,
@@ -1476,6 +1744,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditGlobalApplicationCommand {
                 application_id: self.try_application_id()?,
                 command_id,
@@ -1497,6 +1767,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditGuild {
                 guild_id,
             },
@@ -1521,6 +1793,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditGuildApplicationCommand {
                 application_id: self.try_application_id()?,
                 guild_id,
@@ -1547,6 +1821,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditGuildApplicationCommandPermission {
                 application_id: self.try_application_id()?,
                 guild_id,
@@ -1572,6 +1848,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditGuildApplicationCommandsPermissions {
                 application_id: self.try_application_id()?,
                 guild_id,
@@ -1588,6 +1866,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditGuildChannels {
                 guild_id,
             },
@@ -1603,6 +1883,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditGuildWidget {
                 guild_id,
             },
@@ -1622,6 +1904,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditGuildWelcomeScreen {
                 guild_id,
             },
@@ -1644,6 +1928,8 @@ impl Http {
                 body: Some(&body),
                 multipart: None,
                 headers: audit_log_reason.map(reason_into_header),
+                timeout: None,
+                priority: RequestPriority::Normal,
                 route: RouteInfo::EditMember {
                     guild_id,
                     user_id,
@@ -1675,6 +1961,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditMessage {
                 channel_id,
                 message_id,
@@ -1701,6 +1989,8 @@ impl Http {
                 fields: vec![],
             }),
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditMessage {
                 channel_id,
                 message_id,
@@ -1717,6 +2007,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CrosspostMessage {
                 channel_id,
                 message_id,
@@ -1733,6 +2025,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditMemberMe {
                 guild_id,
             },
@@ -1751,6 +2045,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditMemberMe {
                 guild_id,
             },
@@ -1771,6 +2067,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::FollowNewsChannel {
                 channel_id: news_channel_id,
             },
@@ -1787,6 +2085,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::High,
             route: RouteInfo::GetOriginalInteractionResponse {
                 application_id: self.try_application_id()?,
                 interaction_token,
@@ -1809,6 +2109,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::High,
             route: RouteInfo::EditOriginalInteractionResponse {
                 application_id: self.try_application_id()?,
                 interaction_token,
@@ -1826,6 +2128,8 @@ impl Http {
                 body: Some(&body),
                 multipart: None,
                 headers: None,
+                timeout: None,
+                priority: RequestPriority::Normal,
                 route: RouteInfo::EditProfile,
             })
             .await?;
@@ -1847,6 +2151,8 @@ impl Http {
                 body: Some(&body),
                 multipart: None,
                 headers: audit_log_reason.map(reason_into_header),
+                timeout: None,
+                priority: RequestPriority::Normal,
                 route: RouteInfo::EditRole {
                     guild_id,
                     role_id,
@@ -1881,6 +2187,8 @@ impl Http {
                 body: Some(&body),
                 multipart: None,
                 headers: audit_log_reason.map(reason_into_header),
+                timeout: None,
+                priority: RequestPriority::Normal,
                 route: RouteInfo::EditRolePosition {
                     guild_id,
                 },
@@ -1917,6 +2225,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditScheduledEvent {
                 guild_id,
                 event_id,
@@ -1943,6 +2253,8 @@ impl Http {
                 body: Some(&body),
                 multipart: None,
                 headers: audit_log_reason.map(reason_into_header),
+                timeout: None,
+                priority: RequestPriority::Normal,
                 route: RouteInfo::EditSticker {
                     guild_id,
                     sticker_id,
@@ -1967,6 +2279,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditThread {
                 channel_id,
             },
@@ -2014,6 +2328,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditVoiceState {
                 guild_id,
                 user_id,
@@ -2064,6 +2380,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditVoiceStateMe {
                 guild_id,
             },
@@ -2115,6 +2433,8 @@ impl Http {
             body: Some(to_string(map)?.as_bytes()),
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditWebhook {
                 webhook_id,
             },
@@ -2159,6 +2479,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditWebhookWithToken {
                 token,
                 webhook_id,
@@ -2232,6 +2554,8 @@ impl Http {
                 body: Some(&body),
                 multipart: None,
                 headers: Some(headers),
+                timeout: None,
+                priority: RequestPriority::Normal,
                 route: RouteInfo::ExecuteWebhook {
                     token,
                     wait,
@@ -2273,6 +2597,8 @@ impl Http {
                 fields: vec![],
             }),
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::ExecuteWebhook {
                 token,
                 wait,
@@ -2293,6 +2619,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetWebhookMessage {
                 token,
                 webhook_id,
@@ -2316,6 +2644,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditWebhookMessage {
                 token,
                 webhook_id,
@@ -2336,6 +2666,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteWebhookMessage {
                 token,
                 webhook_id,
@@ -2360,6 +2692,8 @@ impl Http {
                 body: None,
                 multipart: None,
                 headers: None,
+                timeout: None,
+                priority: RequestPriority::Normal,
                 route: RouteInfo::GetActiveMaintenance,
             })
             .await?;
@@ -2374,6 +2708,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetBans {
                 guild_id,
             },
@@ -2394,6 +2730,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetAuditLogs {
                 action_type,
                 before,
@@ -2413,6 +2751,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetAutoModRules {
                 guild_id,
             },
@@ -2428,6 +2768,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetAutoModRule {
                 guild_id,
                 rule_id,
@@ -2446,6 +2788,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreateAutoModRule {
                 guild_id,
             },
@@ -2468,6 +2812,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::EditAutoModRule {
                 guild_id,
                 rule_id,
@@ -2484,6 +2830,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::DeleteAutoModRule {
                 guild_id,
                 rule_id,
@@ -2498,6 +2846,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetBotGateway,
         })
         .await
@@ -2509,6 +2859,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetChannelInvites {
                 channel_id,
             },
@@ -2522,6 +2874,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetChannelThreadMembers {
                 channel_id,
             },
@@ -2535,6 +2889,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGuildActiveThreads {
                 guild_id,
             },
@@ -2553,6 +2909,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetChannelArchivedPublicThreads {
                 channel_id,
                 before,
@@ -2573,6 +2931,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetChannelArchivedPrivateThreads {
                 channel_id,
                 before,
@@ -2593,6 +2953,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetChannelJoinedPrivateArchivedThreads {
                 channel_id,
                 before,
@@ -2608,6 +2970,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::JoinThread {
                 channel_id,
             },
@@ -2621,6 +2985,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::LeaveThread {
                 channel_id,
             },
@@ -2634,6 +3000,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::AddThreadMember {
                 channel_id,
                 user_id,
@@ -2648,6 +3016,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::RemoveThreadMember {
                 channel_id,
                 user_id,
@@ -2680,6 +3050,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetChannelWebhooks {
                 channel_id,
             },
@@ -2693,6 +3065,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetChannel {
                 channel_id,
             },
@@ -2706,6 +3080,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetChannels {
                 guild_id,
             },
@@ -2719,6 +3095,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetStageInstance {
                 channel_id,
             },
@@ -2734,6 +3112,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetCurrentApplicationInfo,
         })
         .await
@@ -2745,6 +3125,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetCurrentUser,
         })
         .await
@@ -2756,6 +3138,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetEmojis {
                 guild_id,
             },
@@ -2769,6 +3153,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetEmoji {
                 guild_id,
                 emoji_id,
@@ -2783,6 +3169,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGateway,
         })
         .await
@@ -2794,6 +3182,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGlobalApplicationCommands {
                 application_id: self.try_application_id()?,
             },
@@ -2807,6 +3197,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGlobalApplicationCommand {
                 application_id: self.try_application_id()?,
                 command_id,
@@ -2821,6 +3213,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGuild {
                 guild_id,
             },
@@ -2834,6 +3228,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGuildWithCounts {
                 guild_id,
             },
@@ -2847,6 +3243,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGuildApplicationCommands {
                 application_id: self.try_application_id()?,
                 guild_id,
@@ -2865,6 +3263,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGuildApplicationCommand {
                 application_id: self.try_application_id()?,
                 guild_id,
@@ -2883,6 +3283,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGuildApplicationCommandsPermissions {
                 application_id: self.try_application_id()?,
                 guild_id,
@@ -2901,6 +3303,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGuildApplicationCommandPermissions {
                 application_id: self.try_application_id()?,
                 guild_id,
@@ -2918,6 +3322,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGuildWidget {
                 guild_id,
             },
@@ -2931,6 +3337,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGuildPreview {
                 guild_id,
             },
@@ -2944,6 +3352,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGuildWelcomeScreen {
                 guild_id,
             },
@@ -2957,6 +3367,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGuildIntegrations {
                 guild_id,
             },
@@ -2970,6 +3382,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGuildInvites {
                 guild_id,
             },
@@ -2988,6 +3402,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGuildVanityUrl {
                 guild_id,
             },
@@ -3018,6 +3434,8 @@ impl Http {
                 body: None,
                 multipart: None,
                 headers: None,
+                timeout: None,
+                priority: RequestPriority::Normal,
                 route: RouteInfo::GetGuildMembers {
                     after,
                     guild_id,
@@ -3055,6 +3473,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGuildPruneCount {
                 days: req.days,
                 guild_id,
@@ -3070,6 +3490,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGuildRegions {
                 guild_id,
             },
@@ -3084,6 +3506,8 @@ impl Http {
                 body: None,
                 multipart: None,
                 headers: None,
+                timeout: None,
+                priority: RequestPriority::Normal,
                 route: RouteInfo::GetGuildRoles {
                     guild_id,
                 },
@@ -3118,6 +3542,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetScheduledEvent {
                 guild_id,
                 event_id,
@@ -3141,6 +3567,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetScheduledEvents {
                 guild_id,
                 with_user_count,
@@ -3184,6 +3612,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetScheduledEventUsers {
                 guild_id,
                 event_id,
@@ -3203,6 +3633,8 @@ impl Http {
                 body: None,
                 multipart: None,
                 headers: None,
+                timeout: None,
+                priority: RequestPriority::Normal,
                 route: RouteInfo::GetGuildStickers {
                     guild_id,
                 },
@@ -3229,6 +3661,8 @@ impl Http {
                 body: None,
                 multipart: None,
                 headers: None,
+                timeout: None,
+                priority: RequestPriority::Normal,
                 route: RouteInfo::GetGuildSticker {
                     guild_id,
                     sticker_id,
@@ -3269,6 +3703,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGuildWebhooks {
                 guild_id,
             },
@@ -3319,6 +3755,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetGuilds {
                 after,
                 before,
@@ -3356,6 +3794,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetInvite {
                 code,
                 member_counts,
@@ -3373,6 +3813,8 @@ impl Http {
                 body: None,
                 multipart: None,
                 headers: None,
+                timeout: None,
+                priority: RequestPriority::Normal,
                 route: RouteInfo::GetMember {
                     guild_id,
                     user_id,
@@ -3395,6 +3837,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetMessage {
                 channel_id,
                 message_id,
@@ -3409,6 +3853,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetMessages {
                 query: query.to_owned(),
                 channel_id,
@@ -3428,6 +3874,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetStickerPacks,
         })
         .await?
@@ -3443,6 +3891,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetPins {
                 channel_id,
             },
@@ -3465,6 +3915,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetReactionUsers {
                 after,
                 channel_id,
@@ -3482,6 +3934,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetSticker {
                 sticker_id,
             },
@@ -3504,6 +3958,8 @@ impl Http {
                 body: None,
                 multipart: None,
                 headers: None,
+                timeout: None,
+                priority: RequestPriority::Normal,
                 route: RouteInfo::GetUnresolvedIncidents,
             })
             .await?;
@@ -3527,6 +3983,8 @@ impl Http {
                 body: None,
                 multipart: None,
                 headers: None,
+                timeout: None,
+                priority: RequestPriority::Normal,
                 route: RouteInfo::GetUpcomingMaintenances,
             })
             .await?;
@@ -3541,6 +3999,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetUser {
                 user_id,
             },
@@ -3559,6 +4019,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetUserConnections,
         })
         .await
@@ -3570,6 +4032,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetUserDmChannels,
         })
         .await
@@ -3581,6 +4045,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetVoiceRegions,
         })
         .await
@@ -3610,6 +4076,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetWebhook {
                 webhook_id,
             },
@@ -3642,6 +4110,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetWebhookWithToken {
                 token,
                 webhook_id,
@@ -3675,6 +4145,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::GetWebhookWithToken {
                 token,
                 webhook_id,
@@ -3699,6 +4171,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: Some(reason_into_header(reason)),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::KickMember {
                 guild_id,
                 user_id,
@@ -3713,6 +4187,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::LeaveGuild {
                 guild_id,
             },
@@ -3744,6 +4220,8 @@ impl Http {
                 fields: vec![],
             }),
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreateMessage {
                 channel_id,
             },
@@ -3759,6 +4237,8 @@ impl Http {
             body: Some(&body),
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::CreateMessage {
                 channel_id,
             },
@@ -3777,6 +4257,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::PinMessage {
                 channel_id,
                 message_id,
@@ -3796,6 +4278,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::RemoveBan {
                 guild_id,
                 user_id,
@@ -3821,6 +4305,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::RemoveMemberRole {
                 guild_id,
                 user_id,
@@ -3843,6 +4329,8 @@ impl Http {
                 body: None,
                 multipart: None,
                 headers: None,
+                timeout: None,
+                priority: RequestPriority::Normal,
                 route: RouteInfo::SearchGuildMembers {
                     guild_id,
                     query,
@@ -3875,6 +4363,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::StartGuildPrune {
                 days,
                 guild_id,
@@ -3889,6 +4379,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::StartIntegrationSync {
                 guild_id,
                 integration_id,
@@ -3947,6 +4439,8 @@ impl Http {
             body: None,
             multipart: None,
             headers: audit_log_reason.map(reason_into_header),
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: RouteInfo::UnpinMessage {
                 channel_id,
                 message_id,
@@ -4007,6 +4501,10 @@ impl Http {
 
     /// Performs a request, ratelimiting it if necessary.
     ///
+    /// If the request has a timeout, or the client a [default timeout], it is
+    /// cancelled once the timeout elapses, whether it is waiting on a
+    /// ratelimit or on Discord, and fails with [`HttpError::Timeout`].
+    ///
     /// Returns the raw reqwest Response. Use [`Self::fire`] to deserialize the response
     /// into some type.
     ///
@@ -4040,15 +4538,25 @@ impl Http {
     /// #     Ok(())
     /// # }
     /// ```
+    ///
+    /// [default timeout]: HttpBuilder::default_timeout
     #[instrument]
-    pub async fn request(&self, mut req: Request<'_>) -> Result<ReqwestResponse> {
-        let response = if self.ratelimiter_disabled {
-            let request =
-                req.build(&self.client, &self.token, self.proxy.as_ref()).await?.build()?;
-            self.client.execute(request).await?
-        } else {
-            let ratelimiting_req = RatelimitedRequest::from(req);
-            self.ratelimiter.perform(ratelimiting_req).await?
+    pub async fn request(&self, req: Request<'_>) -> Result<ReqwestResponse> {
+        let (method, ..) = req.route.deconstruct();
+        let request_timeout = req.timeout.or(self.default_timeout);
+
+        let response = async {
+            match &self.retry_policy {
+                Some(policy) if policy.retries(method) => self.perform_retrying(req, policy).await,
+                _ => self.perform(req).await,
+            }
+        };
+
+        let response = match request_timeout {
+            Some(request_timeout) => timeout(request_timeout, response)
+                .await
+                .map_err(|_| Error::Http(Box::new(HttpError::Timeout)))??,
+            None => response.await?,
         };
 
         if response.status().is_success() {
@@ -4058,6 +4566,42 @@ impl Http {
         }
     }
 
+    async fn perform(&self, mut req: Request<'_>) -> Result<ReqwestResponse> {
+        if self.ratelimiter_disabled {
+            let request = req.build(&self.client, &self.token, self.base_urls()).await?.build()?;
+            Ok(self.client.execute(request).await?)
+        } else {
+            let ratelimiting_req = RatelimitedRequest::from(req);
+            self.ratelimiter.perform(ratelimiting_req).await
+        }
+    }
+
+    // Performs a request, retrying it as the policy allows.
+    async fn perform_retrying(
+        &self,
+        req: Request<'_>,
+        policy: &RetryPolicy,
+    ) -> Result<ReqwestResponse> {
+        let mut attempt = 1;
+
+        loop {
+            let result = self.perform(req.clone()).await;
+
+            if !policy.should_retry(attempt, &result) {
+                return result;
+            }
+
+            let delay = policy.backoff_delay(attempt);
+            match &result {
+                Ok(response) => debug!("Retrying after {} in {:?}", response.status(), delay),
+                Err(why) => debug!("Retrying after {:?} in {:?}", why, delay),
+            }
+
+            sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
     /// Performs a request and then verifies that the response status code is equal
     /// to the expected value.
     ///
@@ -4092,3 +4636,111 @@ impl AsRef<Http> for Http {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use reqwest::Url;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::HttpBuilder;
+    use crate::http::ratelimiting::{InMemoryRatelimitBackend, RatelimitBackend};
+    use crate::http::request::RequestBuilder;
+    use crate::http::routing::RouteInfo;
+    use crate::http::HttpError;
+    use crate::internal::prelude::*;
+
+    #[tokio::test]
+    async fn test_request_timeout() -> Result<()> {
+        // A server that accepts connections but never responds.
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let api_base =
+            Url::parse(&format!("http://{}/api/v10", listener.local_addr()?)).expect("Invalid URL");
+
+        let http = HttpBuilder::new("token")
+            .api_base(api_base)
+            .default_timeout(Duration::from_millis(50))
+            .build();
+
+        let is_timeout = |result: Result<_>| match result {
+            Err(Error::Http(why)) => why.is_timeout(),
+            _ => false,
+        };
+
+        assert!(is_timeout(http.get_gateway().await.map(drop)));
+
+        let mut request = RequestBuilder::new(RouteInfo::GetGateway);
+        request.timeout(Some(Duration::from_millis(10)));
+        assert!(is_timeout(http.request(request.build()).await.map(drop)));
+
+        assert!(HttpError::Timeout.is_timeout());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout_in_queue() -> Result<()> {
+        // A server that responds to every request with an exhausted bucket.
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let api_base =
+            Url::parse(&format!("http://{}/api/v10", listener.local_addr()?)).expect("Invalid URL");
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buf = [0; 1024];
+                    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+                        match stream.read(&mut buf).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(read) => request.extend_from_slice(&buf[..read]),
+                        }
+                    }
+
+                    let response = "HTTP/1.1 200 OK\r\n\
+                                    x-ratelimit-bucket: abcd1234\r\n\
+                                    x-ratelimit-limit: 1\r\n\
+                                    x-ratelimit-remaining: 0\r\n\
+                                    x-ratelimit-reset-after: 0.3\r\n\
+                                    content-length: 2\r\n\
+                                    connection: close\r\n\r\n{}";
+                    stream.write_all(response.as_bytes()).await.ok();
+                });
+            }
+        });
+
+        let backend = Arc::new(InMemoryRatelimitBackend::new());
+        let http = HttpBuilder::new("token")
+            .api_base(api_base)
+            .ratelimit_backend_arc(Arc::clone(&backend))
+            .build();
+
+        let waiters = || async {
+            backend.snapshot().await.unwrap().iter().map(|bucket| bucket.waiters).sum::<usize>()
+        };
+
+        // Exhaust the bucket.
+        http.request(RequestBuilder::new(RouteInfo::GetGateway).build()).await?;
+
+        let mut timed_out = RequestBuilder::new(RouteInfo::GetGateway);
+        timed_out.timeout(Some(Duration::from_millis(50)));
+        let next = RequestBuilder::new(RouteInfo::GetGateway);
+
+        // The first request times out while waiting on the bucket, leaving the
+        // next one as the only waiter.
+        let ((timed_out, waiting), next) = tokio::join!(
+            async { (http.request(timed_out.build()).await, waiters().await) },
+            http.request(next.build()),
+        );
+
+        assert!(matches!(timed_out, Err(Error::Http(why)) if matches!(*why, HttpError::Timeout)));
+        assert_eq!(waiting, 1);
+        assert!(next.is_ok());
+        assert_eq!(waiters().await, 0);
+
+        Ok(())
+    }
+}
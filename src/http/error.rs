@@ -5,6 +5,7 @@ use reqwest::header::InvalidHeaderValue;
 use reqwest::{Error as ReqwestError, Response, StatusCode, Url};
 use url::ParseError as UrlError;
 
+pub use super::error_code::JsonErrorCode;
 use crate::http::utils::deserialize_errors;
 
 #[derive(Clone, Deserialize, Serialize, PartialEq, Eq, Debug)]
@@ -20,6 +21,29 @@ pub struct DiscordJsonError {
     pub errors: Vec<DiscordJsonSingleError>,
 }
 
+impl DiscordJsonError {
+    /// The [`JsonErrorCode`] of the error.
+    #[must_use]
+    pub fn error_code(&self) -> JsonErrorCode {
+        JsonErrorCode::from(self.code)
+    }
+
+    /// The errors of the field at the given dot separated path in the request
+    /// body, such as `embeds.0.description`, including those of the fields
+    /// nested within it.
+    pub fn field_errors<'a>(
+        &'a self,
+        path: &'a str,
+    ) -> impl Iterator<Item = &'a DiscordJsonSingleError> + 'a {
+        self.errors.iter().filter(move |error| {
+            error
+                .path
+                .strip_prefix(path)
+                .map_or(false, |rest| path.is_empty() || rest.is_empty() || rest.starts_with('.'))
+        })
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct DiscordJsonSingleError {
     /// The error code.
@@ -30,6 +54,16 @@ pub struct DiscordJsonSingleError {
     pub path: String,
 }
 
+impl DiscordJsonSingleError {
+    /// The segments of the [`path`] to the error, such as `embeds`, `0` and
+    /// `description`.
+    ///
+    /// [`path`]: Self::path
+    pub fn path_segments(&self) -> impl Iterator<Item = &str> {
+        self.path.split('.')
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ErrorResponse {
     pub status_code: StatusCode,
@@ -78,6 +112,9 @@ pub enum Error {
     InvalidPort,
     /// When an application id was expected but missing.
     ApplicationIdMissing,
+    /// When a request did not complete within its timeout, including the time
+    /// spent waiting on ratelimits.
+    Timeout,
 }
 
 impl Error {
@@ -105,6 +142,102 @@ impl Error {
         matches!(self, Self::InvalidHeader(_))
     }
 
+    /// Returns true when the error is caused by a request timing out
+    #[must_use]
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Self::Timeout)
+    }
+
+    /// Returns the error returned by Discord if the error is an unsuccessful
+    /// request
+    #[must_use]
+    pub fn discord_error(&self) -> Option<&DiscordJsonError> {
+        match self {
+            Self::UnsuccessfulRequest(res) => Some(&res.error),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`JsonErrorCode`] if the error is an unsuccessful request
+    #[must_use]
+    pub fn json_error_code(&self) -> Option<JsonErrorCode> {
+        self.discord_error().map(DiscordJsonError::error_code)
+    }
+
+    fn has_json_error_code(&self, code: JsonErrorCode) -> bool {
+        self.json_error_code() == Some(code)
+    }
+
+    /// Returns true when the error is caused by missing permissions
+    #[must_use]
+    pub fn is_missing_permissions(&self) -> bool {
+        self.has_json_error_code(JsonErrorCode::MissingPermissions)
+    }
+
+    /// Returns true when the error is caused by missing access, such as to a
+    /// channel the bot cannot view
+    #[must_use]
+    pub fn is_missing_access(&self) -> bool {
+        self.has_json_error_code(JsonErrorCode::MissingAccess)
+    }
+
+    /// Returns true when the error is caused by an unknown channel
+    #[must_use]
+    pub fn is_unknown_channel(&self) -> bool {
+        self.has_json_error_code(JsonErrorCode::UnknownChannel)
+    }
+
+    /// Returns true when the error is caused by an unknown guild
+    #[must_use]
+    pub fn is_unknown_guild(&self) -> bool {
+        self.has_json_error_code(JsonErrorCode::UnknownGuild)
+    }
+
+    /// Returns true when the error is caused by an unknown member
+    #[must_use]
+    pub fn is_unknown_member(&self) -> bool {
+        self.has_json_error_code(JsonErrorCode::UnknownMember)
+    }
+
+    /// Returns true when the error is caused by an unknown message
+    #[must_use]
+    pub fn is_unknown_message(&self) -> bool {
+        self.has_json_error_code(JsonErrorCode::UnknownMessage)
+    }
+
+    /// Returns true when the error is caused by an unknown role
+    #[must_use]
+    pub fn is_unknown_role(&self) -> bool {
+        self.has_json_error_code(JsonErrorCode::UnknownRole)
+    }
+
+    /// Returns true when the error is caused by an unknown user
+    #[must_use]
+    pub fn is_unknown_user(&self) -> bool {
+        self.has_json_error_code(JsonErrorCode::UnknownUser)
+    }
+
+    /// Returns true when the error is caused by an unknown or expired
+    /// interaction
+    #[must_use]
+    pub fn is_unknown_interaction(&self) -> bool {
+        self.has_json_error_code(JsonErrorCode::UnknownInteraction)
+    }
+
+    /// Returns true when the error is caused by the user not accepting direct
+    /// messages from the bot
+    #[must_use]
+    pub fn is_cannot_send_to_user(&self) -> bool {
+        self.has_json_error_code(JsonErrorCode::CannotSendMessagesToUser)
+    }
+
+    /// Returns true when the error is caused by an invalid request body, whose
+    /// errors are given by [`DiscordJsonError::field_errors`]
+    #[must_use]
+    pub fn is_invalid_form_body(&self) -> bool {
+        self.has_json_error_code(JsonErrorCode::InvalidFormBody)
+    }
+
     /// Returns the status code if the error is an unsuccessful request
     #[must_use]
     pub fn status_code(&self) -> Option<StatusCode> {
@@ -172,6 +305,7 @@ impl fmt::Display for Error {
             Self::InvalidScheme => f.write_str("Invalid Url scheme."),
             Self::InvalidPort => f.write_str("Invalid port."),
             Self::ApplicationIdMissing => f.write_str("Application id was expected but missing."),
+            Self::Timeout => f.write_str("Request timed out."),
         }
     }
 }
@@ -219,4 +353,63 @@ mod test {
 
         assert_eq!(error_response, known);
     }
+
+    #[test]
+    fn test_json_error_code() {
+        let mut json = String::from(
+            r#"{
+                "code": 50035,
+                "message": "Invalid Form Body",
+                "errors": {
+                    "embeds": {
+                        "0": {
+                            "description": {
+                                "_errors": [{"code": "BASE_TYPE_MAX_LENGTH", "message": "Too long."}]
+                            },
+                            "fields": {
+                                "1": {
+                                    "name": {
+                                        "_errors": [{"code": "BASE_TYPE_REQUIRED", "message": "Required."}]
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    "content": {
+                        "_errors": [{"code": "BASE_TYPE_MAX_LENGTH", "message": "Too long."}]
+                    }
+                }
+            }"#,
+        );
+        let error: DiscordJsonError = crate::json::from_str(&mut json).unwrap();
+
+        assert_eq!(error.error_code(), JsonErrorCode::InvalidFormBody);
+        assert_eq!(error.field_errors("embeds").count(), 2);
+        assert_eq!(error.field_errors("embeds.0.description").count(), 1);
+        assert_eq!(error.field_errors("embeds.0.desc").count(), 0);
+        assert_eq!(error.field_errors("").count(), 3);
+
+        let name = error.field_errors("embeds.0.fields").next().unwrap();
+        assert_eq!(name.code, "BASE_TYPE_REQUIRED");
+        assert_eq!(name.path_segments().collect::<Vec<_>>(), [
+            "embeds", "0", "fields", "1", "name"
+        ]);
+
+        let error = Error::UnsuccessfulRequest(ErrorResponse {
+            status_code: StatusCode::FORBIDDEN,
+            url: String::from("https://ferris.crab").parse().unwrap(),
+            error: DiscordJsonError {
+                code: 50013,
+                message: String::from("Missing Permissions"),
+                errors: vec![],
+            },
+        });
+        assert!(error.is_missing_permissions());
+        assert!(!error.is_unknown_message());
+        assert!(!Error::InvalidWebhook.is_missing_permissions());
+
+        assert_eq!(JsonErrorCode::from(10008), JsonErrorCode::UnknownMessage);
+        assert_eq!(JsonErrorCode::from(1), JsonErrorCode::Unrecognized(1));
+        assert_eq!(JsonErrorCode::UnknownMessage.code(), 10008);
+    }
 }
@@ -0,0 +1,390 @@
+/// Generates [`JsonErrorCode`] from Discord's documented codes, along with the
+/// conversions from and to the raw codes.
+macro_rules! json_error_codes {
+    ($($(#[doc = $doc:literal])* $variant:ident = $code:literal,)*) => {
+        /// The code of an error returned by Discord's API, as documented in
+        /// [Discord's docs].
+        ///
+        /// Codes not known to the library are kept as [`Self::Unrecognized`].
+        ///
+        /// [Discord's docs]: https://discord.com/developers/docs/topics/opcodes-and-status-codes#json-json-error-codes
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        #[non_exhaustive]
+        pub enum JsonErrorCode {
+            $(
+                $(#[doc = $doc])*
+                $variant,
+            )*
+            /// A code not known to the library.
+            Unrecognized(isize),
+        }
+
+        impl JsonErrorCode {
+            /// The raw code of the error.
+            #[must_use]
+            pub fn code(self) -> isize {
+                match self {
+                    $(Self::$variant => $code,)*
+                    Self::Unrecognized(code) => code,
+                }
+            }
+        }
+
+        impl From<isize> for JsonErrorCode {
+            fn from(code: isize) -> Self {
+                match code {
+                    $($code => Self::$variant,)*
+                    _ => Self::Unrecognized(code),
+                }
+            }
+        }
+    };
+}
+
+json_error_codes! {
+    /// General error (such as a malformed request body, amongst other things).
+    General = 0,
+    /// Unknown account.
+    UnknownAccount = 10001,
+    /// Unknown application.
+    UnknownApplication = 10002,
+    /// Unknown channel.
+    UnknownChannel = 10003,
+    /// Unknown guild.
+    UnknownGuild = 10004,
+    /// Unknown integration.
+    UnknownIntegration = 10005,
+    /// Unknown invite.
+    UnknownInvite = 10006,
+    /// Unknown member.
+    UnknownMember = 10007,
+    /// Unknown message.
+    UnknownMessage = 10008,
+    /// Unknown permission overwrite.
+    UnknownPermissionOverwrite = 10009,
+    /// Unknown provider.
+    UnknownProvider = 10010,
+    /// Unknown role.
+    UnknownRole = 10011,
+    /// Unknown token.
+    UnknownToken = 10012,
+    /// Unknown user.
+    UnknownUser = 10013,
+    /// Unknown emoji.
+    UnknownEmoji = 10014,
+    /// Unknown webhook.
+    UnknownWebhook = 10015,
+    /// Unknown webhook service.
+    UnknownWebhookService = 10016,
+    /// Unknown session.
+    UnknownSession = 10020,
+    /// Unknown ban.
+    UnknownBan = 10026,
+    /// Unknown SKU.
+    UnknownSku = 10027,
+    /// Unknown store listing.
+    UnknownStoreListing = 10028,
+    /// Unknown entitlement.
+    UnknownEntitlement = 10029,
+    /// Unknown build.
+    UnknownBuild = 10030,
+    /// Unknown lobby.
+    UnknownLobby = 10031,
+    /// Unknown branch.
+    UnknownBranch = 10032,
+    /// Unknown store directory layout.
+    UnknownStoreDirectoryLayout = 10033,
+    /// Unknown redistributable.
+    UnknownRedistributable = 10036,
+    /// Unknown gift code.
+    UnknownGiftCode = 10038,
+    /// Unknown stream.
+    UnknownStream = 10049,
+    /// Unknown premium server subscribe cooldown.
+    UnknownPremiumServerSubscribeCooldown = 10050,
+    /// Unknown guild template.
+    UnknownGuildTemplate = 10057,
+    /// Unknown discoverable server category.
+    UnknownDiscoverableServerCategory = 10059,
+    /// Unknown sticker.
+    UnknownSticker = 10060,
+    /// Unknown interaction.
+    UnknownInteraction = 10062,
+    /// Unknown application command.
+    UnknownApplicationCommand = 10063,
+    /// Unknown voice state.
+    UnknownVoiceState = 10065,
+    /// Unknown application command permissions.
+    UnknownApplicationCommandPermissions = 10066,
+    /// Unknown stage instance.
+    UnknownStageInstance = 10067,
+    /// Unknown guild member verification form.
+    UnknownGuildMemberVerificationForm = 10068,
+    /// Unknown guild welcome screen.
+    UnknownGuildWelcomeScreen = 10069,
+    /// Unknown guild scheduled event.
+    UnknownGuildScheduledEvent = 10070,
+    /// Unknown guild scheduled event user.
+    UnknownGuildScheduledEventUser = 10071,
+    /// Unknown tag.
+    UnknownTag = 10087,
+    /// Bots cannot use this endpoint.
+    BotsCannotUseEndpoint = 20001,
+    /// Only bots can use this endpoint.
+    OnlyBotsCanUseEndpoint = 20002,
+    /// Explicit content cannot be sent to the desired recipient(s).
+    ExplicitContentCannotBeSent = 20009,
+    /// You are not authorized to perform this action on this application.
+    NotAuthorizedForApplication = 20012,
+    /// This action cannot be performed due to slowmode rate limit.
+    SlowmodeRateLimit = 20016,
+    /// Only the owner of this account can perform this action.
+    OnlyAccountOwner = 20018,
+    /// This message cannot be edited due to announcement rate limits.
+    AnnouncementRateLimit = 20022,
+    /// Under minimum age.
+    UnderMinimumAge = 20024,
+    /// The channel you are writing has hit the write rate limit.
+    ChannelWriteRateLimit = 20028,
+    /// The write action you are performing on the server has hit the write rate
+    /// limit.
+    ServerWriteRateLimit = 20029,
+    /// Your stage topic, server name, server description, or channel names
+    /// contain words that are not allowed.
+    DisallowedWords = 20031,
+    /// Guild premium subscription level too low.
+    PremiumTierTooLow = 20035,
+    /// Maximum number of guilds reached (100).
+    MaximumGuilds = 30001,
+    /// Maximum number of friends reached (1000).
+    MaximumFriends = 30002,
+    /// Maximum number of pins reached for the channel (50).
+    MaximumPins = 30003,
+    /// Maximum number of recipients reached (10).
+    MaximumRecipients = 30004,
+    /// Maximum number of guild roles reached (250).
+    MaximumRoles = 30005,
+    /// Maximum number of webhooks reached (15).
+    MaximumWebhooks = 30007,
+    /// Maximum number of emojis reached.
+    MaximumEmojis = 30008,
+    /// Maximum number of reactions reached (20).
+    MaximumReactions = 30010,
+    /// Maximum number of guild channels reached (500).
+    MaximumChannels = 30013,
+    /// Maximum number of attachments in a message reached (10).
+    MaximumAttachments = 30015,
+    /// Maximum number of invites reached (1000).
+    MaximumInvites = 30016,
+    /// Maximum number of animated emojis reached.
+    MaximumAnimatedEmojis = 30018,
+    /// Maximum number of server members reached.
+    MaximumMembers = 30019,
+    /// Maximum number of server categories has been reached (5).
+    MaximumCategories = 30030,
+    /// Guild already has a template.
+    GuildAlreadyHasTemplate = 30031,
+    /// Maximum number of application commands reached.
+    MaximumApplicationCommands = 30032,
+    /// Maximum number of thread participants has been reached (1000).
+    MaximumThreadParticipants = 30033,
+    /// Maximum number of daily application command creates has been reached
+    /// (200).
+    MaximumDailyApplicationCommandCreates = 30034,
+    /// Maximum number of bans for non-guild members have been exceeded.
+    MaximumNonMemberBans = 30035,
+    /// Maximum number of bans fetches has been reached.
+    MaximumBanFetches = 30037,
+    /// Maximum number of uncompleted guild scheduled events reached (100).
+    MaximumUncompletedScheduledEvents = 30038,
+    /// Maximum number of stickers reached.
+    MaximumStickers = 30039,
+    /// Maximum number of prune requests has been reached.
+    MaximumPruneRequests = 30040,
+    /// Maximum number of guild widget settings updates has been reached.
+    MaximumWidgetSettingsUpdates = 30042,
+    /// Maximum number of edits to messages older than 1 hour reached.
+    MaximumOldMessageEdits = 30046,
+    /// Maximum number of pinned threads in a forum channel has been reached.
+    MaximumPinnedThreads = 30047,
+    /// Maximum number of tags in a forum channel has been reached.
+    MaximumForumTags = 30048,
+    /// Bitrate is too high for channel of this type.
+    BitrateTooHigh = 30052,
+    /// Unauthorized. Provide a valid token and try again.
+    Unauthorized = 40001,
+    /// You need to verify your account in order to perform this action.
+    AccountVerificationRequired = 40002,
+    /// You are opening direct messages too fast.
+    OpeningDirectMessagesTooFast = 40003,
+    /// Send messages has been temporarily disabled.
+    SendMessagesDisabled = 40004,
+    /// Request entity too large.
+    RequestEntityTooLarge = 40005,
+    /// This feature has been temporarily disabled server-side.
+    FeatureDisabled = 40006,
+    /// The user is banned from this guild.
+    UserBannedFromGuild = 40007,
+    /// Connection has been revoked.
+    ConnectionRevoked = 40012,
+    /// Target user is not connected to voice.
+    TargetUserNotInVoice = 40032,
+    /// This message has already been crossposted.
+    MessageAlreadyCrossposted = 40033,
+    /// An application command with that name already exists.
+    ApplicationCommandNameExists = 40041,
+    /// Application interaction failed to send.
+    InteractionFailedToSend = 40043,
+    /// Cannot send a message in a forum channel.
+    CannotSendInForumChannel = 40058,
+    /// Interaction has already been acknowledged.
+    InteractionAlreadyAcknowledged = 40060,
+    /// Tag names must be unique.
+    TagNamesMustBeUnique = 40061,
+    /// Missing access.
+    MissingAccess = 50001,
+    /// Invalid account type.
+    InvalidAccountType = 50002,
+    /// Cannot execute action on a DM channel.
+    CannotExecuteOnDmChannel = 50003,
+    /// Guild widget disabled.
+    GuildWidgetDisabled = 50004,
+    /// Cannot edit a message authored by another user.
+    CannotEditOtherUsersMessage = 50005,
+    /// Cannot send an empty message.
+    CannotSendEmptyMessage = 50006,
+    /// Cannot send messages to this user.
+    CannotSendMessagesToUser = 50007,
+    /// Cannot send messages in a non-text channel.
+    CannotSendMessagesInNonTextChannel = 50008,
+    /// Channel verification level is too high for you to gain access.
+    ChannelVerificationLevelTooHigh = 50009,
+    /// OAuth2 application does not have a bot.
+    OAuth2ApplicationWithoutBot = 50010,
+    /// OAuth2 application limit reached.
+    OAuth2ApplicationLimitReached = 50011,
+    /// Invalid OAuth2 state.
+    InvalidOAuth2State = 50012,
+    /// You lack permissions to perform that action.
+    MissingPermissions = 50013,
+    /// Invalid authentication token provided.
+    InvalidAuthenticationToken = 50014,
+    /// Note was too long.
+    NoteTooLong = 50015,
+    /// Provided too few or too many messages to delete. Must provide at least 2
+    /// and fewer than 100 messages to delete.
+    InvalidBulkDeleteCount = 50016,
+    /// Invalid MFA level.
+    InvalidMfaLevel = 50017,
+    /// A message can only be pinned to the channel it was sent in.
+    PinInOtherChannel = 50019,
+    /// Invite code was either invalid or taken.
+    InvalidInviteCode = 50020,
+    /// Cannot execute action on a system message.
+    CannotExecuteOnSystemMessage = 50021,
+    /// Cannot execute action on this channel type.
+    CannotExecuteOnChannelType = 50024,
+    /// Invalid OAuth2 access token provided.
+    InvalidOAuth2AccessToken = 50025,
+    /// Missing required OAuth2 scope.
+    MissingOAuth2Scope = 50026,
+    /// Invalid webhook token provided.
+    InvalidWebhookToken = 50027,
+    /// Invalid role.
+    InvalidRole = 50028,
+    /// Invalid recipient(s).
+    InvalidRecipients = 50033,
+    /// A message provided was too old to bulk delete.
+    MessageTooOldToBulkDelete = 50034,
+    /// Invalid form body, or invalid `Content-Type` provided.
+    InvalidFormBody = 50035,
+    /// An invite was accepted to a guild the application's bot is not in.
+    InviteAcceptedToGuildWithoutBot = 50036,
+    /// Invalid activity action.
+    InvalidActivityAction = 50041,
+    /// Invalid API version provided.
+    InvalidApiVersion = 50045,
+    /// File uploaded exceeds the maximum size.
+    FileTooLarge = 50046,
+    /// Cannot self-redeem this gift.
+    CannotSelfRedeemGift = 50054,
+    /// Invalid guild.
+    InvalidGuild = 50055,
+    /// Invalid message type.
+    InvalidMessageType = 50068,
+    /// Payment source required to redeem gift.
+    PaymentSourceRequired = 50070,
+    /// Cannot delete a channel required for Community guilds.
+    CannotDeleteCommunityChannel = 50074,
+    /// Cannot edit stickers within a message.
+    CannotEditMessageStickers = 50080,
+    /// Invalid sticker sent.
+    InvalidSticker = 50081,
+    /// Tried to perform an operation on an archived thread, such as editing a
+    /// message or adding a user to the thread.
+    ThreadArchived = 50083,
+    /// Invalid thread notification settings.
+    InvalidThreadNotificationSettings = 50084,
+    /// `before` value is earlier than the thread creation date.
+    BeforeEarlierThanThreadCreation = 50085,
+    /// Community server channels must be text channels.
+    CommunityChannelsMustBeText = 50086,
+    /// This server is not available in your location.
+    ServerNotAvailableInLocation = 50095,
+    /// This server needs monetization enabled in order to perform this action.
+    MonetizationRequired = 50097,
+    /// This server needs more boosts to perform this action.
+    MoreBoostsRequired = 50101,
+    /// The request body contains invalid JSON.
+    InvalidJson = 50109,
+    /// Ownership cannot be transferred to a bot user.
+    CannotTransferOwnershipToBot = 50132,
+    /// Failed to resize asset below the maximum size.
+    AssetResizeFailed = 50138,
+    /// Uploaded file not found.
+    UploadedFileNotFound = 50146,
+    /// Two factor is required for this operation.
+    TwoFactorRequired = 60003,
+    /// No users with DiscordTag exist.
+    NoUsersWithTag = 80004,
+    /// Reaction was blocked.
+    ReactionBlocked = 90001,
+    /// API resource is currently overloaded.
+    ResourceOverloaded = 130000,
+    /// The stage is already open.
+    StageAlreadyOpen = 150006,
+    /// Cannot reply without permission to read message history.
+    CannotReplyWithoutReadMessageHistory = 160002,
+    /// A thread has already been created for this message.
+    ThreadAlreadyCreated = 160004,
+    /// Thread is locked.
+    ThreadLocked = 160005,
+    /// Maximum number of active threads reached.
+    MaximumActiveThreads = 160006,
+    /// Maximum number of active announcement threads reached.
+    MaximumActiveAnnouncementThreads = 160007,
+    /// Invalid JSON for uploaded Lottie file.
+    InvalidLottieJson = 170001,
+    /// Uploaded Lotties cannot contain rasterized images such as PNG or JPEG.
+    LottieContainsRasterImages = 170002,
+    /// Sticker maximum framerate exceeded.
+    StickerFramerateExceeded = 170003,
+    /// Sticker frame count exceeds maximum of 1000 frames.
+    StickerFrameCountExceeded = 170004,
+    /// Lottie animation maximum dimensions exceeded.
+    LottieDimensionsExceeded = 170005,
+    /// Sticker frame rate is either too small or too large.
+    StickerFrameRateInvalid = 170006,
+    /// Sticker animation duration exceeds maximum of 5 seconds.
+    StickerDurationExceeded = 170007,
+    /// Cannot update a finished event.
+    CannotUpdateFinishedEvent = 180000,
+    /// Failed to create stage needed for stage event.
+    FailedToCreateStage = 180002,
+    /// Message was blocked by automatic moderation.
+    BlockedByAutomod = 200000,
+    /// Title was blocked by automatic moderation.
+    TitleBlockedByAutomod = 200001,
+    /// Webhooks can only create threads in forum channels.
+    WebhooksCanOnlyCreateThreadsInForums = 220003,
+}
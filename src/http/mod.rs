@@ -14,7 +14,8 @@
 //! The former require a [`Client`] to have logged in, while the latter may be
 //! made regardless of any other usage of the library.
 //!
-//! If a request spuriously fails, it will be retried once.
+//! Requests that fail for transient reasons, such as a server error, can be
+//! retried automatically by configuring a [`RetryPolicy`].
 //!
 //! Note that you may want to perform requests through a [model]s'
 //! instance methods where possible, as they each offer different
@@ -25,9 +26,13 @@
 
 pub mod client;
 pub mod error;
+mod error_code;
 pub mod multipart;
+mod ratelimit_backend;
+mod ratelimit_coordinator;
 pub mod ratelimiting;
 pub mod request;
+mod retry;
 pub mod routing;
 pub mod typing;
 mod utils;
@@ -40,6 +45,7 @@ pub use reqwest::StatusCode;
 pub use self::client::*;
 pub use self::error::Error as HttpError;
 use self::request::Request;
+pub use self::retry::RetryPolicy;
 pub use self::typing::*;
 #[cfg(feature = "cache")]
 use crate::cache::Cache;
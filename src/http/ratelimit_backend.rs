@@ -0,0 +1,749 @@
+use std::collections::HashMap;
+use std::mem::{discriminant, Discriminant};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use async_trait::async_trait;
+use reqwest::header::HeaderMap;
+use reqwest::Response;
+use tokio::sync::{Mutex, MutexGuard, RwLock};
+use tokio::time::{sleep, sleep_until, Duration, Instant};
+use tracing::debug;
+
+use super::ratelimiting::{
+    parse_header,
+    retry_after,
+    GlobalRatelimit,
+    PriorityQueue,
+    Ratelimit,
+    RatelimitInfo,
+};
+use super::request::RequestPriority;
+use super::routing::{Route, RouteInfo};
+use super::LightMethod;
+use crate::internal::prelude::*;
+
+/// The callback the [`Ratelimiter`] reports ratelimits to.
+///
+/// [`Ratelimiter`]: super::ratelimiting::Ratelimiter
+pub type RatelimitCallback = dyn Fn(RatelimitInfo) + Send + Sync;
+
+/// Storage for the ratelimits of requests to the Discord API.
+///
+/// The [`Ratelimiter`] acquires a ticket from the backend before sending each
+/// request, and records the ratelimit headers of each response. Processes
+/// sharing a backend - such as a [`CoordinatedRatelimitBackend`] - share their
+/// ratelimits, instead of each assuming that it has the full budget.
+///
+/// [`Ratelimiter`]: super::ratelimiting::Ratelimiter
+/// [`CoordinatedRatelimitBackend`]: super::ratelimiting::CoordinatedRatelimitBackend
+#[async_trait]
+pub trait RatelimitBackend: Send + Sync {
+    /// Waits until a request to the route may be sent, taking a ticket from
+    /// the ratelimit of its bucket.
+    ///
+    /// Requests of a higher priority should be let through first where the
+    /// backend is able to order them.
+    ///
+    /// The callback must be called before waiting on a ratelimit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend could not be reached.
+    async fn acquire(
+        &self,
+        route: &RouteInfo<'_>,
+        priority: RequestPriority,
+        ratelimit_callback: &RatelimitCallback,
+    ) -> Result<()>;
+
+    /// Records the ratelimit headers of the response to a request to the
+    /// route.
+    ///
+    /// If the request was ratelimited, this waits for its `retry-after` and
+    /// returns `true` for the request to be retried.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend could not be reached, or the headers
+    /// are invalid.
+    async fn record_response(
+        &self,
+        route: &RouteInfo<'_>,
+        response: &Response,
+        ratelimit_callback: &RatelimitCallback,
+    ) -> Result<bool>;
+
+    /// Waits until the global ratelimit is over, if it was hit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend could not be reached.
+    async fn wait_global(&self) -> Result<()>;
+
+    /// Records that the global ratelimit was hit, blocking every request for
+    /// the given duration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend could not be reached.
+    async fn lock_global(&self, duration: Duration) -> Result<()>;
+
+    /// Takes a token from the global ratelimit, which allows `limit` requests
+    /// per second, returning how long to wait for it if none is available.
+    ///
+    /// The token is reserved, so the caller must wait out the returned
+    /// duration and not call this again for the same request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend could not be reached.
+    async fn take_global(&self, limit: u32) -> Result<Option<Duration>>;
+
+    /// Takes a snapshot of the ratelimit of every bucket tracked.
+    ///
+    /// By default this returns no buckets, for backends that cannot inspect
+    /// their ratelimits.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend could not be reached.
+    async fn snapshot(&self) -> Result<Vec<BucketSnapshot>> {
+        Ok(Vec::new())
+    }
+}
+
+/// The state of the ratelimit of a bucket at the time of a
+/// [`RatelimitBackend::snapshot`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct BucketSnapshot {
+    /// The hash of the bucket as sent by Discord, if known.
+    pub bucket: Option<String>,
+    /// The routes sharing the ratelimit.
+    pub routes: Vec<Route>,
+    /// The total number of requests that can be made in a period of time.
+    pub limit: i64,
+    /// The number of requests remaining in the period of time.
+    pub remaining: i64,
+    /// The absolute time when the interval resets.
+    pub reset: Option<SystemTime>,
+    /// The total time when the interval resets.
+    pub reset_after: Option<Duration>,
+    /// The number of requests waiting to be sent.
+    pub waiters: usize,
+}
+
+/// The [`Ratelimit`] of a bucket of an [`InMemoryRatelimitBackend`], along
+/// with the queue of requests waiting on it.
+///
+/// Waiting requests are let through in order of their [`RequestPriority`],
+/// then in the order they started waiting.
+#[derive(Debug, Default)]
+pub struct RatelimitBucket {
+    ratelimit: Mutex<Ratelimit>,
+    // Held by the request whose turn it is, while it waits out the ratelimit.
+    queue: PriorityQueue,
+    waiters: AtomicUsize,
+}
+
+impl RatelimitBucket {
+    /// The ratelimit of the bucket.
+    ///
+    /// The ratelimit is only locked briefly by requests, never while waiting.
+    pub async fn ratelimit(&self) -> MutexGuard<'_, Ratelimit> {
+        self.ratelimit.lock().await
+    }
+
+    /// The number of requests waiting to be sent.
+    #[must_use]
+    pub fn waiters(&self) -> usize {
+        self.waiters.load(Ordering::Relaxed)
+    }
+
+    // Waits for the bucket's turn and ticket, counted as a waiter until done
+    // or cancelled.
+    async fn acquire(
+        &self,
+        route: &RouteInfo<'_>,
+        priority: RequestPriority,
+        ratelimit_callback: &RatelimitCallback,
+    ) {
+        self.waiters.fetch_add(1, Ordering::Relaxed);
+        let _waiter = Waiter(&self.waiters);
+
+        let _turn = self.queue.turn(priority).await;
+
+        let delay = {
+            let mut ratelimit = self.ratelimit.lock().await;
+            ratelimit.take_ticket().map(|delay| (delay, ratelimit.limit()))
+        };
+
+        if let Some((delay, limit)) = delay {
+            let (method, route, path) = route.deconstruct();
+
+            debug!("Pre-emptive ratelimit on route {:?} for {}ms", route, delay.as_millis());
+            ratelimit_callback(RatelimitInfo {
+                timeout: delay,
+                limit,
+                method,
+                path: path.to_string(),
+                global: false,
+            });
+
+            sleep(delay).await;
+        }
+    }
+}
+
+/// Counts a request as waiting on a bucket until dropped.
+struct Waiter<'a>(&'a AtomicUsize);
+
+impl Drop for Waiter<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// A [`RatelimitBackend`] keeping ratelimits in memory.
+///
+/// This is the default backend. Ratelimits are first tracked per [`Route`], or
+/// for requests without one, per template of their path and major parameter.
+/// Responses tell which bucket a route belongs to, after which its
+/// [`RatelimitBucket`] is shared by every route of the same bucket and major
+/// parameter.
+#[derive(Debug, Default)]
+pub struct InMemoryRatelimitBackend {
+    global: Mutex<Option<Instant>>,
+    global_tokens: Mutex<GlobalRatelimit>,
+    // When futures is implemented, make tasks clear out their respective entry
+    // when the 'reset' passes.
+    routes: Arc<RwLock<HashMap<Route, Arc<RatelimitBucket>>>>,
+    // The ratelimits of routes without a `Route`, by the template of their
+    // path and their major parameter.
+    unknown_routes: RwLock<HashMap<UnknownRoute, Arc<RatelimitBucket>>>,
+    // The bucket hashes learned from responses, by method and kind of route.
+    bucket_hashes: RwLock<HashMap<RouteKind, String>>,
+    buckets: RwLock<HashMap<BucketKey, Arc<RatelimitBucket>>>,
+}
+
+impl InMemoryRatelimitBackend {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The routes mutex is a HashMap of each [`Route`] and their respective
+    /// ratelimit information.
+    ///
+    /// Routes of the same bucket and major parameter share their
+    /// [`RatelimitBucket`] once the bucket is known.
+    ///
+    /// See the documentation for [`Ratelimit`] for more information on how the
+    /// library handles ratelimiting.
+    ///
+    /// # Examples
+    ///
+    /// View the `reset` time of the route for `ChannelsId(7)`:
+    ///
+    /// ```rust,no_run
+    /// use std::sync::Arc;
+    ///
+    /// use serenity::http::ratelimiting::{InMemoryRatelimitBackend, Route};
+    /// use serenity::http::HttpBuilder;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let backend = Arc::new(InMemoryRatelimitBackend::new());
+    /// let http = HttpBuilder::new("token").ratelimit_backend_arc(Arc::clone(&backend)).build();
+    ///
+    /// let routes = backend.routes();
+    /// let reader = routes.read().await;
+    ///
+    /// if let Some(route) = reader.get(&Route::ChannelsId(7)) {
+    ///     if let Some(reset) = route.ratelimit().await.reset() {
+    ///         println!("Reset time at: {:?}", reset);
+    ///     }
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn routes(&self) -> Arc<RwLock<HashMap<Route, Arc<RatelimitBucket>>>> {
+        Arc::clone(&self.routes)
+    }
+
+    // The ratelimit of a route, shared with the rest of its bucket if the
+    // bucket is known.
+    async fn bucket(&self, method: LightMethod, route: &RouteKey) -> Arc<RatelimitBucket> {
+        // Only take the write locks for routes not yet tracked, so that
+        // requests to known routes don't contend.
+        if let Some(bucket) = self.tracked(route).await {
+            return bucket;
+        }
+
+        let hash = self.bucket_hashes.read().await.get(&route.kind(method)).cloned();
+
+        let hash = match hash {
+            Some(hash) => hash,
+            None => return self.track(route, None).await,
+        };
+
+        let key = BucketKey {
+            hash,
+            major_parameter: route.major_parameter(),
+        };
+        let bucket = Arc::clone(self.buckets.write().await.entry(key).or_default());
+
+        self.track(route, Some(bucket)).await
+    }
+
+    // The ratelimit tracked for a route itself.
+    async fn tracked(&self, route: &RouteKey) -> Option<Arc<RatelimitBucket>> {
+        match route {
+            RouteKey::Known(route) => self.routes.read().await.get(route).cloned(),
+            RouteKey::Unknown(route) => self.unknown_routes.read().await.get(route).cloned(),
+        }
+    }
+
+    // Tracks the given ratelimit for a route, or a new one if none is given
+    // and the route is not yet tracked, returning the route's ratelimit.
+    async fn track(
+        &self,
+        route: &RouteKey,
+        bucket: Option<Arc<RatelimitBucket>>,
+    ) -> Arc<RatelimitBucket> {
+        match (route, bucket) {
+            (RouteKey::Known(route), Some(bucket)) => {
+                self.routes.write().await.insert(*route, Arc::clone(&bucket));
+
+                bucket
+            },
+            (RouteKey::Known(route), None) => {
+                Arc::clone(self.routes.write().await.entry(*route).or_default())
+            },
+            (RouteKey::Unknown(route), Some(bucket)) => {
+                self.unknown_routes.write().await.insert(route.clone(), Arc::clone(&bucket));
+
+                bucket
+            },
+            (RouteKey::Unknown(route), None) => {
+                Arc::clone(self.unknown_routes.write().await.entry(route.clone()).or_default())
+            },
+        }
+    }
+
+    // Records the bucket a route belongs to from the `x-ratelimit-bucket`
+    // header, returning the ratelimit to update with the response.
+    //
+    // If the bucket is not yet tracked, the route's current ratelimit becomes
+    // the bucket's.
+    async fn learn_bucket(
+        &self,
+        method: LightMethod,
+        route: &RouteKey,
+        headers: &HeaderMap,
+        bucket: Arc<RatelimitBucket>,
+    ) -> Result<Arc<RatelimitBucket>> {
+        let hash = match parse_header::<String>(headers, "x-ratelimit-bucket")? {
+            Some(hash) => hash,
+            None => return Ok(bucket),
+        };
+
+        // Responses mostly confirm what is already known, so the mappings are
+        // checked under read locks and only written when they change.
+        let kind = route.kind(method);
+        let known = self.bucket_hashes.read().await.get(&kind) == Some(&hash);
+        if !known {
+            debug!("Route {:?} {:?} is in bucket {}", method, route, hash);
+            self.bucket_hashes.write().await.insert(kind, hash.clone());
+        }
+
+        let key = BucketKey {
+            hash,
+            major_parameter: route.major_parameter(),
+        };
+        let shared = self.buckets.read().await.get(&key).cloned();
+        let shared = match shared {
+            Some(shared) => shared,
+            None => Arc::clone(
+                self.buckets.write().await.entry(key).or_insert_with(|| Arc::clone(&bucket)),
+            ),
+        };
+
+        if Arc::ptr_eq(&shared, &bucket) {
+            return Ok(shared);
+        }
+
+        Ok(self.track(route, Some(shared)).await)
+    }
+}
+
+#[async_trait]
+impl RatelimitBackend for InMemoryRatelimitBackend {
+    async fn acquire(
+        &self,
+        route: &RouteInfo<'_>,
+        priority: RequestPriority,
+        ratelimit_callback: &RatelimitCallback,
+    ) -> Result<()> {
+        let (method, route_key, path) = route.deconstruct();
+        let bucket = self.bucket(method, &RouteKey::new(route_key, &path)).await;
+
+        bucket.acquire(route, priority, ratelimit_callback).await;
+
+        Ok(())
+    }
+
+    async fn record_response(
+        &self,
+        route: &RouteInfo<'_>,
+        response: &Response,
+        ratelimit_callback: &RatelimitCallback,
+    ) -> Result<bool> {
+        let (method, route_key, path) = route.deconstruct();
+        let route_key = RouteKey::new(route_key, &path);
+        let bucket = self.bucket(method, &route_key).await;
+        let bucket = self.learn_bucket(method, &route_key, response.headers(), bucket).await?;
+
+        let limit = {
+            let mut ratelimit = bucket.ratelimit.lock().await;
+            ratelimit.update(response.headers())?;
+            ratelimit.limit()
+        };
+
+        retry_after(response.status(), response.headers(), route, limit, ratelimit_callback).await
+    }
+
+    async fn wait_global(&self) -> Result<()> {
+        let until = *self.global.lock().await;
+
+        if let Some(until) = until {
+            sleep_until(until).await;
+        }
+
+        Ok(())
+    }
+
+    async fn lock_global(&self, duration: Duration) -> Result<()> {
+        let until = Instant::now() + duration;
+        let mut global = self.global.lock().await;
+
+        *global = Some(global.map_or(until, |current| current.max(until)));
+
+        Ok(())
+    }
+
+    async fn take_global(&self, limit: u32) -> Result<Option<Duration>> {
+        Ok(self.global_tokens.lock().await.take(limit, Instant::now()))
+    }
+
+    async fn snapshot(&self) -> Result<Vec<BucketSnapshot>> {
+        let hashes = self
+            .buckets
+            .read()
+            .await
+            .iter()
+            .map(|(key, bucket)| (Arc::as_ptr(bucket) as usize, key.hash.clone()))
+            .collect::<HashMap<_, _>>();
+
+        // Group the routes by the bucket they share.
+        let mut buckets = HashMap::<_, (Arc<RatelimitBucket>, Vec<Route>)>::new();
+        let mut group = |route, bucket: &Arc<RatelimitBucket>| {
+            buckets
+                .entry(Arc::as_ptr(bucket) as usize)
+                .or_insert_with(|| (Arc::clone(bucket), Vec::new()))
+                .1
+                .push(route);
+        };
+
+        for (route, bucket) in self.routes.read().await.iter() {
+            group(*route, bucket);
+        }
+        for bucket in self.unknown_routes.read().await.values() {
+            group(Route::None, bucket);
+        }
+
+        let mut snapshots = Vec::with_capacity(buckets.len());
+        for (id, (bucket, routes)) in buckets {
+            let ratelimit = bucket.ratelimit.lock().await;
+
+            snapshots.push(BucketSnapshot {
+                bucket: hashes.get(&id).cloned(),
+                routes,
+                limit: ratelimit.limit(),
+                remaining: ratelimit.remaining(),
+                reset: ratelimit.reset(),
+                reset_after: ratelimit.reset_after(),
+                waiters: bucket.waiters(),
+            });
+        }
+
+        Ok(snapshots)
+    }
+}
+
+/// A route regardless of its major parameter.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum RouteKind {
+    Known(LightMethod, Discriminant<Route>),
+    Unknown(LightMethod, String),
+}
+
+/// A route without a [`Route`], by the template of its path.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct UnknownRoute {
+    template: String,
+    major_parameter: Option<u64>,
+}
+
+/// The key a route's own ratelimit is tracked by, until its bucket is known.
+#[derive(Clone, Debug)]
+enum RouteKey {
+    Known(Route),
+    Unknown(UnknownRoute),
+}
+
+impl RouteKey {
+    fn new(route: Route, path: &str) -> Self {
+        if route == Route::None {
+            let (template, major_parameter) = path_template(path);
+
+            Self::Unknown(UnknownRoute {
+                template,
+                major_parameter,
+            })
+        } else {
+            Self::Known(route)
+        }
+    }
+
+    fn kind(&self, method: LightMethod) -> RouteKind {
+        match self {
+            Self::Known(route) => RouteKind::Known(method, discriminant(route)),
+            Self::Unknown(route) => RouteKind::Unknown(method, route.template.clone()),
+        }
+    }
+
+    fn major_parameter(&self) -> Option<u64> {
+        match self {
+            Self::Known(route) => route.major_parameter(),
+            Self::Unknown(route) => route.major_parameter,
+        }
+    }
+}
+
+/// The name of a route regardless of its major parameter, along with the
+/// major parameter, e.g. `Get ChannelsIdMessages` and the ID of the channel.
+///
+/// Routes without a [`Route`] are named by the template of their path instead,
+/// e.g. `Get /oauth2/applications/@me`.
+pub(crate) fn route_name(route: &RouteInfo<'_>) -> (String, Option<u64>) {
+    let (method, route, path) = route.deconstruct();
+
+    if route == Route::None {
+        let (template, major_parameter) = path_template(&path);
+
+        return (format!("{:?} {}", method, template), major_parameter);
+    }
+
+    let name = format!("{:?}", route);
+    let kind = name.split('(').next().unwrap_or_default();
+
+    (format!("{:?} {}", method, kind), route.major_parameter())
+}
+
+/// The template of a path along with its major parameter, with IDs and tokens
+/// replaced, e.g. `/webhooks/:id/:token` for `/webhooks/1/abc`.
+pub(crate) fn path_template(path: &str) -> (String, Option<u64>) {
+    let path = path.split('?').next().unwrap_or_default();
+    let segments = path.split('/').collect::<Vec<_>>();
+    let is_id = |i: usize| segments[i].parse::<u64>().is_ok();
+
+    let major_parameter = match segments.get(1..3) {
+        Some(&["channels" | "guilds" | "webhooks", id]) => id.parse().ok(),
+        _ => None,
+    };
+
+    let template = (0..segments.len())
+        .map(|i| {
+            if is_id(i) {
+                ":id"
+            } else if i >= 2
+                && is_id(i - 1)
+                && matches!(segments[i - 2], "webhooks" | "interactions")
+            {
+                ":token"
+            } else {
+                segments[i]
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/");
+
+    (template, major_parameter)
+}
+
+/// The key of a ratelimit bucket shared between routes.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct BucketKey {
+    /// The hash of the bucket, as sent in the `x-ratelimit-bucket` header.
+    hash: String,
+    /// The major parameter of the routes sharing the bucket.
+    major_parameter: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use tokio::sync::Mutex;
+    use tokio::time::{self, sleep, Duration};
+
+    use super::{path_template, route_name, InMemoryRatelimitBackend, RatelimitBackend, RouteKey};
+    use crate::http::request::RequestPriority;
+    use crate::http::routing::{Route, RouteInfo};
+    use crate::http::LightMethod;
+    use crate::internal::prelude::*;
+
+    #[tokio::test]
+    async fn test_learn_bucket() -> Result<()> {
+        let backend = InMemoryRatelimitBackend::new();
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-bucket", HeaderValue::from_static("abcd1234"));
+
+        let messages = Route::ChannelsIdMessages(1);
+        let pins = Route::ChannelsIdPins(1);
+
+        // Routes are tracked separately until their bucket is known.
+        let bucket = backend.bucket(LightMethod::Get, &RouteKey::Known(messages)).await;
+        assert!(!Arc::ptr_eq(
+            &bucket,
+            &backend.bucket(LightMethod::Get, &RouteKey::Known(pins)).await
+        ));
+
+        let learned = backend
+            .learn_bucket(LightMethod::Get, &RouteKey::Known(messages), &headers, bucket.clone())
+            .await?;
+        assert!(Arc::ptr_eq(&learned, &bucket));
+
+        let pins_bucket = backend.bucket(LightMethod::Get, &RouteKey::Known(pins)).await;
+        let learned = backend
+            .learn_bucket(LightMethod::Get, &RouteKey::Known(pins), &headers, pins_bucket)
+            .await?;
+        assert!(Arc::ptr_eq(&learned, &bucket));
+        assert!(Arc::ptr_eq(
+            &backend.bucket(LightMethod::Get, &RouteKey::Known(pins)).await,
+            &bucket
+        ));
+
+        // Once known, the bucket of a route applies to other major parameters.
+        let other =
+            backend.bucket(LightMethod::Get, &RouteKey::Known(Route::ChannelsIdMessages(2))).await;
+        assert!(!Arc::ptr_eq(&other, &bucket));
+        assert!(Arc::ptr_eq(
+            &other,
+            &backend.bucket(LightMethod::Get, &RouteKey::Known(Route::ChannelsIdMessages(2))).await
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_path_template() {
+        assert_eq!(
+            path_template("/oauth2/applications/@me"),
+            ("/oauth2/applications/@me".into(), None)
+        );
+        assert_eq!(
+            path_template("/channels/1/messages/2?limit=5"),
+            ("/channels/:id/messages/:id".into(), Some(1))
+        );
+        assert_eq!(
+            path_template("/webhooks/1/abc/messages/2"),
+            ("/webhooks/:id/:token/messages/:id".into(), Some(1))
+        );
+        assert_eq!(
+            path_template("/interactions/1/abc/callback"),
+            ("/interactions/:id/:token/callback".into(), None)
+        );
+
+        let route = RouteInfo::GetCurrentApplicationInfo;
+        assert_eq!(route_name(&route), ("Get /oauth2/applications/@me".into(), None));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_route() -> Result<()> {
+        let backend = InMemoryRatelimitBackend::new();
+        let route = RouteInfo::GetCurrentApplicationInfo;
+
+        // Routes without a `Route` are ratelimited by their own bucket.
+        backend.acquire(&route, RequestPriority::Normal, &|_| {}).await?;
+
+        let snapshot = backend.snapshot().await?;
+        assert_eq!(snapshot.len(), 1);
+        assert_eq!(snapshot[0].routes, vec![Route::None]);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-bucket", HeaderValue::from_static("abcd1234"));
+
+        let key = RouteKey::new(Route::None, "/oauth2/applications/@me");
+        let bucket = backend.bucket(LightMethod::Get, &key).await;
+        let learned =
+            backend.learn_bucket(LightMethod::Get, &key, &headers, bucket.clone()).await?;
+        assert!(Arc::ptr_eq(&learned, &bucket));
+        assert_eq!(backend.snapshot().await?[0].bucket.as_deref(), Some("abcd1234"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bucket_queue() -> Result<()> {
+        time::pause();
+
+        let backend = Arc::new(InMemoryRatelimitBackend::new());
+        let route = || RouteInfo::GetChannel {
+            channel_id: 1,
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-limit", HeaderValue::from_static("1"));
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+        headers.insert("x-ratelimit-reset-after", HeaderValue::from_static("0.2"));
+
+        let bucket = backend.bucket(LightMethod::Get, &RouteKey::Known(Route::ChannelsId(1))).await;
+        bucket.ratelimit().await.update(&headers)?;
+
+        // Requests waiting on the exhausted bucket are let through in order.
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let mut tasks = Vec::new();
+        for i in 0..3 {
+            let backend = Arc::clone(&backend);
+            let order = Arc::clone(&order);
+
+            tasks.push(tokio::spawn(async move {
+                backend.acquire(&route(), RequestPriority::Normal, &|_| {}).await.unwrap();
+                order.lock().await.push(i);
+            }));
+
+            sleep(Duration::from_millis(20)).await;
+        }
+
+        let snapshot = backend.snapshot().await?;
+        assert_eq!(snapshot.len(), 1);
+        assert_eq!(snapshot[0].routes, vec![Route::ChannelsId(1)]);
+        assert_eq!(snapshot[0].limit, 1);
+        assert_eq!(snapshot[0].remaining, 0);
+        assert_eq!(snapshot[0].waiters, 3);
+
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert_eq!(*order.lock().await, vec![0, 1, 2]);
+        assert_eq!(bucket.waiters(), 0);
+
+        Ok(())
+    }
+}
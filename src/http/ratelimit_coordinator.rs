@@ -0,0 +1,597 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+#[cfg(unix)]
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex as StdMutex, PoisonError};
+
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::Response;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+#[cfg(unix)]
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::Mutex;
+use tokio::time::{sleep, Duration, Instant};
+use tracing::{debug, warn};
+
+use super::ratelimit_backend::{route_name, RatelimitBackend, RatelimitCallback};
+use super::ratelimiting::{parse_header, retry_after, GlobalRatelimit, Ratelimit, RatelimitInfo};
+use super::request::RequestPriority;
+use super::routing::RouteInfo;
+use crate::internal::prelude::*;
+use crate::json::{from_str, to_string};
+
+/// The address of a [`RatelimitCoordinator`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum CoordinatorAddr {
+    Tcp(SocketAddr),
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+/// A request to the coordinator.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum CoordinatorRequest {
+    Acquire { route: String, major_parameter: Option<u64> },
+    Record { route: String, major_parameter: Option<u64>, headers: Vec<(String, String)> },
+    WaitGlobal,
+    LockGlobal { duration_ms: u64 },
+    TakeGlobal { limit: u32 },
+}
+
+/// The coordinator's response to a request.
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct CoordinatorResponse {
+    /// How long to wait before proceeding, in milliseconds.
+    wait_ms: u64,
+    /// The limit of the bucket of the route.
+    limit: i64,
+    /// Why the coordinator failed to handle the request, if it did.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// The ratelimits kept by a coordinator.
+#[derive(Debug, Default)]
+struct CoordinatorState {
+    global: Option<Instant>,
+    global_tokens: GlobalRatelimit,
+    // The bucket hashes learned from responses, by route name.
+    bucket_hashes: HashMap<String, String>,
+    // The ratelimits by route name - or bucket hash once known - and major
+    // parameter.
+    buckets: HashMap<(String, Option<u64>), Ratelimit>,
+}
+
+impl CoordinatorState {
+    fn bucket(&mut self, route: &str, major_parameter: Option<u64>) -> &mut Ratelimit {
+        let name = self.bucket_hashes.get(route).map_or(route, String::as_str).to_string();
+
+        self.buckets.entry((name, major_parameter)).or_default()
+    }
+
+    fn handle(&mut self, request: CoordinatorRequest) -> Result<CoordinatorResponse> {
+        Ok(match request {
+            CoordinatorRequest::Acquire {
+                route,
+                major_parameter,
+            } => {
+                let ratelimit = self.bucket(&route, major_parameter);
+
+                CoordinatorResponse {
+                    wait_ms: ratelimit.take_ticket().map_or(0, ceil_millis),
+                    limit: ratelimit.limit(),
+                    ..CoordinatorResponse::default()
+                }
+            },
+            CoordinatorRequest::Record {
+                route,
+                major_parameter,
+                headers,
+            } => {
+                let headers = headers
+                    .iter()
+                    .filter_map(|(name, value)| {
+                        let name = HeaderName::from_bytes(name.as_bytes()).ok()?;
+
+                        Some((name, HeaderValue::from_str(value).ok()?))
+                    })
+                    .collect::<HeaderMap>();
+
+                if let Some(hash) = parse_header::<String>(&headers, "x-ratelimit-bucket")? {
+                    // The route's ratelimit so far becomes the bucket's, if the
+                    // bucket is not yet tracked.
+                    if let Some(ratelimit) = self.buckets.remove(&(route.clone(), major_parameter))
+                    {
+                        self.buckets.entry((hash.clone(), major_parameter)).or_insert(ratelimit);
+                    }
+
+                    self.bucket_hashes.insert(route.clone(), hash);
+                }
+
+                let ratelimit = self.bucket(&route, major_parameter);
+                ratelimit.update(&headers)?;
+
+                CoordinatorResponse {
+                    limit: ratelimit.limit(),
+                    ..CoordinatorResponse::default()
+                }
+            },
+            CoordinatorRequest::WaitGlobal => CoordinatorResponse {
+                wait_ms: self.global.map_or(0, |until| {
+                    ceil_millis(until.saturating_duration_since(Instant::now()))
+                }),
+                ..CoordinatorResponse::default()
+            },
+            CoordinatorRequest::LockGlobal {
+                duration_ms,
+            } => {
+                let until = Instant::now() + Duration::from_millis(duration_ms);
+                self.global = Some(self.global.map_or(until, |current| current.max(until)));
+
+                CoordinatorResponse::default()
+            },
+            CoordinatorRequest::TakeGlobal {
+                limit,
+            } => CoordinatorResponse {
+                wait_ms: self.global_tokens.take(limit, Instant::now()).map_or(0, ceil_millis),
+                limit: i64::from(limit),
+                ..CoordinatorResponse::default()
+            },
+        })
+    }
+}
+
+/// A service keeping the ratelimits of every [`CoordinatedRatelimitBackend`]
+/// connected to it, over TCP or a Unix socket, so that processes sharing it
+/// share their ratelimits.
+///
+/// The protocol is a line of JSON per request and response, with one request
+/// in flight per connection.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use serenity::http::ratelimiting::{
+///     CoordinatedRatelimitBackend,
+///     CoordinatorAddr,
+///     RatelimitCoordinator,
+/// };
+/// use serenity::http::HttpBuilder;
+/// use tokio::net::TcpListener;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// // In the coordinating process:
+/// let listener = TcpListener::bind("127.0.0.1:7777").await?;
+/// tokio::spawn(async move { RatelimitCoordinator::new().serve_tcp(listener).await });
+///
+/// // In each process performing requests:
+/// let backend = CoordinatedRatelimitBackend::new(CoordinatorAddr::Tcp("127.0.0.1:7777".parse()?));
+/// let http = HttpBuilder::new("token").ratelimit_backend(backend).build();
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct RatelimitCoordinator {
+    state: Arc<StdMutex<CoordinatorState>>,
+}
+
+impl RatelimitCoordinator {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serves the backends connecting to the listener, until accepting a
+    /// connection fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if accepting a connection failed.
+    pub async fn serve_tcp(&self, listener: TcpListener) -> Result<()> {
+        loop {
+            let (stream, addr) = listener.accept().await?;
+            debug!("[Ratelimit Coordinator] Accepted connection from {}", addr);
+
+            self.spawn_connection(stream);
+        }
+    }
+
+    /// Serves the backends connecting to the listener, until accepting a
+    /// connection fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if accepting a connection failed.
+    #[cfg(unix)]
+    pub async fn serve_unix(&self, listener: UnixListener) -> Result<()> {
+        loop {
+            let (stream, _) = listener.accept().await?;
+            debug!("[Ratelimit Coordinator] Accepted connection");
+
+            self.spawn_connection(stream);
+        }
+    }
+
+    fn spawn_connection<S>(&self, stream: S)
+    where
+        S: AsyncRead + AsyncWrite + Send + Unpin + 'static,
+    {
+        let state = Arc::clone(&self.state);
+
+        tokio::spawn(async move {
+            if let Err(why) = serve_connection(BufReader::new(stream), &state).await {
+                warn!("[Ratelimit Coordinator] Err serving connection: {:?}", why);
+            }
+        });
+    }
+}
+
+async fn serve_connection<S>(
+    mut stream: BufReader<S>,
+    state: &StdMutex<CoordinatorState>,
+) -> Result<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+
+        if stream.read_line(&mut line).await? == 0 {
+            return Ok(());
+        }
+
+        // A request that fails, such as one with invalid headers, is answered
+        // with the error instead of closing the connection.
+        let response = from_str(&mut line)
+            .and_then(|request| {
+                state.lock().unwrap_or_else(PoisonError::into_inner).handle(request)
+            })
+            .unwrap_or_else(|why| {
+                warn!("[Ratelimit Coordinator] Err handling request: {:?}", why);
+
+                CoordinatorResponse {
+                    error: Some(why.to_string()),
+                    ..CoordinatorResponse::default()
+                }
+            });
+
+        let mut payload = to_string(&response)?;
+        payload.push('\n');
+        stream.get_mut().write_all(payload.as_bytes()).await?;
+    }
+}
+
+/// A connection to a coordinator.
+trait Connection: AsyncRead + AsyncWrite + Send + Unpin {}
+
+impl<T: AsyncRead + AsyncWrite + Send + Unpin> Connection for T {}
+
+/// A [`RatelimitBackend`] keeping ratelimits in a [`RatelimitCoordinator`],
+/// sharing them with every other backend connected to it.
+///
+/// The backend connects when it is first used, and reconnects after a failed
+/// request.
+///
+/// The coordinator lets requests through in the order they ask for a ticket,
+/// regardless of their [`RequestPriority`].
+pub struct CoordinatedRatelimitBackend {
+    addr: CoordinatorAddr,
+    connection: Mutex<Option<BufReader<Box<dyn Connection>>>>,
+}
+
+impl std::fmt::Debug for CoordinatedRatelimitBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CoordinatedRatelimitBackend")
+            .field("addr", &self.addr)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CoordinatedRatelimitBackend {
+    #[must_use]
+    pub fn new(addr: CoordinatorAddr) -> Self {
+        Self {
+            addr,
+            connection: Mutex::new(None),
+        }
+    }
+
+    /// The address of the coordinator.
+    #[must_use]
+    pub fn addr(&self) -> &CoordinatorAddr {
+        &self.addr
+    }
+
+    async fn connect(&self) -> Result<BufReader<Box<dyn Connection>>> {
+        let stream: Box<dyn Connection> = match &self.addr {
+            CoordinatorAddr::Tcp(addr) => Box::new(TcpStream::connect(addr).await?),
+            #[cfg(unix)]
+            CoordinatorAddr::Unix(path) => Box::new(UnixStream::connect(path).await?),
+        };
+
+        Ok(BufReader::new(stream))
+    }
+
+    async fn request(&self, request: &CoordinatorRequest) -> Result<CoordinatorResponse> {
+        let mut connection = self.connection.lock().await;
+
+        // The connection is taken out while in use, so that it is reconnected
+        // on the next request if this fails or is cancelled midway, leaving
+        // it in an unknown state.
+        let mut stream = match connection.take() {
+            Some(stream) => stream,
+            None => self.connect().await?,
+        };
+
+        let response = exchange(&mut stream, request).await?;
+        *connection = Some(stream);
+
+        if let Some(why) = &response.error {
+            warn!("[Ratelimit Coordinator] Coordinator failed to handle request: {}", why);
+
+            return Err(Error::Other("The ratelimit coordinator failed to handle the request"));
+        }
+
+        Ok(response)
+    }
+}
+
+async fn exchange(
+    stream: &mut BufReader<Box<dyn Connection>>,
+    request: &CoordinatorRequest,
+) -> Result<CoordinatorResponse> {
+    let mut payload = to_string(request)?;
+    payload.push('\n');
+    stream.get_mut().write_all(payload.as_bytes()).await?;
+
+    let mut line = String::new();
+    if stream.read_line(&mut line).await? == 0 {
+        return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+    }
+
+    from_str(&mut line)
+}
+
+#[async_trait]
+impl RatelimitBackend for CoordinatedRatelimitBackend {
+    async fn acquire(
+        &self,
+        route: &RouteInfo<'_>,
+        _priority: RequestPriority,
+        ratelimit_callback: &RatelimitCallback,
+    ) -> Result<()> {
+        let (method, route_key, path) = route.deconstruct();
+        let (name, major_parameter) = route_name(route);
+        let request = CoordinatorRequest::Acquire {
+            route: name,
+            major_parameter,
+        };
+
+        loop {
+            let response = self.request(&request).await?;
+
+            if response.wait_ms == 0 {
+                return Ok(());
+            }
+
+            let delay = Duration::from_millis(response.wait_ms);
+
+            debug!("Pre-emptive ratelimit on route {:?} for {}ms", route_key, response.wait_ms);
+            ratelimit_callback(RatelimitInfo {
+                timeout: delay,
+                limit: response.limit,
+                method,
+                path: path.to_string(),
+                global: false,
+            });
+
+            sleep(delay).await;
+        }
+    }
+
+    async fn record_response(
+        &self,
+        route: &RouteInfo<'_>,
+        response: &Response,
+        ratelimit_callback: &RatelimitCallback,
+    ) -> Result<bool> {
+        let (name, major_parameter) = route_name(route);
+        let headers = response
+            .headers()
+            .iter()
+            .filter(|(name, _)| name.as_str().starts_with("x-ratelimit-"))
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+
+        let recorded = self
+            .request(&CoordinatorRequest::Record {
+                route: name,
+                major_parameter,
+                headers,
+            })
+            .await?;
+
+        retry_after(
+            response.status(),
+            response.headers(),
+            route,
+            recorded.limit,
+            ratelimit_callback,
+        )
+        .await
+    }
+
+    async fn wait_global(&self) -> Result<()> {
+        let response = self.request(&CoordinatorRequest::WaitGlobal).await?;
+
+        if response.wait_ms != 0 {
+            sleep(Duration::from_millis(response.wait_ms)).await;
+        }
+
+        Ok(())
+    }
+
+    async fn lock_global(&self, duration: Duration) -> Result<()> {
+        let duration_ms = ceil_millis(duration);
+        self.request(&CoordinatorRequest::LockGlobal {
+            duration_ms,
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn take_global(&self, limit: u32) -> Result<Option<Duration>> {
+        let response = self
+            .request(&CoordinatorRequest::TakeGlobal {
+                limit,
+            })
+            .await?;
+
+        Ok(if response.wait_ms == 0 { None } else { Some(Duration::from_millis(response.wait_ms)) })
+    }
+}
+
+/// Rounds a duration up to whole milliseconds, so that a short wait is not
+/// mistaken for none.
+fn ceil_millis(duration: Duration) -> u64 {
+    ((duration.as_micros() + 999) / 1000) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use reqwest::Response;
+    use tokio::net::TcpListener;
+    use tokio::time::{self, Duration};
+
+    use super::{
+        CoordinatedRatelimitBackend,
+        CoordinatorAddr,
+        CoordinatorRequest,
+        CoordinatorState,
+        RatelimitCoordinator,
+    };
+    use crate::http::ratelimiting::{RatelimitBackend, RatelimitInfo};
+    use crate::http::request::RequestPriority;
+    use crate::http::routing::RouteInfo;
+
+    fn response(remaining: i64) -> Response {
+        let response = http_crate::Response::builder()
+            .header("x-ratelimit-bucket", "abcd1234")
+            .header("x-ratelimit-limit", "2")
+            .header("x-ratelimit-remaining", remaining.to_string())
+            .header("x-ratelimit-reset-after", "0.2")
+            .body("")
+            .unwrap();
+
+        Response::from(response)
+    }
+
+    fn record(headers: &[(&str, &str)]) -> CoordinatorRequest {
+        CoordinatorRequest::Record {
+            route: "Get ChannelsId".to_string(),
+            major_parameter: Some(1),
+            headers: headers
+                .iter()
+                .map(|(name, value)| ((*name).to_string(), (*value).to_string()))
+                .collect(),
+        }
+    }
+
+    fn acquire() -> CoordinatorRequest {
+        CoordinatorRequest::Acquire {
+            route: "Get ChannelsId".to_string(),
+            major_parameter: Some(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_coordinated_ratelimits() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = CoordinatorAddr::Tcp(listener.local_addr().unwrap());
+        tokio::spawn(async move { RatelimitCoordinator::new().serve_tcp(listener).await });
+
+        // Two backends, as if in separate processes.
+        let first = CoordinatedRatelimitBackend::new(addr.clone());
+        let second = CoordinatedRatelimitBackend::new(addr);
+
+        let infos = Arc::new(Mutex::new(Vec::<RatelimitInfo>::new()));
+        let callback = {
+            let infos = Arc::clone(&infos);
+            move |info| infos.lock().unwrap().push(info)
+        };
+
+        let route = RouteInfo::GetChannel {
+            channel_id: 1,
+        };
+        first.acquire(&route, RequestPriority::Normal, &callback).await.unwrap();
+        assert!(!first.record_response(&route, &response(1), &callback).await.unwrap());
+
+        // The bucket is shared by the second backend.
+        second.acquire(&route, RequestPriority::Normal, &callback).await.unwrap();
+        assert!(infos.lock().unwrap().is_empty());
+
+        let exhausted = first.request(&acquire()).await.unwrap();
+        assert!(exhausted.wait_ms > 0 && exhausted.wait_ms <= 200);
+        assert_eq!(exhausted.limit, 2);
+
+        // The global ratelimit blocks every backend.
+        first.lock_global(Duration::from_secs(60)).await.unwrap();
+        let global = second.request(&CoordinatorRequest::WaitGlobal).await.unwrap();
+        assert!(global.wait_ms > 0 && global.wait_ms <= 60_000);
+    }
+
+    #[tokio::test]
+    async fn test_coordinator_error() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = CoordinatorAddr::Tcp(listener.local_addr().unwrap());
+        tokio::spawn(async move { RatelimitCoordinator::new().serve_tcp(listener).await });
+
+        let backend = CoordinatedRatelimitBackend::new(addr);
+        backend.request(&acquire()).await.unwrap();
+
+        // The invalid header fails the request, but not the connection.
+        assert!(backend.request(&record(&[("x-ratelimit-limit", "two")])).await.is_err());
+        assert!(backend.connection.lock().await.is_some());
+
+        let response = backend.request(&record(&[("x-ratelimit-limit", "2")])).await.unwrap();
+        assert_eq!(response.limit, 2);
+    }
+
+    #[tokio::test]
+    async fn test_coordinator_global() {
+        time::pause();
+
+        let mut state = CoordinatorState::default();
+        state
+            .handle(CoordinatorRequest::LockGlobal {
+                duration_ms: 100,
+            })
+            .unwrap();
+
+        assert_eq!(state.handle(CoordinatorRequest::WaitGlobal).unwrap().wait_ms, 100);
+
+        time::advance(Duration::from_millis(60)).await;
+        assert_eq!(state.handle(CoordinatorRequest::WaitGlobal).unwrap().wait_ms, 40);
+
+        time::advance(Duration::from_millis(40)).await;
+        assert_eq!(state.handle(CoordinatorRequest::WaitGlobal).unwrap().wait_ms, 0);
+
+        // Tokens of the global ratelimit are shared by every backend.
+        let take = || CoordinatorRequest::TakeGlobal {
+            limit: 2,
+        };
+        assert_eq!(state.handle(take()).unwrap().wait_ms, 0);
+        assert_eq!(state.handle(take()).unwrap().wait_ms, 0);
+        assert_eq!(state.handle(take()).unwrap().wait_ms, 500);
+    }
+}
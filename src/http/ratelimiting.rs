@@ -37,22 +37,41 @@
 //! variants) have an associated u64 as data. This is the Id of the parameter,
 //! differentiating between different ratelimits.
 //!
+//! Routes are only the initial key of a ratelimit, however. Discord groups
+//! routes into buckets, identified by the hash sent in the
+//! `x-ratelimit-bucket` header. Once the hash of a route is known, all routes
+//! of the same bucket and major parameter share one [`Ratelimit`].
+//!
 //! [Taken from]: https://discord.com/developers/docs/topics/rate-limits#rate-limits
 
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::str::{self, FromStr};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex as StdMutex, MutexGuard, PoisonError};
 use std::time::SystemTime;
 
 use reqwest::header::HeaderMap;
 use reqwest::{Client, Response, StatusCode};
-use tokio::sync::{Mutex, RwLock};
-use tokio::time::{sleep, Duration};
+use tokio::sync::{oneshot, RwLock};
+use tokio::time::{sleep, Duration, Instant};
 use tracing::{debug, instrument};
 
+pub use super::ratelimit_backend::{
+    BucketSnapshot,
+    InMemoryRatelimitBackend,
+    RatelimitBackend,
+    RatelimitBucket,
+    RatelimitCallback,
+};
+pub use super::ratelimit_coordinator::{
+    CoordinatedRatelimitBackend,
+    CoordinatorAddr,
+    RatelimitCoordinator,
+};
+use super::request::RequestPriority;
 pub use super::routing::Route;
-use super::routing::RouteInfo;
+use super::routing::{BaseUrls, RouteInfo};
 use super::{HttpError, LightMethod, Request};
 use crate::internal::prelude::*;
 
@@ -78,22 +97,33 @@ pub struct RatelimitInfo {
 /// When no tickets are available for some time, then the thread sleeps until
 /// that time passes. The mechanism is known as "pre-emptive ratelimiting".
 ///
-/// Occasionally for very high traffic bots, a global ratelimit may be reached
-/// which blocks all future requests until the global ratelimit is over,
-/// regardless of route. The value of this global ratelimit is never given
-/// through the API, so it can't be pre-emptively ratelimited. This only affects
-/// the largest of bots.
+/// Requests are also limited to the global ratelimit, which is 50 requests per
+/// second across all routes unless raised by Discord for a bot. This is
+/// pre-emptively ratelimited as well, and the limit can be configured with
+/// [`Self::set_global_limit`]. Should the global ratelimit still be reached, it
+/// blocks all future requests until it is over, regardless of route.
+/// Interaction responses and followups are exempt from the global ratelimit.
+///
+/// The ratelimits are kept by a [`RatelimitBackend`], by default an
+/// [`InMemoryRatelimitBackend`] tracking them for the current process. Sharing
+/// a backend such as a [`CoordinatedRatelimitBackend`] between processes makes
+/// them share their ratelimits.
+///
+/// Requests waiting on the same ratelimit are sent in order of their
+/// [`RequestPriority`], then in the order they started waiting.
 ///
 /// [`limit`]: Ratelimit::limit
 /// [`remaining`]: Ratelimit::remaining
 /// [`reset`]: Ratelimit::reset
 pub struct Ratelimiter {
     client: Client,
-    global: Arc<Mutex<()>>,
-    // When futures is implemented, make tasks clear out their respective entry
-    // when the 'reset' passes.
-    routes: Arc<RwLock<HashMap<Route, Arc<Mutex<Ratelimit>>>>>,
+    backend: Arc<dyn RatelimitBackend>,
+    // The backend used unless another is set, kept for `Self::routes`.
+    default_backend: Arc<InMemoryRatelimitBackend>,
+    global_limit: u32,
+    global_queue: PriorityQueue,
     token: String,
+    base_urls: BaseUrls,
     ratelimit_callback: Box<dyn Fn(RatelimitInfo) + Send + Sync>,
 }
 
@@ -101,9 +131,9 @@ impl fmt::Debug for Ratelimiter {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Ratelimiter")
             .field("client", &self.client)
-            .field("global", &self.global)
-            .field("routes", &self.routes)
-            .finish()
+            .field("global_limit", &self.global_limit)
+            .field("base_urls", &self.base_urls)
+            .finish_non_exhaustive()
     }
 }
 
@@ -118,53 +148,99 @@ impl Ratelimiter {
     }
 
     fn _new(client: Client, token: String) -> Self {
+        let default_backend = Arc::new(InMemoryRatelimitBackend::new());
+
         Self {
             client,
-            global: Arc::default(),
-            routes: Arc::default(),
+            backend: Arc::clone(&default_backend) as Arc<dyn RatelimitBackend>,
+            default_backend,
+            global_limit: GlobalRatelimit::DEFAULT_LIMIT,
+            global_queue: PriorityQueue::default(),
             token,
+            base_urls: BaseUrls::default(),
             ratelimit_callback: Box::new(|_| {}),
         }
     }
 
-    /// Sets a callback to be called when a route is rate limited.
-    pub fn set_ratelimit_callback(
-        &mut self,
-        ratelimit_callback: Box<dyn Fn(RatelimitInfo) + Send + Sync>,
-    ) {
-        self.ratelimit_callback = ratelimit_callback;
+    /// Sets the base URLs that requests are sent to.
+    ///
+    /// This is done by [`HttpBuilder::build`] with the base URLs configured on
+    /// the builder.
+    ///
+    /// [`HttpBuilder::build`]: super::HttpBuilder::build
+    pub fn set_base_urls(&mut self, base_urls: BaseUrls) {
+        self.base_urls = base_urls;
+    }
+
+    /// The base URLs that requests are sent to.
+    #[must_use]
+    pub fn base_urls(&self) -> &BaseUrls {
+        &self.base_urls
+    }
+
+    /// Sets the backend keeping the ratelimits.
+    ///
+    /// This is done by [`HttpBuilder::build`] with the backend configured on
+    /// the builder, if any.
+    ///
+    /// [`HttpBuilder::build`]: super::HttpBuilder::build
+    pub fn set_backend(&mut self, backend: Arc<dyn RatelimitBackend>) {
+        self.backend = backend;
+    }
+
+    /// The backend keeping the ratelimits.
+    #[must_use]
+    pub fn backend(&self) -> Arc<dyn RatelimitBackend> {
+        Arc::clone(&self.backend)
     }
 
     /// The routes mutex is a HashMap of each [`Route`] and their respective
-    /// ratelimit information.
+    /// ratelimit information, as tracked by the default
+    /// [`InMemoryRatelimitBackend`].
     ///
-    /// See the documentation for [`Ratelimit`] for more information on how the
-    /// library handles ratelimiting.
+    /// The map stays empty if another backend was set with
+    /// [`Self::set_backend`]. Its values are now [`RatelimitBucket`]s, with
+    /// the [`Ratelimit`] behind [`RatelimitBucket::ratelimit`].
+    #[deprecated(note = "use `InMemoryRatelimitBackend::routes` or `Ratelimiter::snapshot`")]
+    #[must_use]
+    pub fn routes(&self) -> Arc<RwLock<HashMap<Route, Arc<RatelimitBucket>>>> {
+        self.default_backend.routes()
+    }
+
+    /// Takes a snapshot of the ratelimit of every bucket tracked by the
+    /// backend, including the number of requests waiting on each.
+    ///
+    /// # Errors
     ///
-    /// # Examples
+    /// Returns an error if the backend could not be reached.
+    pub async fn snapshot(&self) -> Result<Vec<BucketSnapshot>> {
+        self.backend.snapshot().await
+    }
+
+    /// Sets the number of requests per second allowed by the global
+    /// ratelimit, which requests are pre-emptively limited to.
     ///
-    /// View the `reset` time of the route for `ChannelsId(7)`:
+    /// This is 50 by default, and should only be raised for bots whose global
+    /// ratelimit was raised by Discord. A limit of 0 disables pre-emptive
+    /// global ratelimiting.
     ///
-    /// ```rust,no_run
-    /// use serenity::http::ratelimiting::Route;
-    /// # use serenity::http::Http;
+    /// Every process sharing a [`RatelimitBackend`] should be configured with
+    /// the same limit, as they share the global ratelimit.
     ///
-    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
-    /// #     let http = Http::new("token");
-    /// let routes = http.ratelimiter.routes();
-    /// let reader = routes.read().await;
+    /// This is done by [`HttpBuilder::build`] with the limit configured on the
+    /// builder, if any.
     ///
-    /// if let Some(route) = reader.get(&Route::ChannelsId(7)) {
-    ///     if let Some(reset) = route.lock().await.reset() {
-    ///         println!("Reset time at: {:?}", reset);
-    ///     }
-    /// }
-    /// #     Ok(())
-    /// # }
-    /// ```
-    #[must_use]
-    pub fn routes(&self) -> Arc<RwLock<HashMap<Route, Arc<Mutex<Ratelimit>>>>> {
-        Arc::clone(&self.routes)
+    /// [`HttpBuilder::build`]: super::HttpBuilder::build
+    pub fn set_global_limit(&mut self, requests_per_second: u32) {
+        self.global_limit = requests_per_second;
+    }
+
+    /// Sets a callback to be called when a route is rate limited.
+    pub fn set_ratelimit_callback(
+        &mut self,
+        ratelimit_callback: Box<dyn Fn(RatelimitInfo) + Send + Sync>,
+    ) {
+        self.ratelimit_callback = ratelimit_callback;
     }
 
     /// # Errors
@@ -177,8 +253,8 @@ impl Ratelimiter {
         } = req;
 
         loop {
-            // This will block if another thread hit the global ratelimit.
-            drop(self.global.lock().await);
+            // This will block if another request hit the global ratelimit.
+            self.backend.wait_global().await?;
 
             // Destructure the tuple instead of retrieving the third value to
             // take advantage of the type system. If `RouteInfo::deconstruct`
@@ -200,11 +276,32 @@ impl Ratelimiter {
             // - get the global rate;
             // - sleep if there is 0 remaining
             // - then, perform the request
-            let bucket = Arc::clone(self.routes.write().await.entry(route).or_default());
+            //
+            // Routes without a `Route` are ratelimited by the template of their
+            // path. Only status page requests, which are not made to the API,
+            // are not ratelimited.
+            let ratelimited = !req.route.is_status_page();
+            if ratelimited {
+                self.backend.acquire(&req.route, req.priority, &*self.ratelimit_callback).await?;
+
+                if !req.route.is_global_exempt() {
+                    let _turn = self.global_queue.turn(req.priority).await;
+                    if let Some(delay) = self.backend.take_global(self.global_limit).await? {
+                        debug!("Pre-emptive global ratelimit for {}ms", delay.as_millis());
+                        (self.ratelimit_callback)(RatelimitInfo {
+                            timeout: delay,
+                            limit: i64::from(self.global_limit),
+                            method,
+                            path: path.clone(),
+                            global: true,
+                        });
 
-            bucket.lock().await.pre_hook(&req.route, &self.ratelimit_callback).await;
+                        sleep(delay).await;
+                    }
+                }
+            }
 
-            let request = req.build(&self.client, &self.token, None).await?.build()?;
+            let request = req.build(&self.client, &self.token, &self.base_urls).await?.build()?;
 
             let response = self.client.execute(request).await?;
 
@@ -221,13 +318,11 @@ impl Ratelimiter {
             // It _may_ be possible for the limit to be raised at any time,
             // so check if it did from the value of the 'x-ratelimit-limit'
             // header. If the limit was 5 and is now 7, add 2 to the 'remaining'
-            if route == Route::None {
+            if !ratelimited {
                 return Ok(response);
             }
 
             let redo = if response.headers().get("x-ratelimit-global").is_some() {
-                drop(self.global.lock().await);
-
                 Ok(
                     if let Some(retry_after) =
                         parse_header::<f64>(response.headers(), "retry-after")?
@@ -235,12 +330,12 @@ impl Ratelimiter {
                         debug!("Ratelimited on route {:?} for {:?}s", route, retry_after);
                         (self.ratelimit_callback)(RatelimitInfo {
                             timeout: Duration::from_secs_f64(retry_after),
-                            limit: 50,
+                            limit: i64::from(self.global_limit),
                             method,
                             path,
                             global: true,
                         });
-                        sleep(Duration::from_secs_f64(retry_after)).await;
+                        self.backend.lock_global(Duration::from_secs_f64(retry_after)).await?;
 
                         true
                     } else {
@@ -248,7 +343,7 @@ impl Ratelimiter {
                     },
                 )
             } else {
-                bucket.lock().await.post_hook(&response, &req.route, &self.ratelimit_callback).await
+                self.backend.record_response(&req.route, &response, &*self.ratelimit_callback).await
             };
 
             if !redo.unwrap_or(true) {
@@ -258,6 +353,53 @@ impl Ratelimiter {
     }
 }
 
+/// A token bucket pre-emptively limiting requests to the global ratelimit,
+/// kept by a [`RatelimitBackend`].
+///
+/// Tokens refill continuously, up to a burst of one second's worth. A request
+/// finding the bucket empty reserves the next token, so that waiting requests
+/// are spread out instead of all being let through at once.
+#[derive(Debug, Default)]
+pub(crate) struct GlobalRatelimit {
+    /// The tokens available, negative if reserved by waiting requests.
+    tokens: f64,
+    /// When tokens were last taken, if ever.
+    updated: Option<Instant>,
+}
+
+impl GlobalRatelimit {
+    pub(crate) const DEFAULT_LIMIT: u32 = 50;
+
+    /// Takes a token at `now`, with `limit` requests allowed per second,
+    /// returning how long to wait for it if none is available.
+    ///
+    /// A limit of 0 disables the global ratelimit.
+    pub(crate) fn take(&mut self, limit: u32, now: Instant) -> Option<Duration> {
+        if limit == 0 {
+            return None;
+        }
+
+        let rate = f64::from(limit);
+        let available = match self.updated {
+            Some(updated) => {
+                let elapsed = now.saturating_duration_since(updated).as_secs_f64();
+
+                (self.tokens + elapsed * rate).min(rate)
+            },
+            None => rate,
+        };
+
+        self.tokens = available - 1.0;
+        self.updated = Some(now);
+
+        if self.tokens >= 0.0 {
+            None
+        } else {
+            Some(Duration::from_secs_f64(-self.tokens / rate))
+        }
+    }
+}
+
 /// A set of data containing information about the ratelimits for a particular
 /// [`Route`], which is stored in [`Http`].
 ///
@@ -287,8 +429,40 @@ impl Ratelimit {
         route: &RouteInfo<'_>,
         ratelimit_callback: &(dyn Fn(RatelimitInfo) + Send + Sync),
     ) {
+        if let Some(delay) = self.take_ticket() {
+            let (method, route, path) = route.deconstruct();
+
+            debug!("Pre-emptive ratelimit on route {:?} for {}ms", route, delay.as_millis(),);
+            ratelimit_callback(RatelimitInfo {
+                timeout: delay,
+                limit: self.limit,
+                method,
+                path: path.to_string(),
+                global: false,
+            });
+
+            sleep(delay).await;
+        }
+    }
+
+    #[instrument(skip(ratelimit_callback))]
+    pub async fn post_hook(
+        &mut self,
+        response: &Response,
+        route: &RouteInfo<'_>,
+        ratelimit_callback: &(dyn Fn(RatelimitInfo) + Send + Sync),
+    ) -> Result<bool> {
+        self.update(response.headers())?;
+
+        retry_after(response.status(), response.headers(), route, self.limit, ratelimit_callback)
+            .await
+    }
+
+    /// Takes a ticket for a request, returning how long to wait for the
+    /// interval to reset if none remain.
+    pub(crate) fn take_ticket(&mut self) -> Option<Duration> {
         if self.limit() == 0 {
-            return;
+            return None;
         }
 
         let reset = if let Some(reset) = self.reset {
@@ -297,7 +471,7 @@ impl Ratelimit {
             // We're probably in the past.
             self.remaining = self.limit;
 
-            return;
+            return None;
         };
 
         let delay = if let Ok(delay) = reset.duration_since(SystemTime::now()) {
@@ -307,52 +481,34 @@ impl Ratelimit {
             if self.remaining() != 0 {
                 self.remaining -= 1;
             }
-            return;
+            return None;
         };
 
         if self.remaining() == 0 {
-            let (method, route, path) = route.deconstruct();
-
-            debug!("Pre-emptive ratelimit on route {:?} for {}ms", route, delay.as_millis(),);
-            ratelimit_callback(RatelimitInfo {
-                timeout: delay,
-                limit: self.limit,
-                method,
-                path: path.to_string(),
-                global: false,
-            });
-
-            sleep(delay).await;
-
-            return;
+            return Some(delay);
         }
 
         self.remaining -= 1;
+
+        None
     }
 
-    #[instrument(skip(ratelimit_callback))]
-    pub async fn post_hook(
-        &mut self,
-        response: &Response,
-        route: &RouteInfo<'_>,
-        ratelimit_callback: &(dyn Fn(RatelimitInfo) + Send + Sync),
-    ) -> Result<bool> {
-        if let Some(limit) = parse_header(response.headers(), "x-ratelimit-limit")? {
+    /// Updates the ratelimit from the ratelimit headers of a response.
+    pub(crate) fn update(&mut self, headers: &HeaderMap) -> Result<()> {
+        if let Some(limit) = parse_header(headers, "x-ratelimit-limit")? {
             self.limit = limit;
         }
 
-        if let Some(remaining) = parse_header(response.headers(), "x-ratelimit-remaining")? {
+        if let Some(remaining) = parse_header(headers, "x-ratelimit-remaining")? {
             self.remaining = remaining;
         }
 
         #[cfg(feature = "absolute_ratelimits")]
-        if let Some(reset) = parse_header::<f64>(response.headers(), "x-ratelimit-reset")? {
+        if let Some(reset) = parse_header::<f64>(headers, "x-ratelimit-reset")? {
             self.reset = Some(std::time::UNIX_EPOCH + Duration::from_secs_f64(reset));
         }
 
-        if let Some(reset_after) =
-            parse_header::<f64>(response.headers(), "x-ratelimit-reset-after")?
-        {
+        if let Some(reset_after) = parse_header::<f64>(headers, "x-ratelimit-reset-after")? {
             #[cfg(not(feature = "absolute_ratelimits"))]
             {
                 self.reset = Some(SystemTime::now() + Duration::from_secs_f64(reset_after));
@@ -361,26 +517,7 @@ impl Ratelimit {
             self.reset_after = Some(Duration::from_secs_f64(reset_after));
         }
 
-        Ok(if response.status() != StatusCode::TOO_MANY_REQUESTS {
-            false
-        } else if let Some(retry_after) = parse_header::<f64>(response.headers(), "retry-after")? {
-            let (method, route, path) = route.deconstruct();
-
-            debug!("Ratelimited on route {:?} for {:?}ms", route, retry_after);
-            ratelimit_callback(RatelimitInfo {
-                timeout: Duration::from_secs_f64(retry_after),
-                limit: self.limit,
-                method,
-                path: path.to_string(),
-                global: false,
-            });
-
-            sleep(Duration::from_secs_f64(retry_after)).await;
-
-            true
-        } else {
-            false
-        })
+        Ok(())
     }
 
     /// The total number of requests that can be made in a period of time.
@@ -442,7 +579,138 @@ impl<'a> From<Request<'a>> for RatelimitedRequest<'a> {
     }
 }
 
-fn parse_header<T: FromStr>(headers: &HeaderMap, header: &str) -> Result<Option<T>> {
+/// A queue of requests taking turns in order of priority, then in the order
+/// they started waiting.
+#[derive(Debug, Default)]
+pub(crate) struct PriorityQueue {
+    state: StdMutex<QueueState>,
+}
+
+#[derive(Debug, Default)]
+struct QueueState {
+    /// Whether a request holds the turn.
+    busy: bool,
+    /// The sequence number of the next waiting request.
+    next: u64,
+    waiting: BTreeMap<(Reverse<RequestPriority>, u64), oneshot::Sender<()>>,
+}
+
+impl PriorityQueue {
+    fn state(&self) -> MutexGuard<'_, QueueState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Waits for the turn of a request of the given priority, which is held
+    /// until the returned [`Turn`] is dropped.
+    pub(crate) async fn turn(&self, priority: RequestPriority) -> Turn<'_> {
+        let (key, receiver) = {
+            let mut state = self.state();
+
+            if !state.busy {
+                state.busy = true;
+
+                return Turn(self);
+            }
+
+            let key = (Reverse(priority), state.next);
+            let (sender, receiver) = oneshot::channel();
+            state.next += 1;
+            state.waiting.insert(key, sender);
+
+            (key, receiver)
+        };
+
+        let mut waiter = QueueWaiter {
+            queue: self,
+            key,
+            done: false,
+        };
+
+        // The sender is only dropped after sending, or by the waiter.
+        receiver.await.ok();
+        waiter.done = true;
+
+        Turn(self)
+    }
+
+    /// Passes the turn to the next waiting request.
+    fn pass(&self) {
+        let mut state = self.state();
+
+        while let Some(key) = state.waiting.keys().next().copied() {
+            if let Some(sender) = state.waiting.remove(&key) {
+                if sender.send(()).is_ok() {
+                    return;
+                }
+            }
+        }
+
+        state.busy = false;
+    }
+}
+
+/// A request's turn in a [`PriorityQueue`], passed on when dropped.
+pub(crate) struct Turn<'a>(&'a PriorityQueue);
+
+impl Drop for Turn<'_> {
+    fn drop(&mut self) {
+        self.0.pass();
+    }
+}
+
+/// A request waiting in a [`PriorityQueue`], which passes on the turn if
+/// cancelled after being given it.
+struct QueueWaiter<'a> {
+    queue: &'a PriorityQueue,
+    key: (Reverse<RequestPriority>, u64),
+    done: bool,
+}
+
+impl Drop for QueueWaiter<'_> {
+    fn drop(&mut self) {
+        if self.done {
+            return;
+        }
+
+        let given = self.queue.state().waiting.remove(&self.key).is_none();
+        if given {
+            self.queue.pass();
+        }
+    }
+}
+
+/// Waits out the `retry-after` of a ratelimited response, returning whether
+/// the request should be retried.
+pub(crate) async fn retry_after(
+    status: StatusCode,
+    headers: &HeaderMap,
+    route: &RouteInfo<'_>,
+    limit: i64,
+    ratelimit_callback: &(dyn Fn(RatelimitInfo) + Send + Sync),
+) -> Result<bool> {
+    Ok(if status != StatusCode::TOO_MANY_REQUESTS {
+        false
+    } else if let Some(retry_after) = parse_header::<f64>(headers, "retry-after")? {
+        let (method, route, path) = route.deconstruct();
+
+        debug!("Ratelimited on route {:?} for {:?}ms", route, retry_after);
+        ratelimit_callback(RatelimitInfo {
+            timeout: Duration::from_secs_f64(retry_after),
+            limit,
+            method,
+            path: path.to_string(),
+            global: false,
+        });
+
+        sleep(Duration::from_secs_f64(retry_after)).await;
+
+        true
+    } else {
+        false
+    })
+}
+
+pub(crate) fn parse_header<T: FromStr>(headers: &HeaderMap, header: &str) -> Result<Option<T>> {
     let header = match headers.get(header) {
         Some(v) => v,
         None => return Ok(None),
@@ -460,11 +728,15 @@ fn parse_header<T: FromStr>(headers: &HeaderMap, header: &str) -> Result<Option<
 mod tests {
     use std::error::Error as StdError;
     use std::result::Result as StdResult;
+    use std::sync::Arc;
 
     use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+    use tokio::sync::Mutex;
+    use tokio::time::{sleep, timeout, Duration, Instant};
 
-    use super::parse_header;
+    use super::{parse_header, GlobalRatelimit, PriorityQueue};
     use crate::error::Error;
+    use crate::http::request::RequestPriority;
     use crate::http::HttpError;
 
     type Result<T> = StdResult<T, Box<dyn StdError>>;
@@ -523,4 +795,61 @@ mod tests {
         assert!(is_err!("x-bad-num", HttpError::RateLimitI64F64));
         assert!(is_err!("x-bad-unicode", HttpError::RateLimitUtf8));
     }
+
+    #[test]
+    fn test_global_ratelimit() {
+        let mut global = GlobalRatelimit::default();
+        let now = Instant::now();
+
+        for _ in 0..50 {
+            assert!(global.take(50, now).is_none());
+        }
+
+        // Further requests wait for a token each, one after the other.
+        assert_eq!(global.take(50, now), Some(Duration::from_millis(20)));
+        assert_eq!(global.take(50, now), Some(Duration::from_millis(40)));
+
+        // Tokens refill over time.
+        let later = now + Duration::from_secs(1);
+        assert!(global.take(50, later).is_none());
+
+        assert!(GlobalRatelimit::default().take(0, now).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_priority_queue() {
+        let queue = Arc::new(PriorityQueue::default());
+        let order = Arc::new(Mutex::new(Vec::new()));
+
+        let turn = queue.turn(RequestPriority::Normal).await;
+
+        let mut tasks = Vec::new();
+        for priority in [RequestPriority::Low, RequestPriority::Normal, RequestPriority::High] {
+            let queue = Arc::clone(&queue);
+            let order = Arc::clone(&order);
+
+            tasks.push(tokio::spawn(async move {
+                let _turn = queue.turn(priority).await;
+                order.lock().await.push(priority);
+            }));
+
+            sleep(Duration::from_millis(10)).await;
+        }
+
+        // A cancelled waiter does not hold up the queue.
+        let cancelled = timeout(Duration::from_millis(10), queue.turn(RequestPriority::High));
+        assert!(cancelled.await.is_err());
+
+        drop(turn);
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        assert_eq!(*order.lock().await, vec![
+            RequestPriority::High,
+            RequestPriority::Normal,
+            RequestPriority::Low
+        ]);
+        assert!(!queue.state().busy);
+    }
 }
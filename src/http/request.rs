@@ -1,4 +1,4 @@
-use std::borrow::Cow;
+use std::time::Duration;
 
 use reqwest::header::{
     HeaderMap as Headers,
@@ -8,19 +8,43 @@ use reqwest::header::{
     CONTENT_TYPE,
     USER_AGENT,
 };
-use reqwest::{Client, RequestBuilder as ReqwestRequestBuilder, Url};
+use reqwest::{Client, RequestBuilder as ReqwestRequestBuilder};
 use tracing::instrument;
 
 use super::multipart::Multipart;
-use super::routing::RouteInfo;
+use super::routing::{BaseUrls, RouteInfo};
 use super::HttpError;
 use crate::constants;
 use crate::internal::prelude::*;
 
+/// The priority of a request, deciding which of the requests waiting on the
+/// same ratelimit is sent first.
+///
+/// Requests of the same priority are sent in the order they started waiting.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum RequestPriority {
+    /// For bulk work that may wait behind other requests.
+    Low,
+    /// The priority of most requests.
+    Normal,
+    /// For time-sensitive requests, such as responses to interactions, which
+    /// must be sent within 3 seconds.
+    High,
+}
+
+impl Default for RequestPriority {
+    fn default() -> Self {
+        Self::Normal
+    }
+}
+
 pub struct RequestBuilder<'a> {
     body: Option<&'a [u8]>,
     multipart: Option<Multipart<'a>>,
     headers: Option<Headers>,
+    timeout: Option<Duration>,
+    priority: RequestPriority,
     route: RouteInfo<'a>,
 }
 
@@ -31,6 +55,8 @@ impl<'a> RequestBuilder<'a> {
             body: None,
             multipart: None,
             headers: None,
+            timeout: None,
+            priority: RequestPriority::Normal,
             route: route_info,
         }
     }
@@ -58,6 +84,26 @@ impl<'a> RequestBuilder<'a> {
         self
     }
 
+    /// Sets the maximum time to spend on the request, including waiting on
+    /// ratelimits, overriding [`HttpBuilder::default_timeout`].
+    ///
+    /// A request taking longer fails with [`HttpError::Timeout`].
+    ///
+    /// [`HttpBuilder::default_timeout`]: super::HttpBuilder::default_timeout
+    pub fn timeout(&mut self, timeout: Option<Duration>) -> &mut Self {
+        self.timeout = timeout;
+
+        self
+    }
+
+    /// Sets the priority of the request over others waiting on the same
+    /// ratelimit. Defaults to [`RequestPriority::Normal`].
+    pub fn priority(&mut self, priority: RequestPriority) -> &mut Self {
+        self.priority = priority;
+
+        self
+    }
+
     pub fn route(&mut self, route_info: RouteInfo<'a>) -> &mut Self {
         self.route = route_info;
 
@@ -70,6 +116,8 @@ pub struct Request<'a> {
     pub(super) body: Option<&'a [u8]>,
     pub(super) multipart: Option<Multipart<'a>>,
     pub(super) headers: Option<Headers>,
+    pub(super) timeout: Option<Duration>,
+    pub(super) priority: RequestPriority,
     pub(super) route: RouteInfo<'a>,
 }
 
@@ -80,6 +128,8 @@ impl<'a> Request<'a> {
             body,
             multipart,
             headers,
+            timeout,
+            priority,
             route,
         } = builder;
 
@@ -87,6 +137,8 @@ impl<'a> Request<'a> {
             body,
             multipart,
             headers,
+            timeout,
+            priority,
             route,
         }
     }
@@ -96,23 +148,19 @@ impl<'a> Request<'a> {
         &mut self,
         client: &Client,
         token: &str,
-        proxy: Option<&Url>,
+        base_urls: &BaseUrls,
     ) -> Result<ReqwestRequestBuilder> {
         let Request {
             body,
             ref mut multipart,
             headers: ref request_headers,
             route: ref route_info,
+            ..
         } = *self;
 
-        let (method, _, mut path) = route_info.deconstruct();
+        let (method, ..) = route_info.deconstruct();
 
-        if let Some(proxy) = proxy {
-            path = Cow::Owned(path.to_mut().replace("https://discord.com/", proxy.as_str()));
-        }
-
-        let mut builder =
-            client.request(method.reqwest_method(), Url::parse(&path).map_err(HttpError::Url)?);
+        let mut builder = client.request(method.reqwest_method(), route_info.url(base_urls)?);
 
         if let Some(bytes) = body {
             builder = builder.body(Vec::from(bytes));
@@ -167,6 +215,26 @@ impl<'a> Request<'a> {
         &mut self.headers
     }
 
+    #[must_use]
+    pub fn timeout_ref(&self) -> &Option<Duration> {
+        &self.timeout
+    }
+
+    #[must_use]
+    pub fn timeout_mut(&mut self) -> &mut Option<Duration> {
+        &mut self.timeout
+    }
+
+    #[must_use]
+    pub fn priority_ref(&self) -> &RequestPriority {
+        &self.priority
+    }
+
+    #[must_use]
+    pub fn priority_mut(&mut self) -> &mut RequestPriority {
+        &mut self.priority
+    }
+
     #[must_use]
     pub fn route_ref(&self) -> &RouteInfo<'_> {
         &self.route
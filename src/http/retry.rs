@@ -0,0 +1,178 @@
+use std::time::Duration;
+
+use rand::Rng;
+use reqwest::Response;
+
+use super::{HttpError, LightMethod};
+use crate::internal::prelude::*;
+
+/// A policy for retrying requests that failed for transient reasons: a server
+/// error response, a timeout, or a failed or reset connection.
+///
+/// Retries are waited out with an exponential backoff with jitter, and are
+/// only made for the [methods] that are safe to repeat, by default `GET`,
+/// `PUT` and `DELETE`.
+///
+/// Set it on an [`Http`] client with [`HttpBuilder::retry_policy`].
+///
+/// # Examples
+///
+/// Retry requests up to 5 times, waiting from 250 milliseconds up to 8
+/// seconds between attempts:
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use serenity::http::{HttpBuilder, RetryPolicy};
+///
+/// let policy = RetryPolicy::new()
+///     .max_attempts(6)
+///     .backoff(Duration::from_millis(250), Duration::from_secs(8));
+///
+/// let http = HttpBuilder::new("token").retry_policy(policy).build();
+/// ```
+///
+/// [methods]: Self::methods
+/// [`Http`]: super::Http
+/// [`HttpBuilder::retry_policy`]: super::HttpBuilder::retry_policy
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct RetryPolicy {
+    /// The maximum number of attempts to make for a request, including the
+    /// first.
+    pub max_attempts: u32,
+    /// The delay before the first retry, doubled for every retry after.
+    pub base_delay: Duration,
+    /// The maximum delay between attempts.
+    pub max_delay: Duration,
+    /// The methods of requests that may be retried.
+    pub methods: Vec<LightMethod>,
+}
+
+impl RetryPolicy {
+    /// Creates a policy making up to 3 attempts, waiting 500 milliseconds
+    /// before the first retry and up to 10 seconds between attempts.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of attempts to make for a request, including
+    /// the first. A value of 1 disables retrying.
+    #[must_use]
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+
+        self
+    }
+
+    /// Sets the delay before the first retry, which is doubled for every
+    /// retry after, up to `max_delay`.
+    #[must_use]
+    pub fn backoff(mut self, base_delay: Duration, max_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self.max_delay = max_delay;
+
+        self
+    }
+
+    /// Sets the methods of requests that may be retried.
+    ///
+    /// **Note**: Retrying a `POST` or `PATCH` request may apply it twice, such
+    /// as sending a message twice, if the first attempt reached Discord.
+    #[must_use]
+    pub fn methods(mut self, methods: impl Into<Vec<LightMethod>>) -> Self {
+        self.methods = methods.into();
+
+        self
+    }
+
+    /// Whether requests of the given method may be retried.
+    #[must_use]
+    pub fn retries(&self, method: LightMethod) -> bool {
+        self.max_attempts > 1 && self.methods.contains(&method)
+    }
+
+    /// Whether the result of an attempt warrants another, given that it was
+    /// the `attempt`-th, starting at 1.
+    pub(crate) fn should_retry(&self, attempt: u32, result: &Result<Response>) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+
+        match result {
+            Ok(response) => response.status().is_server_error(),
+            Err(Error::Http(why)) => match &**why {
+                HttpError::Request(why) => why.is_timeout() || why.is_connect() || why.is_request(),
+                _ => false,
+            },
+            Err(_) => false,
+        }
+    }
+
+    /// The delay before retrying after the `attempt`-th attempt, without
+    /// jitter.
+    fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2_u32.saturating_pow(attempt.saturating_sub(1));
+
+        self.base_delay
+            .checked_mul(factor)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay))
+    }
+
+    /// The delay before retrying after the `attempt`-th attempt, randomized
+    /// between half and all of the exponential delay.
+    pub(crate) fn backoff_delay(&self, attempt: u32) -> Duration {
+        let delay = self.delay(attempt);
+
+        delay / 2 + delay.mul_f64(rand::thread_rng().gen_range(0.0..=0.5))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            methods: vec![LightMethod::Get, LightMethod::Put, LightMethod::Delete],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use reqwest::Response;
+
+    use super::RetryPolicy;
+    use crate::http::LightMethod;
+
+    #[test]
+    fn test_retry_policy() {
+        let policy = RetryPolicy::new()
+            .max_attempts(4)
+            .backoff(Duration::from_millis(100), Duration::from_millis(300));
+
+        assert!(policy.retries(LightMethod::Get));
+        assert!(!policy.retries(LightMethod::Post));
+        assert!(!policy.clone().max_attempts(1).retries(LightMethod::Get));
+
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(3), Duration::from_millis(300));
+        assert_eq!(policy.delay(40), Duration::from_millis(300));
+
+        let delay = policy.backoff_delay(2);
+        assert!(delay >= Duration::from_millis(100) && delay <= Duration::from_millis(200));
+
+        let response = |status| {
+            Response::from(http_crate::Response::builder().status(status).body("").unwrap())
+        };
+
+        assert!(policy.should_retry(1, &Ok(response(502))));
+        assert!(!policy.should_retry(4, &Ok(response(502))));
+        assert!(!policy.should_retry(1, &Ok(response(404))));
+    }
+}
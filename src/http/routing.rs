@@ -1,14 +1,22 @@
 use std::borrow::Cow;
 use std::fmt::{Display, Write};
 
-use super::LightMethod;
+use reqwest::Url;
+
+use super::{HttpError, LightMethod};
 use crate::constants;
+use crate::internal::prelude::*;
 use crate::model::Timestamp;
 
 /// A representation of all routes registered within the library. These are safe
 /// and memory-efficient representations of each path that functions exist for
 /// in the [`http`] module.
 ///
+/// The `*_path` functions build the path of a route, relative to a base URL;
+/// use [`BaseUrls::join`] to resolve it. The deprecated functions of the same
+/// name without the suffix still return the full URL on Discord's API, ignoring
+/// any configured base URL.
+///
 /// [`http`]: crate::http
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
@@ -462,35 +470,846 @@ pub enum Route {
     ///
     /// [`ChannelId`]: crate::model::id::ChannelId
     StageInstancesChannelId(u64),
-    /// Route where no ratelimit headers are in place (i.e. user account-only
-    /// routes).
+    /// Route that has no variant of its own.
     ///
-    /// This is a special case, in that if the route is [`None`] then pre- and
-    /// post-hooks are not executed.
+    /// Requests to these routes are ratelimited by the template of their path
+    /// and their major parameter instead.
     None,
 }
 
 impl Route {
+    /// The ID of the major parameter of the route, which Discord tracks
+    /// ratelimits of the same bucket separately for.
+    ///
+    /// This is the data of the variant, or `None` for routes without a major
+    /// parameter.
+    #[must_use]
+    pub fn major_parameter(&self) -> Option<u64> {
+        match *self {
+            Route::ChannelsId(id)
+            | Route::ChannelsIdInvites(id)
+            | Route::ChannelsIdMessages(id)
+            | Route::ChannelsIdMessagesBulkDelete(id)
+            | Route::ChannelsIdMessagesIdAck(id)
+            | Route::ChannelsIdMessagesIdReactions(id)
+            | Route::ChannelsIdMessagesIdReactionsUserIdType(id)
+            | Route::ChannelsIdPermissionsOverwriteId(id)
+            | Route::ChannelsIdPins(id)
+            | Route::ChannelsIdPinsMessageId(id)
+            | Route::ChannelsIdCrosspostsMessageId(id)
+            | Route::ChannelsIdTyping(id)
+            | Route::ChannelsIdWebhooks(id)
+            | Route::ChannelsIdMessagesIdThreads(id)
+            | Route::ChannelsIdThreads(id)
+            | Route::ChannelsIdThreadMembersMe(id)
+            | Route::ChannelsIdThreadMembersUserId(id)
+            | Route::ChannelsIdThreadMembers(id)
+            | Route::ChannelsIdArchivedPublicThreads(id)
+            | Route::ChannelsIdArchivedPrivateThreads(id)
+            | Route::ChannelsIdMeJoindedArchivedPrivateThreads(id)
+            | Route::FollowNewsChannel(id)
+            | Route::GuildsId(id)
+            | Route::GuildsIdAutoModRules(id)
+            | Route::GuildsIdAutoModRulesId(id)
+            | Route::GuildsIdBans(id)
+            | Route::GuildsIdAuditLogs(id)
+            | Route::GuildsIdBansUserId(id)
+            | Route::GuildsIdChannels(id)
+            | Route::GuildsIdWidget(id)
+            | Route::GuildsIdPreview(id)
+            | Route::GuildsIdEmojis(id)
+            | Route::GuildsIdEmojisId(id)
+            | Route::GuildsIdIntegrations(id)
+            | Route::GuildsIdIntegrationsId(id)
+            | Route::GuildsIdIntegrationsIdSync(id)
+            | Route::GuildsIdInvites(id)
+            | Route::GuildsIdMembers(id)
+            | Route::GuildsIdMembersId(id)
+            | Route::GuildsIdMembersIdRolesId(id)
+            | Route::GuildsIdMembersMe(id)
+            | Route::GuildsIdMembersMeNick(id)
+            | Route::GuildsIdMembersSearch(id)
+            | Route::GuildsIdPrune(id)
+            | Route::GuildsIdRegions(id)
+            | Route::GuildsIdRoles(id)
+            | Route::GuildsIdRolesId(id)
+            | Route::GuildsIdScheduledEvents(id)
+            | Route::GuildsIdScheduledEventsId(id)
+            | Route::GuildsIdScheduledEventsIdUsers(id)
+            | Route::GuildsIdStickers(id)
+            | Route::GuildsIdStickersId(id)
+            | Route::GuildsIdVanityUrl(id)
+            | Route::GuildsIdVoiceStates(id)
+            | Route::GuildsIdVoiceStatesMe(id)
+            | Route::GuildsIdWebhooks(id)
+            | Route::GuildsIdWelcomeScreen(id)
+            | Route::WebhooksId(id)
+            | Route::WebhooksIdMessagesId(id)
+            | Route::WebhooksApplicationId(id)
+            | Route::InteractionsId(id)
+            | Route::ApplicationsIdCommands(id)
+            | Route::ApplicationsIdCommandsId(id)
+            | Route::ApplicationsIdGuildsIdCommands(id)
+            | Route::ApplicationsIdGuildsIdCommandsPermissions(id)
+            | Route::ApplicationsIdGuildsIdCommandIdPermissions(id)
+            | Route::ApplicationsIdGuildsIdCommandsId(id)
+            | Route::StageInstancesChannelId(id)
+            | Route::ChannelsIdMessagesId(_, id) => Some(id),
+            Route::Gateway
+            | Route::GatewayBot
+            | Route::Guilds
+            | Route::GuildsIdThreadsActive
+            | Route::InvitesCode
+            | Route::StickerPacks
+            | Route::StickersId
+            | Route::UsersId
+            | Route::UsersMe
+            | Route::UsersMeChannels
+            | Route::UsersMeConnections
+            | Route::UsersMeGuilds
+            | Route::UsersMeGuildsId
+            | Route::VoiceRegions
+            | Route::StageInstances
+            | Route::None => None,
+        }
+    }
+
+    #[must_use]
+    pub fn channel_path(channel_id: u64) -> String {
+        format!("/channels/{}", channel_id)
+    }
+
+    #[must_use]
+    pub fn channel_invites_path(channel_id: u64) -> String {
+        format!("/channels/{}/invites", channel_id)
+    }
+
+    #[must_use]
+    pub fn channel_message_path(channel_id: u64, message_id: u64) -> String {
+        format!("/channels/{}/messages/{}", channel_id, message_id)
+    }
+
+    #[must_use]
+    pub fn channel_message_crosspost_path(channel_id: u64, message_id: u64) -> String {
+        format!("/channels/{}/messages/{}/crosspost", channel_id, message_id)
+    }
+
+    #[must_use]
+    pub fn channel_message_reaction_path<D, T>(
+        channel_id: u64,
+        message_id: u64,
+        user_id: D,
+        reaction_type: T,
+    ) -> String
+    where
+        D: Display,
+        T: Display,
+    {
+        format!(
+            "/channels/{}/messages/{}/reactions/{}/{}",
+            channel_id, message_id, reaction_type, user_id,
+        )
+    }
+
+    #[must_use]
+    pub fn channel_message_reaction_emoji_path<T>(
+        channel_id: u64,
+        message_id: u64,
+        reaction_type: T,
+    ) -> String
+    where
+        T: Display,
+    {
+        format!("/channels/{}/messages/{}/reactions/{}", channel_id, message_id, reaction_type)
+    }
+
+    #[must_use]
+    pub fn channel_message_reactions_path(channel_id: u64, message_id: u64) -> String {
+        format!("/channels/{}/messages/{}/reactions", channel_id, message_id)
+    }
+
+    #[must_use]
+    pub fn channel_message_reactions_list_path(
+        channel_id: u64,
+        message_id: u64,
+        reaction: &str,
+        limit: u8,
+        after: Option<u64>,
+    ) -> String {
+        let mut url = format!(
+            "/channels/{}/messages/{}/reactions/{}?limit={}",
+            channel_id, message_id, reaction, limit,
+        );
+
+        if let Some(after) = after {
+            write!(url, "&after={}", after).unwrap();
+        }
+
+        url
+    }
+
+    #[must_use]
+    pub fn channel_messages_path(channel_id: u64, query: Option<&str>) -> String {
+        format!("/channels/{}/messages{}", channel_id, query.unwrap_or(""))
+    }
+
+    #[must_use]
+    pub fn channel_messages_bulk_delete_path(channel_id: u64) -> String {
+        format!("/channels/{}/messages/bulk-delete", channel_id)
+    }
+
+    #[must_use]
+    pub fn channel_follow_news_path(channel_id: u64) -> String {
+        format!("/channels/{}/followers", channel_id)
+    }
+
+    #[must_use]
+    pub fn channel_permission_path(channel_id: u64, target_id: u64) -> String {
+        format!("/channels/{}/permissions/{}", channel_id, target_id)
+    }
+
+    #[must_use]
+    pub fn channel_pin_path(channel_id: u64, message_id: u64) -> String {
+        format!("/channels/{}/pins/{}", channel_id, message_id)
+    }
+
+    #[must_use]
+    pub fn channel_pins_path(channel_id: u64) -> String {
+        format!("/channels/{}/pins", channel_id)
+    }
+
+    #[must_use]
+    pub fn channel_typing_path(channel_id: u64) -> String {
+        format!("/channels/{}/typing", channel_id)
+    }
+
+    #[must_use]
+    pub fn channel_webhooks_path(channel_id: u64) -> String {
+        format!("/channels/{}/webhooks", channel_id)
+    }
+
+    #[must_use]
+    pub fn channel_public_threads_path(channel_id: u64, message_id: u64) -> String {
+        format!("/channels/{}/messages/{}/threads", channel_id, message_id)
+    }
+
+    #[must_use]
+    pub fn channel_private_threads_path(channel_id: u64) -> String {
+        format!("/channels/{}/threads", channel_id)
+    }
+
+    #[must_use]
+    pub fn channel_thread_member_path(channel_id: u64, user_id: u64) -> String {
+        format!("/channels/{}/thread-members/{}", channel_id, user_id)
+    }
+
+    #[must_use]
+    pub fn channel_thread_member_me_path(channel_id: u64) -> String {
+        format!("/channels/{}/thread-members/@me", channel_id)
+    }
+
+    #[must_use]
+    pub fn channel_thread_members_path(channel_id: u64) -> String {
+        format!("/channels/{}/thread-members", channel_id)
+    }
+
+    #[must_use]
+    pub fn channel_archived_public_threads_path(
+        channel_id: u64,
+        before: Option<Timestamp>,
+        limit: Option<u64>,
+    ) -> String {
+        let mut s = format!("/channels/{}/threads/archived/public", channel_id);
+
+        if let Some(id) = before {
+            write!(s, "?before={}", id).unwrap();
+        }
+
+        if let Some(limit) = limit {
+            write!(s, "&limit={}", limit).unwrap();
+        }
+
+        s
+    }
+
+    #[must_use]
+    pub fn channel_archived_private_threads_path(
+        channel_id: u64,
+        before: Option<Timestamp>,
+        limit: Option<u64>,
+    ) -> String {
+        let mut s = format!("/channels/{}/threads/archived/private", channel_id);
+
+        if let Some(id) = before {
+            write!(s, "&before={}", id).unwrap();
+        }
+
+        if let Some(limit) = limit {
+            write!(s, "&limit={}", limit).unwrap();
+        }
+
+        s
+    }
+
+    #[must_use]
+    pub fn channel_joined_private_threads_path(
+        channel_id: u64,
+        before: Option<Timestamp>,
+        limit: Option<u64>,
+    ) -> String {
+        let mut s = format!("/channels/{}/users/@me/threads/archived/private", channel_id);
+
+        if let Some(id) = before {
+            write!(s, "&before={}", id).unwrap();
+        }
+
+        if let Some(limit) = limit {
+            write!(s, "&limit={}", limit).unwrap();
+        }
+
+        s
+    }
+
+    #[must_use]
+    pub fn gateway_path() -> &'static str {
+        "/gateway"
+    }
+
+    #[must_use]
+    pub fn gateway_bot_path() -> &'static str {
+        "/gateway/bot"
+    }
+
+    #[must_use]
+    pub fn guild_path(guild_id: u64) -> String {
+        format!("/guilds/{}", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_with_counts_path(guild_id: u64) -> String {
+        format!("/guilds/{}?with_counts=true", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_audit_logs_path(
+        guild_id: u64,
+        action_type: Option<u8>,
+        user_id: Option<u64>,
+        before: Option<u64>,
+        limit: Option<u8>,
+    ) -> String {
+        let mut s = format!("/guilds/{}/audit-logs?", guild_id);
+
+        if let Some(action_type) = action_type {
+            write!(s, "&action_type={}", action_type).unwrap();
+        }
+
+        if let Some(before) = before {
+            write!(s, "&before={}", before).unwrap();
+        }
+
+        if let Some(limit) = limit {
+            write!(s, "&limit={}", limit).unwrap();
+        }
+
+        if let Some(user_id) = user_id {
+            write!(s, "&user_id={}", user_id).unwrap();
+        }
+
+        s
+    }
+
+    #[must_use]
+    pub fn guild_automod_rule_path(guild_id: u64, rule_id: u64) -> String {
+        format!("/guilds/{}/auto-moderation/rules/{}", guild_id, rule_id)
+    }
+
+    #[must_use]
+    pub fn guild_automod_rules_path(guild_id: u64) -> String {
+        format!("/guilds/{}/auto-moderation/rules", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_ban_path(guild_id: u64, user_id: u64) -> String {
+        format!("/guilds/{}/bans/{}", guild_id, user_id)
+    }
+
+    #[must_use]
+    pub fn guild_ban_optioned_path(guild_id: u64, user_id: u64, delete_message_days: u8) -> String {
+        format!("/guilds/{}/bans/{}?delete_message_days={}", guild_id, user_id, delete_message_days)
+    }
+
+    #[must_use]
+    pub fn guild_kick_optioned_path(guild_id: u64, user_id: u64) -> String {
+        format!("/guilds/{}/members/{}", guild_id, user_id)
+    }
+
+    #[must_use]
+    pub fn guild_bans_path(guild_id: u64) -> String {
+        format!("/guilds/{}/bans", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_channels_path(guild_id: u64) -> String {
+        format!("/guilds/{}/channels", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_widget_path(guild_id: u64) -> String {
+        format!("/guilds/{}/widget", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_preview_path(guild_id: u64) -> String {
+        format!("/guilds/{}/preview", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_emojis_path(guild_id: u64) -> String {
+        format!("/guilds/{}/emojis", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_emoji_path(guild_id: u64, emoji_id: u64) -> String {
+        format!("/guilds/{}/emojis/{}", guild_id, emoji_id)
+    }
+
+    #[must_use]
+    pub fn guild_integration_path(guild_id: u64, integration_id: u64) -> String {
+        format!("/guilds/{}/integrations/{}", guild_id, integration_id)
+    }
+
+    #[must_use]
+    pub fn guild_integration_sync_path(guild_id: u64, integration_id: u64) -> String {
+        format!("/guilds/{}/integrations/{}/sync", guild_id, integration_id)
+    }
+
+    #[must_use]
+    pub fn guild_integrations_path(guild_id: u64) -> String {
+        format!("/guilds/{}/integrations", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_invites_path(guild_id: u64) -> String {
+        format!("/guilds/{}/invites", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_member_path(guild_id: u64, user_id: u64) -> String {
+        format!("/guilds/{}/members/{}", guild_id, user_id)
+    }
+
+    #[must_use]
+    pub fn guild_member_role_path(guild_id: u64, user_id: u64, role_id: u64) -> String {
+        format!("/guilds/{}/members/{}/roles/{}", guild_id, user_id, role_id)
+    }
+
+    #[must_use]
+    pub fn guild_members_path(guild_id: u64) -> String {
+        format!("/guilds/{}/members", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_members_search_path(guild_id: u64, query: &str, limit: Option<u64>) -> String {
+        let mut s = format!("/guilds/{}/members/search?", guild_id);
+
+        write!(s, "&query={}&limit={}", query, limit.unwrap_or(constants::MEMBER_FETCH_LIMIT))
+            .unwrap();
+        s
+    }
+
+    #[must_use]
+    pub fn guild_members_optioned_path(
+        guild_id: u64,
+        after: Option<u64>,
+        limit: Option<u64>,
+    ) -> String {
+        let mut s = format!("/guilds/{}/members?", guild_id);
+
+        if let Some(after) = after {
+            write!(s, "&after={}", after).unwrap();
+        }
+
+        write!(s, "&limit={}", limit.unwrap_or(constants::MEMBER_FETCH_LIMIT)).unwrap();
+        s
+    }
+
+    #[must_use]
+    pub fn guild_member_me_path(guild_id: u64) -> String {
+        format!("/guilds/{}/members/@me", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_nickname_path(guild_id: u64) -> String {
+        format!("/guilds/{}/members/@me/nick", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_prune_path(guild_id: u64, days: u64) -> String {
+        format!("/guilds/{}/prune?days={}", guild_id, days)
+    }
+
+    #[must_use]
+    pub fn guild_regions_path(guild_id: u64) -> String {
+        format!("/guilds/{}/regions", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_role_path(guild_id: u64, role_id: u64) -> String {
+        format!("/guilds/{}/roles/{}", guild_id, role_id)
+    }
+
+    #[must_use]
+    pub fn guild_roles_path(guild_id: u64) -> String {
+        format!("/guilds/{}/roles", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_scheduled_event_path(
+        guild_id: u64,
+        event_id: u64,
+        with_user_count: Option<bool>,
+    ) -> String {
+        let mut s = format!("/guilds/{}/scheduled-events/{}", guild_id, event_id);
+        if let Some(b) = with_user_count {
+            write!(s, "?with_user_count={}", b).unwrap();
+        }
+        s
+    }
+
+    #[must_use]
+    pub fn guild_scheduled_events_path(guild_id: u64, with_user_count: Option<bool>) -> String {
+        let mut s = format!("/guilds/{}/scheduled-events", guild_id);
+        if let Some(b) = with_user_count {
+            write!(s, "?with_user_count={}", b).unwrap();
+        }
+        s
+    }
+
+    #[must_use]
+    pub fn guild_scheduled_event_users_path(
+        guild_id: u64,
+        event_id: u64,
+        after: Option<u64>,
+        before: Option<u64>,
+        limit: Option<u64>,
+        with_member: Option<bool>,
+    ) -> String {
+        let mut s = format!("/guilds/{}/scheduled-events/{}/users?", guild_id, event_id);
+
+        if let Some(limit) = limit {
+            write!(s, "&limit={}", limit).unwrap();
+        }
+
+        if let Some(after) = after {
+            write!(s, "&after={}", after).unwrap();
+        }
+
+        if let Some(before) = before {
+            write!(s, "&before={}", before).unwrap();
+        }
+
+        if let Some(with_member) = with_member {
+            write!(s, "&with_member={}", with_member).unwrap();
+        }
+
+        s
+    }
+
+    #[must_use]
+    pub fn guild_sticker_path(guild_id: u64, sticker_id: u64) -> String {
+        format!("/guilds/{}/stickers/{}", guild_id, sticker_id)
+    }
+
+    #[must_use]
+    pub fn guild_stickers_path(guild_id: u64) -> String {
+        format!("/guilds/{}/stickers", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_vanity_url_path(guild_id: u64) -> String {
+        format!("/guilds/{}/vanity-url", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_voice_states_path(guild_id: u64, user_id: u64) -> String {
+        format!("/guilds/{}/voice-states/{}", guild_id, user_id)
+    }
+
+    #[must_use]
+    pub fn guild_voice_states_me_path(guild_id: u64) -> String {
+        format!("/guilds/{}/voice-states/@me", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_webhooks_path(guild_id: u64) -> String {
+        format!("/guilds/{}/webhooks", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_welcome_screen_path(guild_id: u64) -> String {
+        format!("/guilds/{}/welcome-screen", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_threads_active_path(guild_id: u64) -> String {
+        format!("/guilds/{}/threads/active", guild_id)
+    }
+
+    #[must_use]
+    pub fn guilds_path() -> &'static str {
+        "/guilds"
+    }
+
+    #[must_use]
+    pub fn invite_path(code: &str) -> String {
+        format!("/invites/{}", code)
+    }
+
+    #[must_use]
+    pub fn invite_optioned_path(
+        code: &str,
+        member_counts: bool,
+        expiration: bool,
+        event_id: Option<u64>,
+    ) -> String {
+        format!(
+            "/invites/{}?with_counts={}&with_expiration={}{}",
+            code,
+            member_counts,
+            expiration,
+            event_id.map(|id| format!("&event_id={}", id)).unwrap_or_default(),
+        )
+    }
+
+    #[must_use]
+    pub fn oauth2_application_current_path() -> &'static str {
+        "/oauth2/applications/@me"
+    }
+
+    #[must_use]
+    pub fn private_channel_path() -> &'static str {
+        "/users/@me/channels"
+    }
+
+    #[must_use]
+    pub fn status_incidents_unresolved_path() -> &'static str {
+        "/incidents/unresolved.json"
+    }
+
+    #[must_use]
+    pub fn status_maintenances_active_path() -> &'static str {
+        "/scheduled-maintenances/active.json"
+    }
+
+    #[must_use]
+    pub fn status_maintenances_upcoming_path() -> &'static str {
+        "/scheduled-maintenances/upcoming.json"
+    }
+
+    #[must_use]
+    pub fn sticker_path(sticker_id: u64) -> String {
+        format!("/stickers/{}", sticker_id)
+    }
+
+    #[must_use]
+    pub fn sticker_packs_path() -> &'static str {
+        "/sticker-packs"
+    }
+
+    #[must_use]
+    pub fn user_path<D: Display>(target: D) -> String {
+        format!("/users/{}", target)
+    }
+
+    #[must_use]
+    pub fn user_me_connections_path() -> &'static str {
+        "/users/@me/connections"
+    }
+
+    #[must_use]
+    pub fn user_dm_channels_path<D: Display>(target: D) -> String {
+        format!("/users/{}/channels", target)
+    }
+
+    #[must_use]
+    pub fn user_guild_path<D: Display>(target: D, guild_id: u64) -> String {
+        format!("/users/{}/guilds/{}", target, guild_id)
+    }
+
+    #[must_use]
+    pub fn user_guilds_path<D: Display>(target: D) -> String {
+        format!("/users/{}/guilds", target)
+    }
+
+    #[must_use]
+    pub fn user_guilds_optioned_path<D: Display>(
+        target: D,
+        after: Option<u64>,
+        before: Option<u64>,
+        limit: Option<u64>,
+    ) -> String {
+        let mut s = format!("/users/{}/guilds?", target);
+
+        if let Some(limit) = limit {
+            write!(s, "&limit={}", limit).unwrap();
+        }
+
+        if let Some(after) = after {
+            write!(s, "&after={}", after).unwrap();
+        }
+
+        if let Some(before) = before {
+            write!(s, "&before={}", before).unwrap();
+        }
+
+        s
+    }
+
+    #[must_use]
+    pub fn voice_regions_path() -> &'static str {
+        "/voice/regions"
+    }
+
+    #[must_use]
+    pub fn webhook_path(webhook_id: u64) -> String {
+        format!("/webhooks/{}", webhook_id)
+    }
+
+    #[must_use]
+    pub fn webhook_with_token_path<D>(webhook_id: u64, token: D) -> String
+    where
+        D: Display,
+    {
+        format!("/webhooks/{}/{}", webhook_id, token)
+    }
+
+    #[must_use]
+    pub fn webhook_with_token_optioned_path<D>(webhook_id: u64, token: D, wait: bool) -> String
+    where
+        D: Display,
+    {
+        format!("/webhooks/{}/{}?wait={}", webhook_id, token, wait)
+    }
+
+    #[must_use]
+    pub fn webhook_message_path<D>(webhook_id: u64, token: D, message_id: u64) -> String
+    where
+        D: Display,
+    {
+        format!("/webhooks/{}/{}/messages/{}", webhook_id, token, message_id)
+    }
+
+    #[must_use]
+    pub fn webhook_original_interaction_response_path<D: Display>(
+        application_id: u64,
+        token: D,
+    ) -> String {
+        format!("/webhooks/{}/{}/messages/@original", application_id, token)
+    }
+
+    #[must_use]
+    pub fn webhook_followup_message_path<D: Display>(
+        application_id: u64,
+        token: D,
+        message_id: u64,
+    ) -> String {
+        format!("/webhooks/{}/{}/messages/{}", application_id, token, message_id)
+    }
+
+    #[must_use]
+    pub fn webhook_followup_messages_path<D: Display>(application_id: u64, token: D) -> String {
+        format!("/webhooks/{}/{}", application_id, token)
+    }
+
+    #[must_use]
+    pub fn interaction_response_path<D: Display>(application_id: u64, token: D) -> String {
+        format!("/interactions/{}/{}/callback", application_id, token)
+    }
+
+    #[must_use]
+    pub fn application_command_path(application_id: u64, command_id: u64) -> String {
+        format!("/applications/{}/commands/{}", application_id, command_id)
+    }
+
+    #[must_use]
+    pub fn application_commands_path(application_id: u64) -> String {
+        format!("/applications/{}/commands", application_id)
+    }
+
+    #[must_use]
+    pub fn application_guild_command_path(
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    ) -> String {
+        format!("/applications/{}/guilds/{}/commands/{}", application_id, guild_id, command_id)
+    }
+
+    #[must_use]
+    pub fn application_guild_command_permissions_path(
+        application_id: u64,
+        guild_id: u64,
+        command_id: u64,
+    ) -> String {
+        format!(
+            "/applications/{}/guilds/{}/commands/{}/permissions",
+            application_id, guild_id, command_id,
+        )
+    }
+
+    #[must_use]
+    pub fn application_guild_commands_path(application_id: u64, guild_id: u64) -> String {
+        format!("/applications/{}/guilds/{}/commands", application_id, guild_id)
+    }
+
+    #[must_use]
+    pub fn application_guild_commands_permissions_path(
+        application_id: u64,
+        guild_id: u64,
+    ) -> String {
+        format!("/applications/{}/guilds/{}/commands/permissions", application_id, guild_id)
+    }
+
+    #[must_use]
+    pub fn stage_instances_path() -> &'static str {
+        "/stage-instances"
+    }
+
+    #[must_use]
+    pub fn stage_instance_path(channel_id: u64) -> String {
+        format!("/stage-instances/{}", channel_id)
+    }
+}
+
+// The functions building full URLs on Discord's API, from before the base URLs
+// were configurable.
+impl Route {
+    #[deprecated(note = "use `Route::channel_path`")]
     #[must_use]
     pub fn channel(channel_id: u64) -> String {
-        api!("/channels/{}", channel_id)
+        api_url(&Self::channel_path(channel_id))
     }
 
+    #[deprecated(note = "use `Route::channel_invites_path`")]
     #[must_use]
     pub fn channel_invites(channel_id: u64) -> String {
-        api!("/channels/{}/invites", channel_id)
+        api_url(&Self::channel_invites_path(channel_id))
     }
 
+    #[deprecated(note = "use `Route::channel_message_path`")]
     #[must_use]
     pub fn channel_message(channel_id: u64, message_id: u64) -> String {
-        api!("/channels/{}/messages/{}", channel_id, message_id)
+        api_url(&Self::channel_message_path(channel_id, message_id))
     }
 
+    #[deprecated(note = "use `Route::channel_message_crosspost_path`")]
     #[must_use]
     pub fn channel_message_crosspost(channel_id: u64, message_id: u64) -> String {
-        api!("/channels/{}/messages/{}/crosspost", channel_id, message_id)
+        api_url(&Self::channel_message_crosspost_path(channel_id, message_id))
     }
 
+    #[deprecated(note = "use `Route::channel_message_reaction_path`")]
     #[must_use]
     pub fn channel_message_reaction<D, T>(
         channel_id: u64,
@@ -502,15 +1321,15 @@ impl Route {
         D: Display,
         T: Display,
     {
-        api!(
-            "/channels/{}/messages/{}/reactions/{}/{}",
+        api_url(&Self::channel_message_reaction_path(
             channel_id,
             message_id,
-            reaction_type,
             user_id,
-        )
+            reaction_type,
+        ))
     }
 
+    #[deprecated(note = "use `Route::channel_message_reaction_emoji_path`")]
     #[must_use]
     pub fn channel_message_reaction_emoji<T>(
         channel_id: u64,
@@ -520,14 +1339,16 @@ impl Route {
     where
         T: Display,
     {
-        api!("/channels/{}/messages/{}/reactions/{}", channel_id, message_id, reaction_type)
+        api_url(&Self::channel_message_reaction_emoji_path(channel_id, message_id, reaction_type))
     }
 
+    #[deprecated(note = "use `Route::channel_message_reactions_path`")]
     #[must_use]
     pub fn channel_message_reactions(channel_id: u64, message_id: u64) -> String {
-        api!("/channels/{}/messages/{}/reactions", channel_id, message_id)
+        api_url(&Self::channel_message_reactions_path(channel_id, message_id))
     }
 
+    #[deprecated(note = "use `Route::channel_message_reactions_list_path`")]
     #[must_use]
     pub fn channel_message_reactions_list(
         channel_id: u64,
@@ -536,163 +1357,144 @@ impl Route {
         limit: u8,
         after: Option<u64>,
     ) -> String {
-        let mut url = api!(
-            "/channels/{}/messages/{}/reactions/{}?limit={}",
-            channel_id,
-            message_id,
-            reaction,
-            limit,
-        );
-
-        if let Some(after) = after {
-            write!(url, "&after={}", after).unwrap();
-        }
-
-        url
+        api_url(&Self::channel_message_reactions_list_path(
+            channel_id, message_id, reaction, limit, after,
+        ))
     }
 
+    #[deprecated(note = "use `Route::channel_messages_path`")]
     #[must_use]
     pub fn channel_messages(channel_id: u64, query: Option<&str>) -> String {
-        api!("/channels/{}/messages{}", channel_id, query.unwrap_or(""))
+        api_url(&Self::channel_messages_path(channel_id, query))
     }
 
+    #[deprecated(note = "use `Route::channel_messages_bulk_delete_path`")]
     #[must_use]
     pub fn channel_messages_bulk_delete(channel_id: u64) -> String {
-        api!("/channels/{}/messages/bulk-delete", channel_id)
+        api_url(&Self::channel_messages_bulk_delete_path(channel_id))
     }
 
+    #[deprecated(note = "use `Route::channel_follow_news_path`")]
     #[must_use]
     pub fn channel_follow_news(channel_id: u64) -> String {
-        api!("/channels/{}/followers", channel_id)
+        api_url(&Self::channel_follow_news_path(channel_id))
     }
 
+    #[deprecated(note = "use `Route::channel_permission_path`")]
     #[must_use]
     pub fn channel_permission(channel_id: u64, target_id: u64) -> String {
-        api!("/channels/{}/permissions/{}", channel_id, target_id)
+        api_url(&Self::channel_permission_path(channel_id, target_id))
     }
 
+    #[deprecated(note = "use `Route::channel_pin_path`")]
     #[must_use]
     pub fn channel_pin(channel_id: u64, message_id: u64) -> String {
-        api!("/channels/{}/pins/{}", channel_id, message_id)
+        api_url(&Self::channel_pin_path(channel_id, message_id))
     }
 
+    #[deprecated(note = "use `Route::channel_pins_path`")]
     #[must_use]
     pub fn channel_pins(channel_id: u64) -> String {
-        api!("/channels/{}/pins", channel_id)
+        api_url(&Self::channel_pins_path(channel_id))
     }
 
+    #[deprecated(note = "use `Route::channel_typing_path`")]
     #[must_use]
     pub fn channel_typing(channel_id: u64) -> String {
-        api!("/channels/{}/typing", channel_id)
+        api_url(&Self::channel_typing_path(channel_id))
     }
 
+    #[deprecated(note = "use `Route::channel_webhooks_path`")]
     #[must_use]
     pub fn channel_webhooks(channel_id: u64) -> String {
-        api!("/channels/{}/webhooks", channel_id)
+        api_url(&Self::channel_webhooks_path(channel_id))
     }
 
+    #[deprecated(note = "use `Route::channel_public_threads_path`")]
     #[must_use]
     pub fn channel_public_threads(channel_id: u64, message_id: u64) -> String {
-        api!("/channels/{}/messages/{}/threads", channel_id, message_id)
+        api_url(&Self::channel_public_threads_path(channel_id, message_id))
     }
 
+    #[deprecated(note = "use `Route::channel_private_threads_path`")]
     #[must_use]
     pub fn channel_private_threads(channel_id: u64) -> String {
-        api!("/channels/{}/threads", channel_id)
+        api_url(&Self::channel_private_threads_path(channel_id))
     }
 
+    #[deprecated(note = "use `Route::channel_thread_member_path`")]
     #[must_use]
     pub fn channel_thread_member(channel_id: u64, user_id: u64) -> String {
-        api!("/channels/{}/thread-members/{}", channel_id, user_id)
+        api_url(&Self::channel_thread_member_path(channel_id, user_id))
     }
 
+    #[deprecated(note = "use `Route::channel_thread_member_me_path`")]
     #[must_use]
     pub fn channel_thread_member_me(channel_id: u64) -> String {
-        api!("/channels/{}/thread-members/@me", channel_id)
+        api_url(&Self::channel_thread_member_me_path(channel_id))
     }
 
+    #[deprecated(note = "use `Route::channel_thread_members_path`")]
     #[must_use]
     pub fn channel_thread_members(channel_id: u64) -> String {
-        api!("/channels/{}/thread-members", channel_id)
+        api_url(&Self::channel_thread_members_path(channel_id))
     }
 
+    #[deprecated(note = "use `Route::channel_archived_public_threads_path`")]
     #[must_use]
     pub fn channel_archived_public_threads(
         channel_id: u64,
         before: Option<Timestamp>,
         limit: Option<u64>,
     ) -> String {
-        let mut s = api!("/channels/{}/threads/archived/public", channel_id);
-
-        if let Some(id) = before {
-            write!(s, "?before={}", id).unwrap();
-        }
-
-        if let Some(limit) = limit {
-            write!(s, "&limit={}", limit).unwrap();
-        }
-
-        s
+        api_url(&Self::channel_archived_public_threads_path(channel_id, before, limit))
     }
 
+    #[deprecated(note = "use `Route::channel_archived_private_threads_path`")]
     #[must_use]
     pub fn channel_archived_private_threads(
         channel_id: u64,
         before: Option<Timestamp>,
         limit: Option<u64>,
     ) -> String {
-        let mut s = api!("/channels/{}/threads/archived/private", channel_id);
-
-        if let Some(id) = before {
-            write!(s, "&before={}", id).unwrap();
-        }
-
-        if let Some(limit) = limit {
-            write!(s, "&limit={}", limit).unwrap();
-        }
-
-        s
+        api_url(&Self::channel_archived_private_threads_path(channel_id, before, limit))
     }
 
+    #[deprecated(note = "use `Route::channel_joined_private_threads_path`")]
     #[must_use]
     pub fn channel_joined_private_threads(
         channel_id: u64,
         before: Option<Timestamp>,
         limit: Option<u64>,
     ) -> String {
-        let mut s = api!("/channels/{}/users/@me/threads/archived/private", channel_id);
-
-        if let Some(id) = before {
-            write!(s, "&before={}", id).unwrap();
-        }
-
-        if let Some(limit) = limit {
-            write!(s, "&limit={}", limit).unwrap();
-        }
-
-        s
+        api_url(&Self::channel_joined_private_threads_path(channel_id, before, limit))
     }
 
+    #[deprecated(note = "use `Route::gateway_path`")]
     #[must_use]
     pub fn gateway() -> &'static str {
         api!("/gateway")
     }
 
+    #[deprecated(note = "use `Route::gateway_bot_path`")]
     #[must_use]
     pub fn gateway_bot() -> &'static str {
         api!("/gateway/bot")
     }
 
+    #[deprecated(note = "use `Route::guild_path`")]
     #[must_use]
     pub fn guild(guild_id: u64) -> String {
-        api!("/guilds/{}", guild_id)
+        api_url(&Self::guild_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_with_counts_path`")]
     #[must_use]
     pub fn guild_with_counts(guild_id: u64) -> String {
-        api!("/guilds/{}?with_counts=true", guild_id)
+        api_url(&Self::guild_with_counts_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_audit_logs_path`")]
     #[must_use]
     pub fn guild_audit_logs(
         guild_id: u64,
@@ -701,190 +1503,182 @@ impl Route {
         before: Option<u64>,
         limit: Option<u8>,
     ) -> String {
-        let mut s = api!("/guilds/{}/audit-logs?", guild_id);
-
-        if let Some(action_type) = action_type {
-            write!(s, "&action_type={}", action_type).unwrap();
-        }
-
-        if let Some(before) = before {
-            write!(s, "&before={}", before).unwrap();
-        }
-
-        if let Some(limit) = limit {
-            write!(s, "&limit={}", limit).unwrap();
-        }
-
-        if let Some(user_id) = user_id {
-            write!(s, "&user_id={}", user_id).unwrap();
-        }
-
-        s
+        api_url(&Self::guild_audit_logs_path(guild_id, action_type, user_id, before, limit))
     }
 
+    #[deprecated(note = "use `Route::guild_automod_rule_path`")]
     #[must_use]
     pub fn guild_automod_rule(guild_id: u64, rule_id: u64) -> String {
-        api!("/guilds/{}/auto-moderation/rules/{}", guild_id, rule_id)
+        api_url(&Self::guild_automod_rule_path(guild_id, rule_id))
     }
 
+    #[deprecated(note = "use `Route::guild_automod_rules_path`")]
     #[must_use]
     pub fn guild_automod_rules(guild_id: u64) -> String {
-        api!("/guilds/{}/auto-moderation/rules", guild_id)
+        api_url(&Self::guild_automod_rules_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_ban_path`")]
     #[must_use]
     pub fn guild_ban(guild_id: u64, user_id: u64) -> String {
-        api!("/guilds/{}/bans/{}", guild_id, user_id)
+        api_url(&Self::guild_ban_path(guild_id, user_id))
     }
 
+    #[deprecated(note = "use `Route::guild_ban_optioned_path`")]
     #[must_use]
     pub fn guild_ban_optioned(guild_id: u64, user_id: u64, delete_message_days: u8) -> String {
-        api!("/guilds/{}/bans/{}?delete_message_days={}", guild_id, user_id, delete_message_days)
+        api_url(&Self::guild_ban_optioned_path(guild_id, user_id, delete_message_days))
     }
 
+    #[deprecated(note = "use `Route::guild_kick_optioned_path`")]
     #[must_use]
     pub fn guild_kick_optioned(guild_id: u64, user_id: u64) -> String {
-        api!("/guilds/{}/members/{}", guild_id, user_id)
+        api_url(&Self::guild_kick_optioned_path(guild_id, user_id))
     }
 
+    #[deprecated(note = "use `Route::guild_bans_path`")]
     #[must_use]
     pub fn guild_bans(guild_id: u64) -> String {
-        api!("/guilds/{}/bans", guild_id)
+        api_url(&Self::guild_bans_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_channels_path`")]
     #[must_use]
     pub fn guild_channels(guild_id: u64) -> String {
-        api!("/guilds/{}/channels", guild_id)
+        api_url(&Self::guild_channels_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_widget_path`")]
     #[must_use]
     pub fn guild_widget(guild_id: u64) -> String {
-        api!("/guilds/{}/widget", guild_id)
+        api_url(&Self::guild_widget_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_preview_path`")]
     #[must_use]
     pub fn guild_preview(guild_id: u64) -> String {
-        api!("/guilds/{}/preview", guild_id)
+        api_url(&Self::guild_preview_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_emojis_path`")]
     #[must_use]
     pub fn guild_emojis(guild_id: u64) -> String {
-        api!("/guilds/{}/emojis", guild_id)
+        api_url(&Self::guild_emojis_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_emoji_path`")]
     #[must_use]
     pub fn guild_emoji(guild_id: u64, emoji_id: u64) -> String {
-        api!("/guilds/{}/emojis/{}", guild_id, emoji_id)
+        api_url(&Self::guild_emoji_path(guild_id, emoji_id))
     }
 
+    #[deprecated(note = "use `Route::guild_integration_path`")]
     #[must_use]
     pub fn guild_integration(guild_id: u64, integration_id: u64) -> String {
-        api!("/guilds/{}/integrations/{}", guild_id, integration_id)
+        api_url(&Self::guild_integration_path(guild_id, integration_id))
     }
 
+    #[deprecated(note = "use `Route::guild_integration_sync_path`")]
     #[must_use]
     pub fn guild_integration_sync(guild_id: u64, integration_id: u64) -> String {
-        api!("/guilds/{}/integrations/{}/sync", guild_id, integration_id)
+        api_url(&Self::guild_integration_sync_path(guild_id, integration_id))
     }
 
+    #[deprecated(note = "use `Route::guild_integrations_path`")]
     #[must_use]
     pub fn guild_integrations(guild_id: u64) -> String {
-        api!("/guilds/{}/integrations", guild_id)
+        api_url(&Self::guild_integrations_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_invites_path`")]
     #[must_use]
     pub fn guild_invites(guild_id: u64) -> String {
-        api!("/guilds/{}/invites", guild_id)
+        api_url(&Self::guild_invites_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_member_path`")]
     #[must_use]
     pub fn guild_member(guild_id: u64, user_id: u64) -> String {
-        api!("/guilds/{}/members/{}", guild_id, user_id)
+        api_url(&Self::guild_member_path(guild_id, user_id))
     }
 
+    #[deprecated(note = "use `Route::guild_member_role_path`")]
     #[must_use]
     pub fn guild_member_role(guild_id: u64, user_id: u64, role_id: u64) -> String {
-        api!("/guilds/{}/members/{}/roles/{}", guild_id, user_id, role_id)
+        api_url(&Self::guild_member_role_path(guild_id, user_id, role_id))
     }
 
+    #[deprecated(note = "use `Route::guild_members_path`")]
     #[must_use]
     pub fn guild_members(guild_id: u64) -> String {
-        api!("/guilds/{}/members", guild_id)
+        api_url(&Self::guild_members_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_members_search_path`")]
     #[must_use]
     pub fn guild_members_search(guild_id: u64, query: &str, limit: Option<u64>) -> String {
-        let mut s = api!("/guilds/{}/members/search?", guild_id);
-
-        write!(s, "&query={}&limit={}", query, limit.unwrap_or(constants::MEMBER_FETCH_LIMIT))
-            .unwrap();
-        s
+        api_url(&Self::guild_members_search_path(guild_id, query, limit))
     }
 
+    #[deprecated(note = "use `Route::guild_members_optioned_path`")]
     #[must_use]
     pub fn guild_members_optioned(guild_id: u64, after: Option<u64>, limit: Option<u64>) -> String {
-        let mut s = api!("/guilds/{}/members?", guild_id);
-
-        if let Some(after) = after {
-            write!(s, "&after={}", after).unwrap();
-        }
-
-        write!(s, "&limit={}", limit.unwrap_or(constants::MEMBER_FETCH_LIMIT)).unwrap();
-        s
+        api_url(&Self::guild_members_optioned_path(guild_id, after, limit))
     }
 
+    #[deprecated(note = "use `Route::guild_member_me_path`")]
     #[must_use]
     pub fn guild_member_me(guild_id: u64) -> String {
-        api!("/guilds/{}/members/@me", guild_id)
+        api_url(&Self::guild_member_me_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_nickname_path`")]
     #[must_use]
     pub fn guild_nickname(guild_id: u64) -> String {
-        api!("/guilds/{}/members/@me/nick", guild_id)
+        api_url(&Self::guild_nickname_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_prune_path`")]
     #[must_use]
     pub fn guild_prune(guild_id: u64, days: u64) -> String {
-        api!("/guilds/{}/prune?days={}", guild_id, days)
+        api_url(&Self::guild_prune_path(guild_id, days))
     }
 
+    #[deprecated(note = "use `Route::guild_regions_path`")]
     #[must_use]
     pub fn guild_regions(guild_id: u64) -> String {
-        api!("/guilds/{}/regions", guild_id)
+        api_url(&Self::guild_regions_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_role_path`")]
     #[must_use]
     pub fn guild_role(guild_id: u64, role_id: u64) -> String {
-        api!("/guilds/{}/roles/{}", guild_id, role_id)
+        api_url(&Self::guild_role_path(guild_id, role_id))
     }
 
+    #[deprecated(note = "use `Route::guild_roles_path`")]
     #[must_use]
     pub fn guild_roles(guild_id: u64) -> String {
-        api!("/guilds/{}/roles", guild_id)
+        api_url(&Self::guild_roles_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_scheduled_event_path`")]
     #[must_use]
     pub fn guild_scheduled_event(
         guild_id: u64,
         event_id: u64,
         with_user_count: Option<bool>,
     ) -> String {
-        let mut s = api!("/guilds/{}/scheduled-events/{}", guild_id, event_id);
-        if let Some(b) = with_user_count {
-            write!(s, "?with_user_count={}", b).unwrap();
-        }
-        s
+        api_url(&Self::guild_scheduled_event_path(guild_id, event_id, with_user_count))
     }
 
+    #[deprecated(note = "use `Route::guild_scheduled_events_path`")]
     #[must_use]
     pub fn guild_scheduled_events(guild_id: u64, with_user_count: Option<bool>) -> String {
-        let mut s = api!("/guilds/{}/scheduled-events", guild_id);
-        if let Some(b) = with_user_count {
-            write!(s, "?with_user_count={}", b).unwrap();
-        }
-        s
+        api_url(&Self::guild_scheduled_events_path(guild_id, with_user_count))
     }
 
+    #[deprecated(note = "use `Route::guild_scheduled_event_users_path`")]
     #[must_use]
     pub fn guild_scheduled_event_users(
         guild_id: u64,
@@ -894,77 +1688,77 @@ impl Route {
         limit: Option<u64>,
         with_member: Option<bool>,
     ) -> String {
-        let mut s = api!("/guilds/{}/scheduled-events/{}/users?", guild_id, event_id);
-
-        if let Some(limit) = limit {
-            write!(s, "&limit={}", limit).unwrap();
-        }
-
-        if let Some(after) = after {
-            write!(s, "&after={}", after).unwrap();
-        }
-
-        if let Some(before) = before {
-            write!(s, "&before={}", before).unwrap();
-        }
-
-        if let Some(with_member) = with_member {
-            write!(s, "&with_member={}", with_member).unwrap();
-        }
-
-        s
+        api_url(&Self::guild_scheduled_event_users_path(
+            guild_id,
+            event_id,
+            after,
+            before,
+            limit,
+            with_member,
+        ))
     }
 
+    #[deprecated(note = "use `Route::guild_sticker_path`")]
     #[must_use]
     pub fn guild_sticker(guild_id: u64, sticker_id: u64) -> String {
-        api!("/guilds/{}/stickers/{}", guild_id, sticker_id)
+        api_url(&Self::guild_sticker_path(guild_id, sticker_id))
     }
 
+    #[deprecated(note = "use `Route::guild_stickers_path`")]
     #[must_use]
     pub fn guild_stickers(guild_id: u64) -> String {
-        api!("/guilds/{}/stickers", guild_id)
+        api_url(&Self::guild_stickers_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_vanity_url_path`")]
     #[must_use]
     pub fn guild_vanity_url(guild_id: u64) -> String {
-        api!("/guilds/{}/vanity-url", guild_id)
+        api_url(&Self::guild_vanity_url_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_voice_states_path`")]
     #[must_use]
     pub fn guild_voice_states(guild_id: u64, user_id: u64) -> String {
-        api!("/guilds/{}/voice-states/{}", guild_id, user_id)
+        api_url(&Self::guild_voice_states_path(guild_id, user_id))
     }
 
+    #[deprecated(note = "use `Route::guild_voice_states_me_path`")]
     #[must_use]
     pub fn guild_voice_states_me(guild_id: u64) -> String {
-        api!("/guilds/{}/voice-states/@me", guild_id)
+        api_url(&Self::guild_voice_states_me_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_webhooks_path`")]
     #[must_use]
     pub fn guild_webhooks(guild_id: u64) -> String {
-        api!("/guilds/{}/webhooks", guild_id)
+        api_url(&Self::guild_webhooks_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_welcome_screen_path`")]
     #[must_use]
     pub fn guild_welcome_screen(guild_id: u64) -> String {
-        api!("/guilds/{}/welcome-screen", guild_id)
+        api_url(&Self::guild_welcome_screen_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guild_threads_active_path`")]
     #[must_use]
     pub fn guild_threads_active(guild_id: u64) -> String {
-        api!("/guilds/{}/threads/active", guild_id)
+        api_url(&Self::guild_threads_active_path(guild_id))
     }
 
+    #[deprecated(note = "use `Route::guilds_path`")]
     #[must_use]
     pub fn guilds() -> &'static str {
         api!("/guilds")
     }
 
+    #[deprecated(note = "use `Route::invite_path`")]
     #[must_use]
     pub fn invite(code: &str) -> String {
-        api!("/invites/{}", code)
+        api_url(&Self::invite_path(code))
     }
 
+    #[deprecated(note = "use `Route::invite_optioned_path`")]
     #[must_use]
     pub fn invite_optioned(
         code: &str,
@@ -972,75 +1766,82 @@ impl Route {
         expiration: bool,
         event_id: Option<u64>,
     ) -> String {
-        api!(
-            "/invites/{}?with_counts={}&with_expiration={}{}",
-            code,
-            member_counts,
-            expiration,
-            event_id.map(|id| format!("&event_id={}", id)).unwrap_or_default(),
-        )
+        api_url(&Self::invite_optioned_path(code, member_counts, expiration, event_id))
     }
 
+    #[deprecated(note = "use `Route::oauth2_application_current_path`")]
     #[must_use]
     pub fn oauth2_application_current() -> &'static str {
         api!("/oauth2/applications/@me")
     }
 
+    #[deprecated(note = "use `Route::private_channel_path`")]
     #[must_use]
     pub fn private_channel() -> &'static str {
         api!("/users/@me/channels")
     }
 
+    #[deprecated(note = "use `Route::status_incidents_unresolved_path`")]
     #[must_use]
     pub fn status_incidents_unresolved() -> &'static str {
         status!("/incidents/unresolved.json")
     }
 
+    #[deprecated(note = "use `Route::status_maintenances_active_path`")]
     #[must_use]
     pub fn status_maintenances_active() -> &'static str {
         status!("/scheduled-maintenances/active.json")
     }
 
+    #[deprecated(note = "use `Route::status_maintenances_upcoming_path`")]
     #[must_use]
     pub fn status_maintenances_upcoming() -> &'static str {
         status!("/scheduled-maintenances/upcoming.json")
     }
 
+    #[deprecated(note = "use `Route::sticker_path`")]
     #[must_use]
     pub fn sticker(sticker_id: u64) -> String {
-        api!("/stickers/{}", sticker_id)
+        api_url(&Self::sticker_path(sticker_id))
     }
 
+    #[deprecated(note = "use `Route::sticker_packs_path`")]
     #[must_use]
     pub fn sticker_packs() -> &'static str {
         api!("/sticker-packs")
     }
 
+    #[deprecated(note = "use `Route::user_path`")]
     #[must_use]
     pub fn user<D: Display>(target: D) -> String {
-        api!("/users/{}", target)
+        api_url(&Self::user_path(target))
     }
 
+    #[deprecated(note = "use `Route::user_me_connections_path`")]
     #[must_use]
     pub fn user_me_connections() -> &'static str {
         api!("/users/@me/connections")
     }
 
+    #[deprecated(note = "use `Route::user_dm_channels_path`")]
     #[must_use]
     pub fn user_dm_channels<D: Display>(target: D) -> String {
-        api!("/users/{}/channels", target)
+        api_url(&Self::user_dm_channels_path(target))
     }
 
+    #[deprecated(note = "use `Route::user_guild_path`")]
     #[must_use]
     pub fn user_guild<D: Display>(target: D, guild_id: u64) -> String {
-        api!("/users/{}/guilds/{}", target, guild_id)
+        api_url(&Self::user_guild_path(target, guild_id))
     }
 
+    #[deprecated(note = "use `Route::user_guilds_path`")]
     #[must_use]
     pub fn user_guilds<D: Display>(target: D) -> String {
-        api!("/users/{}/guilds", target)
+        api_url(&Self::user_guilds_path(target))
     }
 
+    #[deprecated(note = "use `Route::user_guilds_optioned_path`")]
     #[must_use]
     pub fn user_guilds_optioned<D: Display>(
         target: D,
@@ -1048,135 +1849,191 @@ impl Route {
         before: Option<u64>,
         limit: Option<u64>,
     ) -> String {
-        let mut s = api!("/users/{}/guilds?", target);
-
-        if let Some(limit) = limit {
-            write!(s, "&limit={}", limit).unwrap();
-        }
-
-        if let Some(after) = after {
-            write!(s, "&after={}", after).unwrap();
-        }
-
-        if let Some(before) = before {
-            write!(s, "&before={}", before).unwrap();
-        }
-
-        s
+        api_url(&Self::user_guilds_optioned_path(target, after, before, limit))
     }
 
+    #[deprecated(note = "use `Route::voice_regions_path`")]
     #[must_use]
     pub fn voice_regions() -> &'static str {
         api!("/voice/regions")
     }
 
+    #[deprecated(note = "use `Route::webhook_path`")]
     #[must_use]
     pub fn webhook(webhook_id: u64) -> String {
-        api!("/webhooks/{}", webhook_id)
+        api_url(&Self::webhook_path(webhook_id))
     }
 
+    #[deprecated(note = "use `Route::webhook_with_token_path`")]
     #[must_use]
     pub fn webhook_with_token<D>(webhook_id: u64, token: D) -> String
     where
         D: Display,
     {
-        api!("/webhooks/{}/{}", webhook_id, token)
+        api_url(&Self::webhook_with_token_path(webhook_id, token))
     }
 
+    #[deprecated(note = "use `Route::webhook_with_token_optioned_path`")]
     #[must_use]
     pub fn webhook_with_token_optioned<D>(webhook_id: u64, token: D, wait: bool) -> String
     where
         D: Display,
     {
-        api!("/webhooks/{}/{}?wait={}", webhook_id, token, wait)
+        api_url(&Self::webhook_with_token_optioned_path(webhook_id, token, wait))
     }
 
+    #[deprecated(note = "use `Route::webhook_message_path`")]
     #[must_use]
     pub fn webhook_message<D>(webhook_id: u64, token: D, message_id: u64) -> String
     where
         D: Display,
     {
-        api!("/webhooks/{}/{}/messages/{}", webhook_id, token, message_id)
+        api_url(&Self::webhook_message_path(webhook_id, token, message_id))
     }
 
+    #[deprecated(note = "use `Route::webhook_original_interaction_response_path`")]
     #[must_use]
     pub fn webhook_original_interaction_response<D: Display>(
         application_id: u64,
         token: D,
     ) -> String {
-        api!("/webhooks/{}/{}/messages/@original", application_id, token)
+        api_url(&Self::webhook_original_interaction_response_path(application_id, token))
     }
 
+    #[deprecated(note = "use `Route::webhook_followup_message_path`")]
     #[must_use]
     pub fn webhook_followup_message<D: Display>(
         application_id: u64,
         token: D,
         message_id: u64,
     ) -> String {
-        api!("/webhooks/{}/{}/messages/{}", application_id, token, message_id)
+        api_url(&Self::webhook_followup_message_path(application_id, token, message_id))
     }
 
+    #[deprecated(note = "use `Route::webhook_followup_messages_path`")]
     #[must_use]
     pub fn webhook_followup_messages<D: Display>(application_id: u64, token: D) -> String {
-        api!("/webhooks/{}/{}", application_id, token)
+        api_url(&Self::webhook_followup_messages_path(application_id, token))
     }
 
+    #[deprecated(note = "use `Route::interaction_response_path`")]
     #[must_use]
     pub fn interaction_response<D: Display>(application_id: u64, token: D) -> String {
-        api!("/interactions/{}/{}/callback", application_id, token)
+        api_url(&Self::interaction_response_path(application_id, token))
     }
 
+    #[deprecated(note = "use `Route::application_command_path`")]
     #[must_use]
     pub fn application_command(application_id: u64, command_id: u64) -> String {
-        api!("/applications/{}/commands/{}", application_id, command_id)
+        api_url(&Self::application_command_path(application_id, command_id))
     }
 
+    #[deprecated(note = "use `Route::application_commands_path`")]
     #[must_use]
     pub fn application_commands(application_id: u64) -> String {
-        api!("/applications/{}/commands", application_id)
+        api_url(&Self::application_commands_path(application_id))
     }
 
+    #[deprecated(note = "use `Route::application_guild_command_path`")]
     #[must_use]
     pub fn application_guild_command(
         application_id: u64,
         guild_id: u64,
         command_id: u64,
     ) -> String {
-        api!("/applications/{}/guilds/{}/commands/{}", application_id, guild_id, command_id)
+        api_url(&Self::application_guild_command_path(application_id, guild_id, command_id))
     }
 
+    #[deprecated(note = "use `Route::application_guild_command_permissions_path`")]
     #[must_use]
     pub fn application_guild_command_permissions(
         application_id: u64,
         guild_id: u64,
         command_id: u64,
     ) -> String {
-        api!(
-            "/applications/{}/guilds/{}/commands/{}/permissions",
+        api_url(&Self::application_guild_command_permissions_path(
             application_id,
             guild_id,
             command_id,
-        )
+        ))
     }
 
+    #[deprecated(note = "use `Route::application_guild_commands_path`")]
     #[must_use]
     pub fn application_guild_commands(application_id: u64, guild_id: u64) -> String {
-        api!("/applications/{}/guilds/{}/commands", application_id, guild_id)
+        api_url(&Self::application_guild_commands_path(application_id, guild_id))
     }
 
+    #[deprecated(note = "use `Route::application_guild_commands_permissions_path`")]
     #[must_use]
     pub fn application_guild_commands_permissions(application_id: u64, guild_id: u64) -> String {
-        api!("/applications/{}/guilds/{}/commands/permissions", application_id, guild_id)
+        api_url(&Self::application_guild_commands_permissions_path(application_id, guild_id))
     }
 
+    #[deprecated(note = "use `Route::stage_instances_path`")]
     #[must_use]
     pub fn stage_instances() -> &'static str {
         api!("/stage-instances")
     }
 
+    #[deprecated(note = "use `Route::stage_instance_path`")]
     #[must_use]
     pub fn stage_instance(channel_id: u64) -> String {
-        api!("/stage-instances/{}", channel_id)
+        api_url(&Self::stage_instance_path(channel_id))
+    }
+}
+
+// The full URL of a path on Discord's API, at its default base URL.
+fn api_url(path: &str) -> String {
+    format!("{}{}", BaseUrls::default().api.as_str().trim_end_matches('/'), path)
+}
+
+/// The base URLs that the paths built by [`Route`] are relative to, along with
+/// the base URL of the CDN.
+///
+/// These default to Discord's own, and can be changed through
+/// [`HttpBuilder::api_base`], [`HttpBuilder::cdn_base`] and
+/// [`HttpBuilder::status_base`], e.g. to point the client at a local mock
+/// server.
+///
+/// [`HttpBuilder::api_base`]: super::HttpBuilder::api_base
+/// [`HttpBuilder::cdn_base`]: super::HttpBuilder::cdn_base
+/// [`HttpBuilder::status_base`]: super::HttpBuilder::status_base
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct BaseUrls {
+    /// The base URL of the REST API, including the API version.
+    pub api: Url,
+    /// The base URL of the CDN.
+    pub cdn: Url,
+    /// The base URL of the status page API.
+    pub status: Url,
+}
+
+impl BaseUrls {
+    /// Resolves a path, as built by [`Route`], against a base URL.
+    ///
+    /// Unlike [`Url::join`], this keeps the base URL's own path, so that the
+    /// path `/gateway` on the base `https://discord.com/api/v10` resolves to
+    /// `https://discord.com/api/v10/gateway`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HttpError::Url`] if the resulting URL is invalid.
+    pub fn join(base: &Url, path: &str) -> Result<Url> {
+        let base = base.as_str().trim_end_matches('/');
+
+        Ok(Url::parse(&format!("{}{}", base, path)).map_err(HttpError::Url)?)
+    }
+}
+
+impl Default for BaseUrls {
+    fn default() -> Self {
+        Self {
+            api: Url::parse(constants::API_URL).expect("Invalid API URL"),
+            cdn: Url::parse(constants::CDN_URL).expect("Invalid CDN URL"),
+            status: Url::parse(constants::STATUS_URL).expect("Invalid status URL"),
+        }
     }
 }
 
@@ -1763,6 +2620,53 @@ pub enum RouteInfo<'a> {
 }
 
 impl<'a> RouteInfo<'a> {
+    /// Returns the full URL of the route, resolved against the API base URL,
+    /// or the status page base URL for status page routes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HttpError::Url`] if the resulting URL is invalid.
+    pub fn url(&self, base_urls: &BaseUrls) -> Result<Url> {
+        let base = if self.is_status_page() { &base_urls.status } else { &base_urls.api };
+
+        BaseUrls::join(base, &self.deconstruct().2)
+    }
+
+    /// Whether the route is of the status page API rather than the REST API,
+    /// so is not subject to Discord's ratelimits.
+    #[must_use]
+    pub fn is_status_page(&self) -> bool {
+        matches!(
+            self,
+            RouteInfo::GetUnresolvedIncidents
+                | RouteInfo::StatusIncidentsUnresolved
+                | RouteInfo::GetActiveMaintenance
+                | RouteInfo::StatusMaintenancesActive
+                | RouteInfo::GetUpcomingMaintenances
+                | RouteInfo::StatusMaintenancesUpcoming
+        )
+    }
+
+    /// Whether the route is an interaction response or followup, so is exempt
+    /// from the global ratelimit.
+    ///
+    /// Routes authorized by a webhook token are not, as requests to them are
+    /// sent with the bot's token as well.
+    #[must_use]
+    pub fn is_global_exempt(&self) -> bool {
+        matches!(
+            self,
+            RouteInfo::CreateFollowupMessage { .. }
+                | RouteInfo::CreateInteractionResponse { .. }
+                | RouteInfo::DeleteFollowupMessage { .. }
+                | RouteInfo::DeleteOriginalInteractionResponse { .. }
+                | RouteInfo::EditFollowupMessage { .. }
+                | RouteInfo::GetOriginalInteractionResponse { .. }
+                | RouteInfo::EditOriginalInteractionResponse { .. }
+                | RouteInfo::GetFollowupMessage { .. }
+        )
+    }
+
     #[must_use]
     pub fn deconstruct(&self) -> (LightMethod, Route, Cow<'_, str>) {
         match *self {
@@ -1772,7 +2676,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Put,
                 Route::GuildsIdMembersId(guild_id),
-                Cow::from(Route::guild_member(guild_id, user_id)),
+                Cow::from(Route::guild_member_path(guild_id, user_id)),
             ),
             RouteInfo::AddMemberRole {
                 guild_id,
@@ -1781,7 +2685,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Put,
                 Route::GuildsIdMembersIdRolesId(guild_id),
-                Cow::from(Route::guild_member_role(guild_id, user_id, role_id)),
+                Cow::from(Route::guild_member_role_path(guild_id, user_id, role_id)),
             ),
             RouteInfo::GuildBanUser {
                 guild_id,
@@ -1791,7 +2695,7 @@ impl<'a> RouteInfo<'a> {
                 // TODO
                 LightMethod::Put,
                 Route::GuildsIdBansUserId(guild_id),
-                Cow::from(Route::guild_ban_optioned(
+                Cow::from(Route::guild_ban_optioned_path(
                     guild_id,
                     user_id,
                     delete_message_days.unwrap_or(0),
@@ -1802,24 +2706,24 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Post,
                 Route::ChannelsIdTyping(channel_id),
-                Cow::from(Route::channel_typing(channel_id)),
+                Cow::from(Route::channel_typing_path(channel_id)),
             ),
             RouteInfo::CreateAutoModRule {
                 guild_id,
             } => (
                 LightMethod::Post,
                 Route::GuildsIdAutoModRules(guild_id),
-                Cow::from(Route::guild_automod_rules(guild_id)),
+                Cow::from(Route::guild_automod_rules_path(guild_id)),
             ),
             RouteInfo::CreateChannel {
                 guild_id,
             } => (
                 LightMethod::Post,
                 Route::GuildsIdChannels(guild_id),
-                Cow::from(Route::guild_channels(guild_id)),
+                Cow::from(Route::guild_channels_path(guild_id)),
             ),
             RouteInfo::CreateStageInstance => {
-                (LightMethod::Post, Route::StageInstances, Cow::from(Route::stage_instances()))
+                (LightMethod::Post, Route::StageInstances, Cow::from(Route::stage_instances_path()))
             },
             RouteInfo::CreatePublicThread {
                 channel_id,
@@ -1827,21 +2731,21 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Post,
                 Route::ChannelsIdMessagesIdThreads(channel_id),
-                Cow::from(Route::channel_public_threads(channel_id, message_id)),
+                Cow::from(Route::channel_public_threads_path(channel_id, message_id)),
             ),
             RouteInfo::CreatePrivateThread {
                 channel_id,
             } => (
                 LightMethod::Post,
                 Route::ChannelsIdThreads(channel_id),
-                Cow::from(Route::channel_private_threads(channel_id)),
+                Cow::from(Route::channel_private_threads_path(channel_id)),
             ),
             RouteInfo::CreateEmoji {
                 guild_id,
             } => (
                 LightMethod::Post,
                 Route::GuildsIdEmojis(guild_id),
-                Cow::from(Route::guild_emojis(guild_id)),
+                Cow::from(Route::guild_emojis_path(guild_id)),
             ),
             RouteInfo::CreateFollowupMessage {
                 application_id,
@@ -1849,24 +2753,24 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Post,
                 Route::WebhooksId(application_id),
-                Cow::from(Route::webhook_followup_messages(application_id, interaction_token)),
+                Cow::from(Route::webhook_followup_messages_path(application_id, interaction_token)),
             ),
             RouteInfo::CreateGlobalApplicationCommand {
                 application_id,
             } => (
                 LightMethod::Post,
                 Route::ApplicationsIdCommands(application_id),
-                Cow::from(Route::application_commands(application_id)),
+                Cow::from(Route::application_commands_path(application_id)),
             ),
             RouteInfo::CreateGlobalApplicationCommands {
                 application_id,
             } => (
                 LightMethod::Put,
                 Route::ApplicationsIdCommands(application_id),
-                Cow::from(Route::application_commands(application_id)),
+                Cow::from(Route::application_commands_path(application_id)),
             ),
             RouteInfo::CreateGuild => {
-                (LightMethod::Post, Route::Guilds, Cow::from(Route::guilds()))
+                (LightMethod::Post, Route::Guilds, Cow::from(Route::guilds_path()))
             },
             RouteInfo::CreateGuildApplicationCommand {
                 application_id,
@@ -1874,7 +2778,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Post,
                 Route::ApplicationsIdGuildsIdCommands(application_id),
-                Cow::from(Route::application_guild_commands(application_id, guild_id)),
+                Cow::from(Route::application_guild_commands_path(application_id, guild_id)),
             ),
             RouteInfo::CreateGuildApplicationCommands {
                 application_id,
@@ -1882,7 +2786,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Put,
                 Route::ApplicationsIdGuildsIdCommands(application_id),
-                Cow::from(Route::application_guild_commands(application_id, guild_id)),
+                Cow::from(Route::application_guild_commands_path(application_id, guild_id)),
             ),
             RouteInfo::CreateGuildIntegration {
                 guild_id,
@@ -1890,7 +2794,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Post,
                 Route::GuildsIdIntegrationsId(guild_id),
-                Cow::from(Route::guild_integration(guild_id, integration_id)),
+                Cow::from(Route::guild_integration_path(guild_id, integration_id)),
             ),
             RouteInfo::CreateInteractionResponse {
                 interaction_id,
@@ -1898,21 +2802,21 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Post,
                 Route::InteractionsId(interaction_id),
-                Cow::from(Route::interaction_response(interaction_id, interaction_token)),
+                Cow::from(Route::interaction_response_path(interaction_id, interaction_token)),
             ),
             RouteInfo::CreateInvite {
                 channel_id,
             } => (
                 LightMethod::Post,
                 Route::ChannelsIdInvites(channel_id),
-                Cow::from(Route::channel_invites(channel_id)),
+                Cow::from(Route::channel_invites_path(channel_id)),
             ),
             RouteInfo::CreateMessage {
                 channel_id,
             } => (
                 LightMethod::Post,
                 Route::ChannelsIdMessages(channel_id),
-                Cow::from(Route::channel_messages(channel_id, None)),
+                Cow::from(Route::channel_messages_path(channel_id, None)),
             ),
             RouteInfo::CreatePermission {
                 channel_id,
@@ -1920,12 +2824,12 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Put,
                 Route::ChannelsIdPermissionsOverwriteId(channel_id),
-                Cow::from(Route::channel_permission(channel_id, target_id)),
+                Cow::from(Route::channel_permission_path(channel_id, target_id)),
             ),
             RouteInfo::CreatePrivateChannel => (
                 LightMethod::Post,
                 Route::UsersMeChannels,
-                Cow::from(Route::user_dm_channels("@me")),
+                Cow::from(Route::user_dm_channels_path("@me")),
             ),
             RouteInfo::CreateReaction {
                 channel_id,
@@ -1934,28 +2838,30 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Put,
                 Route::ChannelsIdMessagesIdReactionsUserIdType(channel_id),
-                Cow::from(Route::channel_message_reaction(channel_id, message_id, "@me", reaction)),
+                Cow::from(Route::channel_message_reaction_path(
+                    channel_id, message_id, "@me", reaction,
+                )),
             ),
             RouteInfo::CreateRole {
                 guild_id,
             } => (
                 LightMethod::Post,
                 Route::GuildsIdRoles(guild_id),
-                Cow::from(Route::guild_roles(guild_id)),
+                Cow::from(Route::guild_roles_path(guild_id)),
             ),
             RouteInfo::CreateScheduledEvent {
                 guild_id,
             } => (
                 LightMethod::Post,
                 Route::GuildsIdScheduledEvents(guild_id),
-                Cow::from(Route::guild_scheduled_events(guild_id, None)),
+                Cow::from(Route::guild_scheduled_events_path(guild_id, None)),
             ),
             RouteInfo::CreateSticker {
                 guild_id,
             } => (
                 LightMethod::Post,
                 Route::GuildsIdStickers(guild_id),
-                Cow::from(Route::guild_stickers(guild_id)),
+                Cow::from(Route::guild_stickers_path(guild_id)),
             ),
             RouteInfo::CrosspostMessage {
                 channel_id,
@@ -1963,14 +2869,14 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Post,
                 Route::ChannelsIdCrosspostsMessageId(channel_id),
-                Cow::from(Route::channel_message_crosspost(channel_id, message_id)),
+                Cow::from(Route::channel_message_crosspost_path(channel_id, message_id)),
             ),
             RouteInfo::CreateWebhook {
                 channel_id,
             } => (
                 LightMethod::Post,
                 Route::ChannelsIdWebhooks(channel_id),
-                Cow::from(Route::channel_webhooks(channel_id)),
+                Cow::from(Route::channel_webhooks_path(channel_id)),
             ),
             RouteInfo::DeleteAutoModRule {
                 guild_id,
@@ -1978,21 +2884,21 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::GuildsIdAutoModRulesId(guild_id),
-                Cow::from(Route::guild_automod_rule(guild_id, rule_id)),
+                Cow::from(Route::guild_automod_rule_path(guild_id, rule_id)),
             ),
             RouteInfo::DeleteChannel {
                 channel_id,
             } => (
                 LightMethod::Delete,
                 Route::ChannelsId(channel_id),
-                Cow::from(Route::channel(channel_id)),
+                Cow::from(Route::channel_path(channel_id)),
             ),
             RouteInfo::DeleteStageInstance {
                 channel_id,
             } => (
                 LightMethod::Delete,
                 Route::StageInstancesChannelId(channel_id),
-                Cow::from(Route::stage_instance(channel_id)),
+                Cow::from(Route::stage_instance_path(channel_id)),
             ),
             RouteInfo::DeleteEmoji {
                 emoji_id,
@@ -2000,7 +2906,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::GuildsIdEmojisId(guild_id),
-                Cow::from(Route::guild_emoji(guild_id, emoji_id)),
+                Cow::from(Route::guild_emoji_path(guild_id, emoji_id)),
             ),
             RouteInfo::DeleteFollowupMessage {
                 application_id,
@@ -2009,7 +2915,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::WebhooksApplicationId(application_id),
-                Cow::from(Route::webhook_followup_message(
+                Cow::from(Route::webhook_followup_message_path(
                     application_id,
                     interaction_token,
                     message_id,
@@ -2021,13 +2927,15 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::ApplicationsIdCommandsId(application_id),
-                Cow::from(Route::application_command(application_id, command_id)),
+                Cow::from(Route::application_command_path(application_id, command_id)),
             ),
             RouteInfo::DeleteGuild {
                 guild_id,
-            } => {
-                (LightMethod::Delete, Route::GuildsId(guild_id), Cow::from(Route::guild(guild_id)))
-            },
+            } => (
+                LightMethod::Delete,
+                Route::GuildsId(guild_id),
+                Cow::from(Route::guild_path(guild_id)),
+            ),
             RouteInfo::DeleteGuildApplicationCommand {
                 application_id,
                 guild_id,
@@ -2035,7 +2943,11 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::ApplicationsIdGuildsIdCommandsId(application_id),
-                Cow::from(Route::application_guild_command(application_id, guild_id, command_id)),
+                Cow::from(Route::application_guild_command_path(
+                    application_id,
+                    guild_id,
+                    command_id,
+                )),
             ),
             RouteInfo::DeleteGuildIntegration {
                 guild_id,
@@ -2043,18 +2955,18 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::GuildsIdIntegrationsId(guild_id),
-                Cow::from(Route::guild_integration(guild_id, integration_id)),
+                Cow::from(Route::guild_integration_path(guild_id, integration_id)),
             ),
             RouteInfo::DeleteInvite {
                 code,
-            } => (LightMethod::Delete, Route::InvitesCode, Cow::from(Route::invite(code))),
+            } => (LightMethod::Delete, Route::InvitesCode, Cow::from(Route::invite_path(code))),
             RouteInfo::DeleteMessageReactions {
                 channel_id,
                 message_id,
             } => (
                 LightMethod::Delete,
                 Route::ChannelsIdMessagesIdReactions(channel_id),
-                Cow::from(Route::channel_message_reactions(channel_id, message_id)),
+                Cow::from(Route::channel_message_reactions_path(channel_id, message_id)),
             ),
             RouteInfo::DeleteMessageReactionEmoji {
                 channel_id,
@@ -2063,7 +2975,9 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::ChannelsIdMessagesIdReactions(channel_id),
-                Cow::from(Route::channel_message_reaction_emoji(channel_id, message_id, reaction)),
+                Cow::from(Route::channel_message_reaction_emoji_path(
+                    channel_id, message_id, reaction,
+                )),
             ),
             RouteInfo::DeleteMessage {
                 channel_id,
@@ -2071,14 +2985,14 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::ChannelsIdMessagesId(LightMethod::Delete, message_id),
-                Cow::from(Route::channel_message(channel_id, message_id)),
+                Cow::from(Route::channel_message_path(channel_id, message_id)),
             ),
             RouteInfo::DeleteMessages {
                 channel_id,
             } => (
                 LightMethod::Post,
                 Route::ChannelsIdMessagesBulkDelete(channel_id),
-                Cow::from(Route::channel_messages_bulk_delete(channel_id)),
+                Cow::from(Route::channel_messages_bulk_delete_path(channel_id)),
             ),
             RouteInfo::DeleteOriginalInteractionResponse {
                 application_id,
@@ -2086,7 +3000,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::WebhooksApplicationId(application_id),
-                Cow::from(Route::webhook_original_interaction_response(
+                Cow::from(Route::webhook_original_interaction_response_path(
                     application_id,
                     interaction_token,
                 )),
@@ -2097,7 +3011,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::ChannelsIdPermissionsOverwriteId(channel_id),
-                Cow::from(Route::channel_permission(channel_id, target_id)),
+                Cow::from(Route::channel_permission_path(channel_id, target_id)),
             ),
             RouteInfo::DeleteReaction {
                 channel_id,
@@ -2107,7 +3021,9 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::ChannelsIdMessagesIdReactionsUserIdType(channel_id),
-                Cow::from(Route::channel_message_reaction(channel_id, message_id, user, reaction)),
+                Cow::from(Route::channel_message_reaction_path(
+                    channel_id, message_id, user, reaction,
+                )),
             ),
             RouteInfo::DeleteRole {
                 guild_id,
@@ -2115,7 +3031,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::GuildsIdRolesId(guild_id),
-                Cow::from(Route::guild_role(guild_id, role_id)),
+                Cow::from(Route::guild_role_path(guild_id, role_id)),
             ),
             RouteInfo::DeleteScheduledEvent {
                 guild_id,
@@ -2123,7 +3039,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::GuildsIdScheduledEventsId(guild_id),
-                Cow::from(Route::guild_scheduled_event(guild_id, event_id, None)),
+                Cow::from(Route::guild_scheduled_event_path(guild_id, event_id, None)),
             ),
             RouteInfo::DeleteSticker {
                 guild_id,
@@ -2131,14 +3047,14 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::GuildsIdStickersId(guild_id),
-                Cow::from(Route::guild_sticker(guild_id, sticker_id)),
+                Cow::from(Route::guild_sticker_path(guild_id, sticker_id)),
             ),
             RouteInfo::DeleteWebhook {
                 webhook_id,
             } => (
                 LightMethod::Delete,
                 Route::WebhooksId(webhook_id),
-                Cow::from(Route::webhook(webhook_id)),
+                Cow::from(Route::webhook_path(webhook_id)),
             ),
             RouteInfo::DeleteWebhookWithToken {
                 token,
@@ -2146,7 +3062,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::WebhooksId(webhook_id),
-                Cow::from(Route::webhook_with_token(webhook_id, token)),
+                Cow::from(Route::webhook_with_token_path(webhook_id, token)),
             ),
             RouteInfo::DeleteWebhookMessage {
                 token,
@@ -2155,7 +3071,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::WebhooksIdMessagesId(webhook_id),
-                Cow::from(Route::webhook_message(webhook_id, token, message_id)),
+                Cow::from(Route::webhook_message_path(webhook_id, token, message_id)),
             ),
             RouteInfo::EditAutoModRule {
                 guild_id,
@@ -2163,7 +3079,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Patch,
                 Route::GuildsIdAutoModRulesId(guild_id),
-                Cow::from(Route::guild_automod_rule(guild_id, rule_id)),
+                Cow::from(Route::guild_automod_rule_path(guild_id, rule_id)),
             ),
             RouteInfo::EditChannel {
                 channel_id,
@@ -2173,7 +3089,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Patch,
                 Route::ChannelsId(channel_id),
-                Cow::from(Route::channel(channel_id)),
+                Cow::from(Route::channel_path(channel_id)),
             ),
             RouteInfo::EditScheduledEvent {
                 guild_id,
@@ -2181,14 +3097,14 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Patch,
                 Route::GuildsIdScheduledEventsId(guild_id),
-                Cow::from(Route::guild_scheduled_event(guild_id, event_id, None)),
+                Cow::from(Route::guild_scheduled_event_path(guild_id, event_id, None)),
             ),
             RouteInfo::EditStageInstance {
                 channel_id,
             } => (
                 LightMethod::Patch,
                 Route::StageInstancesChannelId(channel_id),
-                Cow::from(Route::stage_instance(channel_id)),
+                Cow::from(Route::stage_instance_path(channel_id)),
             ),
             RouteInfo::EditEmoji {
                 emoji_id,
@@ -2196,7 +3112,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Patch,
                 Route::GuildsIdEmojisId(guild_id),
-                Cow::from(Route::guild_emoji(guild_id, emoji_id)),
+                Cow::from(Route::guild_emoji_path(guild_id, emoji_id)),
             ),
             RouteInfo::EditFollowupMessage {
                 application_id,
@@ -2205,7 +3121,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Patch,
                 Route::WebhooksApplicationId(application_id),
-                Cow::from(Route::webhook_followup_message(
+                Cow::from(Route::webhook_followup_message_path(
                     application_id,
                     interaction_token,
                     message_id,
@@ -2217,11 +3133,15 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Patch,
                 Route::ApplicationsIdCommandsId(application_id),
-                Cow::from(Route::application_command(application_id, command_id)),
+                Cow::from(Route::application_command_path(application_id, command_id)),
             ),
             RouteInfo::EditGuild {
                 guild_id,
-            } => (LightMethod::Patch, Route::GuildsId(guild_id), Cow::from(Route::guild(guild_id))),
+            } => (
+                LightMethod::Patch,
+                Route::GuildsId(guild_id),
+                Cow::from(Route::guild_path(guild_id)),
+            ),
             RouteInfo::EditGuildApplicationCommand {
                 application_id,
                 guild_id,
@@ -2229,7 +3149,11 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Patch,
                 Route::ApplicationsIdGuildsIdCommandsId(application_id),
-                Cow::from(Route::application_guild_command(application_id, guild_id, command_id)),
+                Cow::from(Route::application_guild_command_path(
+                    application_id,
+                    guild_id,
+                    command_id,
+                )),
             ),
             RouteInfo::EditGuildApplicationCommandPermission {
                 application_id,
@@ -2238,7 +3162,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Put,
                 Route::ApplicationsIdGuildsIdCommandIdPermissions(application_id),
-                Cow::from(Route::application_guild_command_permissions(
+                Cow::from(Route::application_guild_command_permissions_path(
                     application_id,
                     guild_id,
                     command_id,
@@ -2250,28 +3174,31 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Put,
                 Route::ApplicationsIdGuildsIdCommandsPermissions(application_id),
-                Cow::from(Route::application_guild_commands_permissions(application_id, guild_id)),
+                Cow::from(Route::application_guild_commands_permissions_path(
+                    application_id,
+                    guild_id,
+                )),
             ),
             RouteInfo::EditGuildChannels {
                 guild_id,
             } => (
                 LightMethod::Patch,
                 Route::GuildsIdChannels(guild_id),
-                Cow::from(Route::guild_channels(guild_id)),
+                Cow::from(Route::guild_channels_path(guild_id)),
             ),
             RouteInfo::EditGuildWidget {
                 guild_id,
             } => (
                 LightMethod::Patch,
                 Route::GuildsIdWidget(guild_id),
-                Cow::from(Route::guild_widget(guild_id)),
+                Cow::from(Route::guild_widget_path(guild_id)),
             ),
             RouteInfo::EditGuildWelcomeScreen {
                 guild_id,
             } => (
                 LightMethod::Patch,
                 Route::GuildsIdWelcomeScreen(guild_id),
-                Cow::from(Route::guild_welcome_screen(guild_id)),
+                Cow::from(Route::guild_welcome_screen_path(guild_id)),
             ),
             RouteInfo::EditMember {
                 guild_id,
@@ -2279,7 +3206,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Patch,
                 Route::GuildsIdMembersId(guild_id),
-                Cow::from(Route::guild_member(guild_id, user_id)),
+                Cow::from(Route::guild_member_path(guild_id, user_id)),
             ),
             RouteInfo::EditMessage {
                 channel_id,
@@ -2287,21 +3214,21 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Patch,
                 Route::ChannelsIdMessagesId(LightMethod::Patch, channel_id),
-                Cow::from(Route::channel_message(channel_id, message_id)),
+                Cow::from(Route::channel_message_path(channel_id, message_id)),
             ),
             RouteInfo::EditMemberMe {
                 guild_id,
             } => (
                 LightMethod::Patch,
                 Route::GuildsIdMembersMe(guild_id),
-                Cow::from(Route::guild_member_me(guild_id)),
+                Cow::from(Route::guild_member_me_path(guild_id)),
             ),
             RouteInfo::EditNickname {
                 guild_id,
             } => (
                 LightMethod::Patch,
                 Route::GuildsIdMembersMeNick(guild_id),
-                Cow::from(Route::guild_nickname(guild_id)),
+                Cow::from(Route::guild_nickname_path(guild_id)),
             ),
             RouteInfo::GetOriginalInteractionResponse {
                 application_id,
@@ -2309,7 +3236,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::WebhooksApplicationId(application_id),
-                Cow::from(Route::webhook_original_interaction_response(
+                Cow::from(Route::webhook_original_interaction_response_path(
                     application_id,
                     interaction_token,
                 )),
@@ -2320,13 +3247,13 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Patch,
                 Route::WebhooksApplicationId(application_id),
-                Cow::from(Route::webhook_original_interaction_response(
+                Cow::from(Route::webhook_original_interaction_response_path(
                     application_id,
                     interaction_token,
                 )),
             ),
             RouteInfo::EditProfile => {
-                (LightMethod::Patch, Route::UsersMe, Cow::from(Route::user("@me")))
+                (LightMethod::Patch, Route::UsersMe, Cow::from(Route::user_path("@me")))
             },
             RouteInfo::EditRole {
                 guild_id,
@@ -2334,14 +3261,14 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Patch,
                 Route::GuildsIdRolesId(guild_id),
-                Cow::from(Route::guild_role(guild_id, role_id)),
+                Cow::from(Route::guild_role_path(guild_id, role_id)),
             ),
             RouteInfo::EditRolePosition {
                 guild_id,
             } => (
                 LightMethod::Patch,
                 Route::GuildsIdRolesId(guild_id),
-                Cow::from(Route::guild_roles(guild_id)),
+                Cow::from(Route::guild_roles_path(guild_id)),
             ),
             RouteInfo::EditSticker {
                 guild_id,
@@ -2349,7 +3276,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Patch,
                 Route::GuildsIdStickersId(guild_id),
-                Cow::from(Route::guild_sticker(guild_id, sticker_id)),
+                Cow::from(Route::guild_sticker_path(guild_id, sticker_id)),
             ),
             RouteInfo::EditVoiceState {
                 guild_id,
@@ -2357,21 +3284,21 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Patch,
                 Route::GuildsIdVoiceStates(guild_id),
-                Cow::from(Route::guild_voice_states(guild_id, user_id)),
+                Cow::from(Route::guild_voice_states_path(guild_id, user_id)),
             ),
             RouteInfo::EditVoiceStateMe {
                 guild_id,
             } => (
                 LightMethod::Patch,
                 Route::GuildsIdVoiceStatesMe(guild_id),
-                Cow::from(Route::guild_voice_states_me(guild_id)),
+                Cow::from(Route::guild_voice_states_me_path(guild_id)),
             ),
             RouteInfo::EditWebhook {
                 webhook_id,
             } => (
                 LightMethod::Patch,
                 Route::WebhooksId(webhook_id),
-                Cow::from(Route::webhook(webhook_id)),
+                Cow::from(Route::webhook_path(webhook_id)),
             ),
             RouteInfo::EditWebhookWithToken {
                 token,
@@ -2379,7 +3306,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Patch,
                 Route::WebhooksId(webhook_id),
-                Cow::from(Route::webhook_with_token(webhook_id, token)),
+                Cow::from(Route::webhook_with_token_path(webhook_id, token)),
             ),
             RouteInfo::GetWebhookMessage {
                 token,
@@ -2388,7 +3315,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::WebhooksIdMessagesId(webhook_id),
-                Cow::from(Route::webhook_message(webhook_id, token, message_id)),
+                Cow::from(Route::webhook_message_path(webhook_id, token, message_id)),
             ),
             RouteInfo::EditWebhookMessage {
                 token,
@@ -2397,7 +3324,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Patch,
                 Route::WebhooksIdMessagesId(webhook_id),
-                Cow::from(Route::webhook_message(webhook_id, token, message_id)),
+                Cow::from(Route::webhook_message_path(webhook_id, token, message_id)),
             ),
             RouteInfo::ExecuteWebhook {
                 token,
@@ -2406,14 +3333,14 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Post,
                 Route::WebhooksId(webhook_id),
-                Cow::from(Route::webhook_with_token_optioned(webhook_id, token, wait)),
+                Cow::from(Route::webhook_with_token_optioned_path(webhook_id, token, wait)),
             ),
             RouteInfo::FollowNewsChannel {
                 channel_id,
             } => (
                 LightMethod::Post,
                 Route::FollowNewsChannel(channel_id),
-                Cow::from(Route::channel_follow_news(channel_id)),
+                Cow::from(Route::channel_follow_news_path(channel_id)),
             ),
             RouteInfo::GetAuditLogs {
                 action_type,
@@ -2424,14 +3351,20 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::GuildsIdAuditLogs(guild_id),
-                Cow::from(Route::guild_audit_logs(guild_id, action_type, user_id, before, limit)),
+                Cow::from(Route::guild_audit_logs_path(
+                    guild_id,
+                    action_type,
+                    user_id,
+                    before,
+                    limit,
+                )),
             ),
             RouteInfo::GetAutoModRules {
                 guild_id,
             } => (
                 LightMethod::Get,
                 Route::GuildsIdAutoModRules(guild_id),
-                Cow::from(Route::guild_automod_rules(guild_id)),
+                Cow::from(Route::guild_automod_rules_path(guild_id)),
             ),
             RouteInfo::GetAutoModRule {
                 guild_id,
@@ -2439,59 +3372,59 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::GuildsIdAutoModRulesId(guild_id),
-                Cow::from(Route::guild_automod_rule(guild_id, rule_id)),
+                Cow::from(Route::guild_automod_rule_path(guild_id, rule_id)),
             ),
             RouteInfo::GetBans {
                 guild_id,
             } => (
                 LightMethod::Get,
                 Route::GuildsIdBans(guild_id),
-                Cow::from(Route::guild_bans(guild_id)),
+                Cow::from(Route::guild_bans_path(guild_id)),
             ),
             RouteInfo::GetBotGateway => {
-                (LightMethod::Get, Route::GatewayBot, Cow::from(Route::gateway_bot()))
+                (LightMethod::Get, Route::GatewayBot, Cow::from(Route::gateway_bot_path()))
             },
             RouteInfo::GetChannel {
                 channel_id,
             } => (
                 LightMethod::Get,
                 Route::ChannelsId(channel_id),
-                Cow::from(Route::channel(channel_id)),
+                Cow::from(Route::channel_path(channel_id)),
             ),
             RouteInfo::GetStageInstance {
                 channel_id,
             } => (
                 LightMethod::Get,
                 Route::StageInstancesChannelId(channel_id),
-                Cow::from(Route::stage_instance(channel_id)),
+                Cow::from(Route::stage_instance_path(channel_id)),
             ),
             RouteInfo::GetChannelInvites {
                 channel_id,
             } => (
                 LightMethod::Get,
                 Route::ChannelsIdInvites(channel_id),
-                Cow::from(Route::channel_invites(channel_id)),
+                Cow::from(Route::channel_invites_path(channel_id)),
             ),
             RouteInfo::GetChannelWebhooks {
                 channel_id,
             } => (
                 LightMethod::Get,
                 Route::ChannelsIdWebhooks(channel_id),
-                Cow::from(Route::channel_webhooks(channel_id)),
+                Cow::from(Route::channel_webhooks_path(channel_id)),
             ),
             RouteInfo::GetChannels {
                 guild_id,
             } => (
                 LightMethod::Get,
                 Route::GuildsIdChannels(guild_id),
-                Cow::from(Route::guild_channels(guild_id)),
+                Cow::from(Route::guild_channels_path(guild_id)),
             ),
             RouteInfo::GetChannelThreadMembers {
                 channel_id,
             } => (
                 LightMethod::Get,
                 Route::ChannelsIdThreadMembers(channel_id),
-                Cow::from(Route::channel_thread_members(channel_id)),
+                Cow::from(Route::channel_thread_members_path(channel_id)),
             ),
             RouteInfo::GetChannelArchivedPublicThreads {
                 channel_id,
@@ -2500,7 +3433,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::ChannelsIdArchivedPublicThreads(channel_id),
-                Cow::from(Route::channel_archived_public_threads(channel_id, before, limit)),
+                Cow::from(Route::channel_archived_public_threads_path(channel_id, before, limit)),
             ),
             RouteInfo::GetChannelArchivedPrivateThreads {
                 channel_id,
@@ -2509,7 +3442,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::ChannelsIdArchivedPrivateThreads(channel_id),
-                Cow::from(Route::channel_archived_private_threads(channel_id, before, limit)),
+                Cow::from(Route::channel_archived_private_threads_path(channel_id, before, limit)),
             ),
             RouteInfo::GetChannelJoinedPrivateArchivedThreads {
                 channel_id,
@@ -2518,7 +3451,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::ChannelsIdMeJoindedArchivedPrivateThreads(channel_id),
-                Cow::from(Route::channel_joined_private_threads(channel_id, before, limit)),
+                Cow::from(Route::channel_joined_private_threads_path(channel_id, before, limit)),
             ),
             RouteInfo::GetFollowupMessage {
                 application_id,
@@ -2527,7 +3460,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::WebhooksApplicationId(application_id),
-                Cow::from(Route::webhook_followup_message(
+                Cow::from(Route::webhook_followup_message_path(
                     application_id,
                     interaction_token,
                     message_id,
@@ -2538,21 +3471,21 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::GuildsIdThreadsActive,
-                Cow::from(Route::guild_threads_active(guild_id)),
+                Cow::from(Route::guild_threads_active_path(guild_id)),
             ),
             RouteInfo::JoinThread {
                 channel_id,
             } => (
                 LightMethod::Put,
                 Route::ChannelsIdThreadMembersMe(channel_id),
-                Cow::from(Route::channel_thread_member_me(channel_id)),
+                Cow::from(Route::channel_thread_member_me_path(channel_id)),
             ),
             RouteInfo::LeaveThread {
                 channel_id,
             } => (
                 LightMethod::Delete,
                 Route::ChannelsIdThreadMembersMe(channel_id),
-                Cow::from(Route::channel_thread_member_me(channel_id)),
+                Cow::from(Route::channel_thread_member_me_path(channel_id)),
             ),
             RouteInfo::AddThreadMember {
                 channel_id,
@@ -2560,7 +3493,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Put,
                 Route::ChannelsIdThreadMembersUserId(channel_id),
-                Cow::from(Route::channel_thread_member(channel_id, user_id)),
+                Cow::from(Route::channel_thread_member_path(channel_id, user_id)),
             ),
             RouteInfo::RemoveThreadMember {
                 channel_id,
@@ -2568,20 +3501,20 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::ChannelsIdThreadMembersUserId(channel_id),
-                Cow::from(Route::channel_thread_member(channel_id, user_id)),
+                Cow::from(Route::channel_thread_member_path(channel_id, user_id)),
             ),
             RouteInfo::GetCurrentApplicationInfo => {
-                (LightMethod::Get, Route::None, Cow::from(Route::oauth2_application_current()))
+                (LightMethod::Get, Route::None, Cow::from(Route::oauth2_application_current_path()))
             },
             RouteInfo::GetCurrentUser => {
-                (LightMethod::Get, Route::UsersMe, Cow::from(Route::user("@me")))
+                (LightMethod::Get, Route::UsersMe, Cow::from(Route::user_path("@me")))
             },
             RouteInfo::GetEmojis {
                 guild_id,
             } => (
                 LightMethod::Get,
                 Route::GuildsIdEmojis(guild_id),
-                Cow::from(Route::guild_emojis(guild_id)),
+                Cow::from(Route::guild_emojis_path(guild_id)),
             ),
             RouteInfo::GetEmoji {
                 guild_id,
@@ -2589,17 +3522,17 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::GuildsIdEmojisId(guild_id),
-                Cow::from(Route::guild_emoji(guild_id, emoji_id)),
+                Cow::from(Route::guild_emoji_path(guild_id, emoji_id)),
             ),
             RouteInfo::GetGateway => {
-                (LightMethod::Get, Route::Gateway, Cow::from(Route::gateway()))
+                (LightMethod::Get, Route::Gateway, Cow::from(Route::gateway_path()))
             },
             RouteInfo::GetGlobalApplicationCommands {
                 application_id,
             } => (
                 LightMethod::Get,
                 Route::ApplicationsIdCommands(application_id),
-                Cow::from(Route::application_commands(application_id)),
+                Cow::from(Route::application_commands_path(application_id)),
             ),
             RouteInfo::GetGlobalApplicationCommand {
                 application_id,
@@ -2607,17 +3540,21 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::ApplicationsIdCommandsId(application_id),
-                Cow::from(Route::application_command(application_id, command_id)),
+                Cow::from(Route::application_command_path(application_id, command_id)),
             ),
             RouteInfo::GetGuild {
                 guild_id,
-            } => (LightMethod::Get, Route::GuildsId(guild_id), Cow::from(Route::guild(guild_id))),
+            } => (
+                LightMethod::Get,
+                Route::GuildsId(guild_id),
+                Cow::from(Route::guild_path(guild_id)),
+            ),
             RouteInfo::GetGuildWithCounts {
                 guild_id,
             } => (
                 LightMethod::Get,
                 Route::GuildsId(guild_id),
-                Cow::from(Route::guild_with_counts(guild_id)),
+                Cow::from(Route::guild_with_counts_path(guild_id)),
             ),
             RouteInfo::GetGuildApplicationCommands {
                 application_id,
@@ -2625,7 +3562,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::ApplicationsIdGuildsIdCommands(application_id),
-                Cow::from(Route::application_guild_commands(application_id, guild_id)),
+                Cow::from(Route::application_guild_commands_path(application_id, guild_id)),
             ),
             RouteInfo::GetGuildApplicationCommand {
                 application_id,
@@ -2634,7 +3571,11 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::ApplicationsIdGuildsIdCommandsId(application_id),
-                Cow::from(Route::application_guild_command(application_id, guild_id, command_id)),
+                Cow::from(Route::application_guild_command_path(
+                    application_id,
+                    guild_id,
+                    command_id,
+                )),
             ),
             RouteInfo::GetGuildApplicationCommandsPermissions {
                 application_id,
@@ -2642,7 +3583,10 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::ApplicationsIdGuildsIdCommandsPermissions(application_id),
-                Cow::from(Route::application_guild_commands_permissions(application_id, guild_id)),
+                Cow::from(Route::application_guild_commands_permissions_path(
+                    application_id,
+                    guild_id,
+                )),
             ),
             RouteInfo::GetGuildApplicationCommandPermissions {
                 application_id,
@@ -2651,7 +3595,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::ApplicationsIdGuildsIdCommandIdPermissions(application_id),
-                Cow::from(Route::application_guild_command_permissions(
+                Cow::from(Route::application_guild_command_permissions_path(
                     application_id,
                     guild_id,
                     command_id,
@@ -2662,35 +3606,35 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::GuildsIdWidget(guild_id),
-                Cow::from(Route::guild_widget(guild_id)),
+                Cow::from(Route::guild_widget_path(guild_id)),
             ),
             RouteInfo::GetGuildPreview {
                 guild_id,
             } => (
                 LightMethod::Get,
                 Route::GuildsIdPreview(guild_id),
-                Cow::from(Route::guild_preview(guild_id)),
+                Cow::from(Route::guild_preview_path(guild_id)),
             ),
             RouteInfo::GetGuildWelcomeScreen {
                 guild_id,
             } => (
                 LightMethod::Get,
                 Route::GuildsIdWelcomeScreen(guild_id),
-                Cow::from(Route::guild_welcome_screen(guild_id)),
+                Cow::from(Route::guild_welcome_screen_path(guild_id)),
             ),
             RouteInfo::GetGuildIntegrations {
                 guild_id,
             } => (
                 LightMethod::Get,
                 Route::GuildsIdIntegrations(guild_id),
-                Cow::from(Route::guild_integrations(guild_id)),
+                Cow::from(Route::guild_integrations_path(guild_id)),
             ),
             RouteInfo::GetGuildInvites {
                 guild_id,
             } => (
                 LightMethod::Get,
                 Route::GuildsIdInvites(guild_id),
-                Cow::from(Route::guild_invites(guild_id)),
+                Cow::from(Route::guild_invites_path(guild_id)),
             ),
             RouteInfo::GetGuildMembers {
                 after,
@@ -2699,7 +3643,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::GuildsIdMembers(guild_id),
-                Cow::from(Route::guild_members_optioned(guild_id, after, limit)),
+                Cow::from(Route::guild_members_optioned_path(guild_id, after, limit)),
             ),
             RouteInfo::GetGuildPruneCount {
                 days,
@@ -2707,21 +3651,21 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::GuildsIdPrune(guild_id),
-                Cow::from(Route::guild_prune(guild_id, days)),
+                Cow::from(Route::guild_prune_path(guild_id, days)),
             ),
             RouteInfo::GetGuildRegions {
                 guild_id,
             } => (
                 LightMethod::Get,
                 Route::GuildsIdRegions(guild_id),
-                Cow::from(Route::guild_regions(guild_id)),
+                Cow::from(Route::guild_regions_path(guild_id)),
             ),
             RouteInfo::GetGuildRoles {
                 guild_id,
             } => (
                 LightMethod::Get,
                 Route::GuildsIdRoles(guild_id),
-                Cow::from(Route::guild_roles(guild_id)),
+                Cow::from(Route::guild_roles_path(guild_id)),
             ),
             RouteInfo::GetGuildSticker {
                 guild_id,
@@ -2729,28 +3673,28 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::GuildsIdStickersId(guild_id),
-                Cow::from(Route::guild_sticker(guild_id, sticker_id)),
+                Cow::from(Route::guild_sticker_path(guild_id, sticker_id)),
             ),
             RouteInfo::GetGuildStickers {
                 guild_id,
             } => (
                 LightMethod::Get,
                 Route::GuildsIdStickers(guild_id),
-                Cow::from(Route::guild_stickers(guild_id)),
+                Cow::from(Route::guild_stickers_path(guild_id)),
             ),
             RouteInfo::GetGuildVanityUrl {
                 guild_id,
             } => (
                 LightMethod::Get,
                 Route::GuildsIdVanityUrl(guild_id),
-                Cow::from(Route::guild_vanity_url(guild_id)),
+                Cow::from(Route::guild_vanity_url_path(guild_id)),
             ),
             RouteInfo::GetGuildWebhooks {
                 guild_id,
             } => (
                 LightMethod::Get,
                 Route::GuildsIdWebhooks(guild_id),
-                Cow::from(Route::guild_webhooks(guild_id)),
+                Cow::from(Route::guild_webhooks_path(guild_id)),
             ),
             RouteInfo::GetGuilds {
                 after,
@@ -2759,7 +3703,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::UsersMeGuilds,
-                Cow::from(Route::user_guilds_optioned("@me", after, before, limit)),
+                Cow::from(Route::user_guilds_optioned_path("@me", after, before, limit)),
             ),
             RouteInfo::GetInvite {
                 code,
@@ -2769,7 +3713,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::InvitesCode,
-                Cow::from(Route::invite_optioned(code, member_counts, expiration, event_id)),
+                Cow::from(Route::invite_optioned_path(code, member_counts, expiration, event_id)),
             ),
             RouteInfo::GetMember {
                 guild_id,
@@ -2777,7 +3721,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::GuildsIdMembersId(guild_id),
-                Cow::from(Route::guild_member(guild_id, user_id)),
+                Cow::from(Route::guild_member_path(guild_id, user_id)),
             ),
             RouteInfo::GetMessage {
                 channel_id,
@@ -2785,7 +3729,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::ChannelsIdMessagesId(LightMethod::Get, channel_id),
-                Cow::from(Route::channel_message(channel_id, message_id)),
+                Cow::from(Route::channel_message_path(channel_id, message_id)),
             ),
             RouteInfo::GetMessages {
                 channel_id,
@@ -2793,14 +3737,14 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::ChannelsIdMessages(channel_id),
-                Cow::from(Route::channel_messages(channel_id, Some(query.as_ref()))),
+                Cow::from(Route::channel_messages_path(channel_id, Some(query.as_ref()))),
             ),
             RouteInfo::GetPins {
                 channel_id,
             } => (
                 LightMethod::Get,
                 Route::ChannelsIdPins(channel_id),
-                Cow::from(Route::channel_pins(channel_id)),
+                Cow::from(Route::channel_pins_path(channel_id)),
             ),
             RouteInfo::GetReactionUsers {
                 after,
@@ -2811,7 +3755,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::ChannelsIdMessagesIdReactions(channel_id),
-                Cow::from(Route::channel_message_reactions_list(
+                Cow::from(Route::channel_message_reactions_list_path(
                     channel_id, message_id, reaction, limit, after,
                 )),
             ),
@@ -2822,7 +3766,11 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::GuildsIdScheduledEventsId(guild_id),
-                Cow::from(Route::guild_scheduled_event(guild_id, event_id, Some(with_user_count))),
+                Cow::from(Route::guild_scheduled_event_path(
+                    guild_id,
+                    event_id,
+                    Some(with_user_count),
+                )),
             ),
             RouteInfo::GetScheduledEvents {
                 guild_id,
@@ -2830,7 +3778,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::GuildsIdScheduledEvents(guild_id),
-                Cow::from(Route::guild_scheduled_events(guild_id, Some(with_user_count))),
+                Cow::from(Route::guild_scheduled_events_path(guild_id, Some(with_user_count))),
             ),
             RouteInfo::GetScheduledEventUsers {
                 guild_id,
@@ -2842,7 +3790,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::GuildsIdScheduledEventsIdUsers(guild_id),
-                Cow::from(Route::guild_scheduled_event_users(
+                Cow::from(Route::guild_scheduled_event_users_path(
                     guild_id,
                     event_id,
                     after,
@@ -2853,32 +3801,32 @@ impl<'a> RouteInfo<'a> {
             ),
             RouteInfo::GetSticker {
                 sticker_id,
-            } => (LightMethod::Get, Route::StickersId, Cow::from(Route::sticker(sticker_id))),
+            } => (LightMethod::Get, Route::StickersId, Cow::from(Route::sticker_path(sticker_id))),
             RouteInfo::GetStickerPacks => {
-                (LightMethod::Get, Route::StickerPacks, Cow::from(Route::sticker_packs()))
+                (LightMethod::Get, Route::StickerPacks, Cow::from(Route::sticker_packs_path()))
             },
             RouteInfo::GetUser {
                 user_id,
-            } => (LightMethod::Get, Route::UsersId, Cow::from(Route::user(user_id))),
+            } => (LightMethod::Get, Route::UsersId, Cow::from(Route::user_path(user_id))),
             RouteInfo::GetUserConnections => (
                 LightMethod::Get,
                 Route::UsersMeConnections,
-                Cow::from(Route::user_me_connections()),
+                Cow::from(Route::user_me_connections_path()),
             ),
             RouteInfo::GetUserDmChannels => (
                 LightMethod::Get,
                 Route::UsersMeChannels,
-                Cow::from(Route::user_dm_channels("@me")),
+                Cow::from(Route::user_dm_channels_path("@me")),
             ),
             RouteInfo::GetVoiceRegions => {
-                (LightMethod::Get, Route::VoiceRegions, Cow::from(Route::voice_regions()))
+                (LightMethod::Get, Route::VoiceRegions, Cow::from(Route::voice_regions_path()))
             },
             RouteInfo::GetWebhook {
                 webhook_id,
             } => (
                 LightMethod::Get,
                 Route::WebhooksId(webhook_id),
-                Cow::from(Route::webhook(webhook_id)),
+                Cow::from(Route::webhook_path(webhook_id)),
             ),
             RouteInfo::GetWebhookWithToken {
                 token,
@@ -2886,7 +3834,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::WebhooksId(webhook_id),
-                Cow::from(Route::webhook_with_token(webhook_id, token)),
+                Cow::from(Route::webhook_with_token_path(webhook_id, token)),
             ),
             RouteInfo::KickMember {
                 guild_id,
@@ -2894,21 +3842,21 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::GuildsIdMembersId(guild_id),
-                Cow::from(Route::guild_kick_optioned(guild_id, user_id)),
+                Cow::from(Route::guild_kick_optioned_path(guild_id, user_id)),
             ),
             RouteInfo::LeaveGroup {
                 group_id,
             } => (
                 LightMethod::Delete,
                 Route::ChannelsId(group_id),
-                Cow::from(Route::channel(group_id)),
+                Cow::from(Route::channel_path(group_id)),
             ),
             RouteInfo::LeaveGuild {
                 guild_id,
             } => (
                 LightMethod::Delete,
                 Route::UsersMeGuildsId,
-                Cow::from(Route::user_guild("@me", guild_id)),
+                Cow::from(Route::user_guild_path("@me", guild_id)),
             ),
             RouteInfo::PinMessage {
                 channel_id,
@@ -2916,7 +3864,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Put,
                 Route::ChannelsIdPins(channel_id),
-                Cow::from(Route::channel_pin(channel_id, message_id)),
+                Cow::from(Route::channel_pin_path(channel_id, message_id)),
             ),
             RouteInfo::RemoveBan {
                 guild_id,
@@ -2924,7 +3872,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::GuildsIdBansUserId(guild_id),
-                Cow::from(Route::guild_ban(guild_id, user_id)),
+                Cow::from(Route::guild_ban_path(guild_id, user_id)),
             ),
             RouteInfo::RemoveMemberRole {
                 guild_id,
@@ -2933,7 +3881,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Delete,
                 Route::GuildsIdMembersIdRolesId(guild_id),
-                Cow::from(Route::guild_member_role(guild_id, user_id, role_id)),
+                Cow::from(Route::guild_member_role_path(guild_id, user_id, role_id)),
             ),
             RouteInfo::SearchGuildMembers {
                 guild_id,
@@ -2942,7 +3890,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::GuildsIdMembersSearch(guild_id),
-                Cow::from(Route::guild_members_search(guild_id, query, limit)),
+                Cow::from(Route::guild_members_search_path(guild_id, query, limit)),
             ),
             RouteInfo::StartGuildPrune {
                 days,
@@ -2950,7 +3898,7 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Post,
                 Route::GuildsIdPrune(guild_id),
-                Cow::from(Route::guild_prune(guild_id, days)),
+                Cow::from(Route::guild_prune_path(guild_id, days)),
             ),
             RouteInfo::StartIntegrationSync {
                 guild_id,
@@ -2958,25 +3906,60 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Post,
                 Route::GuildsIdIntegrationsId(guild_id),
-                Cow::from(Route::guild_integration_sync(guild_id, integration_id)),
+                Cow::from(Route::guild_integration_sync_path(guild_id, integration_id)),
+            ),
+            RouteInfo::GetUnresolvedIncidents | RouteInfo::StatusIncidentsUnresolved => (
+                LightMethod::Get,
+                Route::None,
+                Cow::from(Route::status_incidents_unresolved_path()),
             ),
-            RouteInfo::GetUnresolvedIncidents | RouteInfo::StatusIncidentsUnresolved => {
-                (LightMethod::Get, Route::None, Cow::from(Route::status_incidents_unresolved()))
-            },
             RouteInfo::GetActiveMaintenance | RouteInfo::StatusMaintenancesActive => {
-                (LightMethod::Get, Route::None, Cow::from(Route::status_maintenances_active()))
-            },
-            RouteInfo::GetUpcomingMaintenances | RouteInfo::StatusMaintenancesUpcoming => {
-                (LightMethod::Get, Route::None, Cow::from(Route::status_maintenances_upcoming()))
+                (LightMethod::Get, Route::None, Cow::from(Route::status_maintenances_active_path()))
             },
+            RouteInfo::GetUpcomingMaintenances | RouteInfo::StatusMaintenancesUpcoming => (
+                LightMethod::Get,
+                Route::None,
+                Cow::from(Route::status_maintenances_upcoming_path()),
+            ),
             RouteInfo::UnpinMessage {
                 channel_id,
                 message_id,
             } => (
                 LightMethod::Delete,
                 Route::ChannelsIdPinsMessageId(channel_id),
-                Cow::from(Route::channel_pin(channel_id, message_id)),
+                Cow::from(Route::channel_pin_path(channel_id, message_id)),
             ),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use reqwest::Url;
+
+    use super::{BaseUrls, RouteInfo};
+
+    #[test]
+    fn test_route_url() {
+        let mut base_urls = BaseUrls::default();
+
+        let route = RouteInfo::GetChannel {
+            channel_id: 1,
+        };
+        assert_eq!(
+            route.url(&base_urls).unwrap().as_str(),
+            "https://discord.com/api/v10/channels/1"
+        );
+
+        base_urls.api = Url::parse("http://localhost:8080/api/v10/").unwrap();
+        base_urls.status = Url::parse("http://localhost:8081").unwrap();
+        assert_eq!(
+            route.url(&base_urls).unwrap().as_str(),
+            "http://localhost:8080/api/v10/channels/1"
+        );
+        assert_eq!(
+            RouteInfo::GetUnresolvedIncidents.url(&base_urls).unwrap().as_str(),
+            "http://localhost:8081/incidents/unresolved.json"
+        );
+    }
+}
@@ -0,0 +1,51 @@
+//! The base URLs that URLs built by models point to.
+//!
+//! Models build these URLs without access to an [`Http`] client, so the bases
+//! are kept for the whole process, and set by clients built with other bases
+//! than Discord's.
+//!
+//! [`Http`]: crate::http::Http
+
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::constants;
+
+struct Bases {
+    api: String,
+    cdn: String,
+}
+
+// The bases set by an `Http` client, or null for Discord's. Set bases are
+// leaked, so that references to them stay valid once replaced.
+static BASES: AtomicPtr<Bases> = AtomicPtr::new(ptr::null_mut());
+
+#[cfg(feature = "model")]
+fn bases() -> Option<&'static Bases> {
+    let bases = BASES.load(Ordering::Acquire);
+
+    // Set bases are leaked boxes, so are never freed.
+    unsafe { bases.as_ref() }
+}
+
+/// The base URL of the REST API, without a trailing slash.
+#[cfg(feature = "model")]
+pub fn api() -> &'static str {
+    bases().map_or(constants::API_URL, |bases| &bases.api)
+}
+
+/// The base URL of the CDN, without a trailing slash.
+#[cfg(feature = "model")]
+pub fn cdn() -> &'static str {
+    bases().map_or(constants::CDN_URL, |bases| &bases.cdn)
+}
+
+/// Sets the base URLs of the REST API and the CDN.
+pub fn set(api: &str, cdn: &str) {
+    let bases = Box::new(Bases {
+        api: api.trim_end_matches('/').to_string(),
+        cdn: cdn.trim_end_matches('/').to_string(),
+    });
+
+    BASES.store(Box::into_raw(bases), Ordering::Release);
+}
@@ -1,12 +1,9 @@
 //! A set of macros for easily working with internals.
 
-#[cfg(any(feature = "model", feature = "utils"))]
+#[cfg(feature = "model")]
 macro_rules! cdn {
-    ($e:expr) => {
-        concat!("https://cdn.discordapp.com", $e)
-    };
     ($e:expr, $($rest:tt)*) => {
-        format!(cdn!($e), $($rest)*)
+        format!(concat!("{}", $e), $crate::internal::base_urls::cdn(), $($rest)*)
     };
 }
 
@@ -15,9 +12,6 @@ macro_rules! api {
     ($e:expr) => {
         concat!("https://discord.com/api/v10", $e)
     };
-    ($e:expr, $($rest:tt)*) => {
-        format!(api!($e), $($rest)*)
-    };
 }
 
 #[cfg(feature = "http")]
@@ -3,6 +3,9 @@ pub mod macros;
 
 pub mod prelude;
 
+#[cfg(feature = "http")]
+pub mod base_urls;
+
 #[cfg(feature = "gateway")]
 pub mod ws_impl;
 
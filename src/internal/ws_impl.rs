@@ -1,39 +1,170 @@
-use std::io::Read;
+use std::io::{self, Read};
 
 use async_trait::async_trait;
 use async_tungstenite::tungstenite::Message;
 use flate2::read::ZlibDecoder;
+use flate2::{Decompress, FlushDecompress, Status};
 use futures::{SinkExt, StreamExt};
 use tokio::time::timeout;
 use tracing::{instrument, warn};
 use url::Url;
 
-use crate::gateway::{GatewayError, WsStream};
+#[cfg(feature = "etf")]
+use crate::gateway::etf;
+use crate::gateway::{GatewayEncoding, GatewayError, WsStream};
 use crate::internal::prelude::*;
-use crate::json::{from_str, to_string};
+use crate::json::to_string;
+use crate::model::event::GatewayEvent;
 
 #[async_trait]
 pub trait ReceiverExt {
-    async fn recv_json(&mut self) -> Result<Option<Value>>;
+    async fn recv_event(&mut self, encoding: GatewayEncoding) -> Result<Option<GatewayEvent>>;
+
+    /// Receives a payload of a `zlib-stream` compressed connection, returning
+    /// `None` until a binary message completes one.
+    async fn recv_event_inflated(
+        &mut self,
+        inflater: &mut Inflater,
+        encoding: GatewayEncoding,
+    ) -> Result<Option<GatewayEvent>>;
 }
 
 #[async_trait]
 pub trait SenderExt {
     async fn send_json(&mut self, value: &Value) -> Result<()>;
+
+    /// Sends a payload in the given encoding.
+    async fn send_payload(&mut self, value: &Value, encoding: GatewayEncoding) -> Result<()>;
 }
 
 #[async_trait]
 impl ReceiverExt for WsStream {
-    async fn recv_json(&mut self) -> Result<Option<Value>> {
-        const TIMEOUT: tokio::time::Duration = tokio::time::Duration::from_millis(500);
+    async fn recv_event(&mut self, encoding: GatewayEncoding) -> Result<Option<GatewayEvent>> {
+        convert_ws_message(recv_message(self).await?, encoding)
+    }
+
+    async fn recv_event_inflated(
+        &mut self,
+        inflater: &mut Inflater,
+        encoding: GatewayEncoding,
+    ) -> Result<Option<GatewayEvent>> {
+        match recv_message(self).await? {
+            Some(Message::Binary(bytes)) => match inflater.inflate(&bytes)? {
+                Some(payload) => decode_payload(payload, encoding).map(Some).map_err(|why| {
+                    warn!("Err deserializing inflated payload: {:?}", why);
+
+                    why
+                }),
+                None => Ok(None),
+            },
+            other => convert_ws_message(other, encoding),
+        }
+    }
+}
 
-        let ws_message = match timeout(TIMEOUT, self.next()).await {
-            Ok(Some(Ok(v))) => Some(v),
-            Ok(Some(Err(e))) => return Err(e.into()),
-            Ok(None) | Err(_) => None,
-        };
+/// Deserializes a binary payload in the given encoding.
+fn decode_payload(payload: &mut [u8], encoding: GatewayEncoding) -> Result<GatewayEvent> {
+    match encoding {
+        GatewayEncoding::Json => {
+            let payload = std::str::from_utf8_mut(payload)
+                .map_err(|why| io::Error::new(io::ErrorKind::InvalidData, why))?;
 
-        convert_ws_message(ws_message)
+            GatewayEvent::from_payload(payload)
+        },
+        #[cfg(feature = "etf")]
+        GatewayEncoding::Etf => GatewayEvent::from_etf(payload),
+    }
+}
+
+async fn recv_message(stream: &mut WsStream) -> Result<Option<Message>> {
+    const TIMEOUT: tokio::time::Duration = tokio::time::Duration::from_millis(500);
+
+    Ok(match timeout(TIMEOUT, stream.next()).await {
+        Ok(Some(Ok(v))) => Some(v),
+        Ok(Some(Err(e))) => return Err(e.into()),
+        Ok(None) | Err(_) => None,
+    })
+}
+
+/// The inflate context of a `zlib-stream` compressed gateway connection.
+///
+/// The whole connection is a single zlib stream, so the context must persist
+/// for the lifetime of the connection. A payload may span several binary
+/// messages, and is complete once the received data ends with the
+/// `Z_SYNC_FLUSH` suffix.
+#[derive(Debug)]
+pub struct Inflater {
+    decompress: Decompress,
+    compressed: Vec<u8>,
+    decompressed: Vec<u8>,
+}
+
+impl Inflater {
+    const SUFFIX: [u8; 4] = [0x00, 0x00, 0xff, 0xff];
+    const DECOMPRESSION_MULTIPLIER: usize = 3;
+
+    pub fn new() -> Self {
+        Self {
+            decompress: Decompress::new(true),
+            compressed: Vec::new(),
+            decompressed: Vec::new(),
+        }
+    }
+
+    /// Buffers the bytes of a binary message, returning the decompressed
+    /// payload if they complete one.
+    pub fn inflate(&mut self, bytes: &[u8]) -> Result<Option<&mut [u8]>> {
+        self.compressed.extend_from_slice(bytes);
+
+        if !self.compressed.ends_with(&Self::SUFFIX) {
+            return Ok(None);
+        }
+
+        self.decompressed.clear();
+        self.decompressed.reserve(self.compressed.len() * Self::DECOMPRESSION_MULTIPLIER);
+
+        let mut input = &self.compressed[..];
+        loop {
+            let total_in = self.decompress.total_in();
+            let total_out = self.decompress.total_out();
+
+            let status = self
+                .decompress
+                .decompress_vec(input, &mut self.decompressed, FlushDecompress::Sync)
+                .map_err(|why| io::Error::new(io::ErrorKind::InvalidData, why))?;
+
+            let consumed = (self.decompress.total_in() - total_in) as usize;
+            let produced = self.decompress.total_out() - total_out;
+            input = &input[consumed..];
+
+            // The output buffer filled up before all input could be inflated.
+            if self.decompressed.len() == self.decompressed.capacity() {
+                self.decompressed.reserve(self.decompressed.len());
+                continue;
+            }
+
+            if input.is_empty() || status == Status::StreamEnd {
+                break;
+            }
+
+            if consumed == 0 && produced == 0 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Stalled inflating payload",
+                )
+                .into());
+            }
+        }
+
+        self.compressed.clear();
+
+        Ok(Some(&mut self.decompressed))
+    }
+}
+
+impl Default for Inflater {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -42,33 +173,59 @@ impl SenderExt for WsStream {
     async fn send_json(&mut self, value: &Value) -> Result<()> {
         Ok(to_string(value).map(Message::Text).map_err(Error::from).map(|m| self.send(m))?.await?)
     }
+
+    async fn send_payload(&mut self, value: &Value, encoding: GatewayEncoding) -> Result<()> {
+        match encoding {
+            GatewayEncoding::Json => self.send_json(value).await,
+            #[cfg(feature = "etf")]
+            GatewayEncoding::Etf => {
+                let payload = etf::to_vec(value)?;
+
+                Ok(self.send(Message::Binary(payload)).await?)
+            },
+        }
+    }
 }
 
 #[inline]
-pub(crate) fn convert_ws_message(message: Option<Message>) -> Result<Option<Value>> {
+pub(crate) fn convert_ws_message(
+    message: Option<Message>,
+    encoding: GatewayEncoding,
+) -> Result<Option<GatewayEvent>> {
     const DECOMPRESSION_MULTIPLIER: usize = 3;
 
     Ok(match message {
-        Some(Message::Binary(bytes)) => {
-            let mut decompressed = String::with_capacity(bytes.len() * DECOMPRESSION_MULTIPLIER);
+        Some(Message::Binary(bytes)) => match encoding {
+            GatewayEncoding::Json => {
+                let mut decompressed =
+                    String::with_capacity(bytes.len() * DECOMPRESSION_MULTIPLIER);
 
-            ZlibDecoder::new(&bytes[..]).read_to_string(&mut decompressed).map_err(|why| {
-                warn!("Err decompressing bytes: {:?}; bytes: {:?}", why, bytes);
+                ZlibDecoder::new(&bytes[..]).read_to_string(&mut decompressed).map_err(|why| {
+                    warn!("Err decompressing bytes: {:?}; bytes: {:?}", why, bytes);
 
-                why
-            })?;
+                    why
+                })?;
+
+                GatewayEvent::from_payload(&mut decompressed).map(Some).map_err(|why| {
+                    warn!("Err deserializing bytes: {:?}; bytes: {:?}", why, bytes);
 
-            from_str(decompressed.as_mut_str()).map(Some).map_err(|why| {
+                    why
+                })?
+            },
+            #[cfg(feature = "etf")]
+            GatewayEncoding::Etf => GatewayEvent::from_etf(&bytes).map(Some).map_err(|why| {
                 warn!("Err deserializing bytes: {:?}; bytes: {:?}", why, bytes);
 
                 why
-            })?
+            })?,
         },
-        Some(Message::Text(mut payload)) => from_str(&mut payload).map(Some).map_err(|why| {
-            warn!("Err deserializing text: {:?}; text: {}", why, payload,);
+        Some(Message::Text(mut payload)) => {
+            GatewayEvent::from_payload(&mut payload).map(Some).map_err(|why| {
+                warn!("Err deserializing text: {:?}; text: {}", why, payload,);
 
-            why
-        })?,
+                why
+            })?
+        },
         Some(Message::Close(Some(frame))) => {
             return Err(Error::Gateway(GatewayError::Closed(Some(frame))));
         },
@@ -90,3 +247,38 @@ pub(crate) async fn create_client(url: Url) -> Result<WsStream> {
 
     Ok(stream)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+
+    use super::Inflater;
+
+    #[test]
+    fn test_inflate_zlib_stream() {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        let mut payloads = Vec::new();
+
+        for payload in &[r#"{"op":11}"#, r#"{"op":1,"d":null}"#] {
+            encoder.write_all(payload.as_bytes()).unwrap();
+            encoder.flush().unwrap();
+            payloads.push(std::mem::take(encoder.get_mut()));
+        }
+
+        let mut inflater = Inflater::new();
+
+        // A payload may be split over several messages.
+        let (first, rest) = payloads[0].split_at(payloads[0].len() / 2);
+        assert!(inflater.inflate(first).unwrap().is_none());
+        assert_eq!(inflater.inflate(rest).unwrap().as_deref(), Some(&br#"{"op":11}"#[..]));
+
+        // The context persists between payloads.
+        assert_eq!(
+            inflater.inflate(&payloads[1]).unwrap().as_deref(),
+            Some(&br#"{"op":1,"d":null}"#[..])
+        );
+    }
+}
@@ -5,7 +5,7 @@
 use std::collections::HashMap;
 use std::hash::{BuildHasher, Hash};
 
-#[cfg(feature = "gateway")]
+#[cfg(any(feature = "gateway", feature = "http"))]
 use serde::de::Deserialize;
 use serde::de::DeserializeOwned;
 use serde::ser::Serialize;
@@ -61,7 +61,7 @@ where
     Ok(simd_json::to_string(v)?)
 }
 
-#[cfg(all(feature = "gateway", not(feature = "simd-json")))]
+#[cfg(all(any(feature = "gateway", feature = "http"), not(feature = "simd-json")))]
 pub(crate) fn from_str<'a, T>(s: &'a mut str) -> Result<T>
 where
     T: Deserialize<'a>,
@@ -69,7 +69,7 @@ where
     Ok(serde_json::from_str(s)?)
 }
 
-#[cfg(all(feature = "gateway", feature = "simd-json"))]
+#[cfg(all(any(feature = "gateway", feature = "http"), feature = "simd-json"))]
 pub(crate) fn from_str<'a, T>(s: &'a mut str) -> Result<T>
 where
     T: Deserialize<'a>,
@@ -77,6 +77,28 @@ where
     Ok(simd_json::from_str(s)?)
 }
 
+/// A JSON value whose parsing has been deferred, borrowing from the input.
+#[cfg(all(feature = "gateway", not(feature = "simd-json")))]
+pub(crate) type RawValue<'a> = &'a serde_json::value::RawValue;
+#[cfg(all(feature = "gateway", feature = "simd-json"))]
+pub(crate) type RawValue<'a> = simd_json::BorrowedValue<'a>;
+
+#[cfg(all(feature = "gateway", not(feature = "simd-json")))]
+pub(crate) fn from_raw<'a, T>(v: RawValue<'a>) -> std::result::Result<T, JsonError>
+where
+    T: Deserialize<'a>,
+{
+    serde_json::from_str(v.get())
+}
+
+#[cfg(all(feature = "gateway", feature = "simd-json"))]
+pub(crate) fn from_raw<'a, T>(v: RawValue<'a>) -> std::result::Result<T, JsonError>
+where
+    T: Deserialize<'a>,
+{
+    simd_json::serde::from_borrowed_value(v)
+}
+
 #[cfg(not(feature = "simd-json"))]
 pub(crate) fn from_value<T>(v: Value) -> Result<T>
 where
@@ -10,8 +10,14 @@ use super::application::component::ActionRow;
 use super::prelude::*;
 use super::utils::{emojis, roles, stickers};
 use crate::constants::OpCode;
+#[cfg(feature = "etf")]
+use crate::gateway::etf::RawTerm;
+#[cfg(feature = "etf")]
+use crate::gateway::EtfError;
 use crate::internal::prelude::*;
 use crate::json::prelude::*;
+#[cfg(feature = "gateway")]
+use crate::json::{from_raw, JsonError, RawValue};
 use crate::model::application::command::CommandPermission;
 use crate::model::application::interaction::Interaction;
 use crate::model::guild::automod::{ActionExecution, Rule};
@@ -472,6 +478,17 @@ pub struct ReactionRemoveAllEvent {
     pub message_id: MessageId,
 }
 
+/// Sent when all reactions of an emoji are removed from a message. The
+/// reaction has no [`Reaction::user_id`] or [`Reaction::member`].
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway#message-reaction-remove-emoji).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct ReactionRemoveEmojiEvent {
+    pub reaction: Reaction,
+}
+
 /// The "Ready" event, containing initial ready cache
 ///
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway#ready).
@@ -488,6 +505,12 @@ pub struct ReadyEvent {
 pub struct ResumedEvent {
     #[serde(rename = "_trace")]
     pub trace: Vec<Option<String>>,
+    /// The number of dispatches Discord replayed before this event, being
+    /// those missed since the sequence number the session was resumed with.
+    ///
+    /// This is not part of the payload, and is filled in by the shard runner.
+    #[serde(skip)]
+    pub replayed: u64,
 }
 
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway#typing-start).
@@ -651,10 +674,12 @@ pub struct ThreadListSyncEvent {
 
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway#thread-member-update).
 #[derive(Clone, Debug, Deserialize, Serialize)]
-#[serde(transparent)]
 #[non_exhaustive]
 pub struct ThreadMemberUpdateEvent {
+    #[serde(flatten)]
     pub member: ThreadMember,
+    /// The id of the guild of the thread.
+    pub guild_id: GuildId,
 }
 
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway#thread-members-update).
@@ -809,6 +834,149 @@ impl<'de> Deserialize<'de> for GatewayEvent {
     }
 }
 
+#[cfg(feature = "gateway")]
+impl GatewayEvent {
+    /// Deserializes a gateway payload straight into a [`GatewayEvent`].
+    ///
+    /// Unlike the [`Deserialize`] implementation, this does not build a
+    /// [`Value`] of the payload first: the event data is deserialized directly
+    /// into its type, and only events unknown to the library keep a
+    /// [`Value`]. With the `simd_json` feature, the payload is parsed in
+    /// place, which is why it is taken mutably.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Json`] if the payload is not a valid gateway payload.
+    pub fn from_payload(payload: &mut str) -> Result<Self> {
+        let payload: RawGatewayPayload<RawValue<'_>> = crate::json::from_str(payload)?;
+
+        payload.into_gateway_event().map_err(From::from)
+    }
+
+    /// Deserializes a gateway payload of the `etf` encoding straight into a
+    /// [`GatewayEvent`], like [`Self::from_payload`] does for JSON payloads.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Etf`] if the payload is not a valid gateway payload.
+    #[cfg(feature = "etf")]
+    pub fn from_etf(payload: &[u8]) -> Result<Self> {
+        let payload: RawGatewayPayload<RawTerm<'_>> = crate::gateway::etf::from_slice(payload)?;
+
+        payload.into_gateway_event().map_err(From::from)
+    }
+}
+
+/// A gateway payload whose data has not been deserialized yet.
+#[cfg(feature = "gateway")]
+#[derive(Deserialize)]
+struct RawGatewayPayload<D> {
+    op: OpCode,
+    #[serde(default)]
+    s: Option<u64>,
+    #[serde(default)]
+    t: Option<EventType>,
+    d: Option<D>,
+}
+
+#[cfg(feature = "gateway")]
+impl<D> RawGatewayPayload<D> {
+    fn into_gateway_event<'a>(self) -> StdResult<GatewayEvent, D::Error>
+    where
+        D: RawData<'a>,
+    {
+        #[derive(Deserialize)]
+        struct Hello {
+            heartbeat_interval: u64,
+        }
+
+        Ok(match self.op {
+            OpCode::Event => {
+                let s = self.s.ok_or_else(|| DeError::custom("expected gateway event sequence"))?;
+                let kind = self.t.ok_or_else(|| DeError::custom("expected gateway event type"))?;
+                let d = self.d.ok_or_else(|| DeError::custom("expected gateway event d"))?;
+
+                let event = d
+                    .deserialize_event(kind.clone())
+                    .map_err(|why| DeError::custom(format_args!("event {:?}: {}", kind, why)))?;
+
+                GatewayEvent::Dispatch(s, event)
+            },
+            OpCode::Heartbeat => GatewayEvent::Heartbeat(
+                self.s.ok_or_else(|| DeError::custom("Expected heartbeat s"))?,
+            ),
+            OpCode::Reconnect => GatewayEvent::Reconnect,
+            OpCode::InvalidSession => {
+                let d =
+                    self.d.ok_or_else(|| DeError::custom("expected gateway invalid session d"))?;
+
+                GatewayEvent::InvalidateSession(d.deserialize()?)
+            },
+            OpCode::Hello => {
+                let d = self.d.ok_or_else(|| DeError::custom("expected gateway hello d"))?;
+                let hello: Hello = d.deserialize()?;
+
+                GatewayEvent::Hello(hello.heartbeat_interval)
+            },
+            OpCode::HeartbeatAck => GatewayEvent::HeartbeatAck,
+            _ => return Err(DeError::custom("invalid opcode")),
+        })
+    }
+}
+
+/// The `d` field of a gateway payload, borrowing from the payload.
+#[cfg(feature = "gateway")]
+trait RawData<'a> {
+    type Error: DeError;
+
+    fn deserialize<T: Deserialize<'a>>(self) -> StdResult<T, Self::Error>;
+
+    fn deserialize_event(self, kind: EventType) -> StdResult<Event, Self::Error>;
+}
+
+#[cfg(all(feature = "gateway", not(feature = "simd-json")))]
+impl<'a> RawData<'a> for RawValue<'a> {
+    type Error = JsonError;
+
+    fn deserialize<T: Deserialize<'a>>(self) -> StdResult<T, JsonError> {
+        from_raw(self)
+    }
+
+    fn deserialize_event(self, kind: EventType) -> StdResult<Event, JsonError> {
+        deserialize_event(kind, &mut serde_json::Deserializer::from_str(self.get()))
+    }
+}
+
+#[cfg(all(feature = "gateway", feature = "simd-json"))]
+impl<'a> RawData<'a> for RawValue<'a> {
+    type Error = JsonError;
+
+    fn deserialize<T: Deserialize<'a>>(self) -> StdResult<T, JsonError> {
+        from_raw(self)
+    }
+
+    fn deserialize_event(self, kind: EventType) -> StdResult<Event, JsonError> {
+        deserialize_event(kind, self)
+    }
+}
+
+#[cfg(feature = "etf")]
+impl<'a> RawData<'a> for RawTerm<'a> {
+    type Error = EtfError;
+
+    fn deserialize<T: Deserialize<'a>>(self) -> StdResult<T, EtfError> {
+        RawTerm::deserialize(self)
+    }
+
+    fn deserialize_event(self, kind: EventType) -> StdResult<Event, EtfError> {
+        let mut deserializer = self.deserializer();
+        let event = deserialize_event(kind, &mut deserializer)?;
+        deserializer.end()?;
+
+        Ok(event)
+    }
+}
+
 /// Event received over a websocket connection
 ///
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway#commands-and-events-gateway-events).
@@ -934,6 +1102,12 @@ pub enum Event {
     ///
     /// [`EventHandler::reaction_remove_all`]: crate::client::EventHandler::reaction_remove_all
     ReactionRemoveAll(ReactionRemoveAllEvent),
+    /// All [`Reaction`]s of an emoji were removed from a [`Message`].
+    ///
+    /// Fires the [`EventHandler::reaction_remove_emoji`] event handler.
+    ///
+    /// [`EventHandler::reaction_remove_emoji`]: crate::client::EventHandler::reaction_remove_emoji
+    ReactionRemoveEmoji(ReactionRemoveEmojiEvent),
     /// The first event in a connection, containing the initial ready cache.
     ///
     /// May also be received at a later time in the event of a reconnect.
@@ -1251,6 +1425,12 @@ macro_rules! with_related_ids_for_event_types {
                 channel_id: Some(e.channel_id),
                 message_id: Some(e.message_id),
             },
+            Self::ReactionRemoveEmoji, Self::ReactionRemoveEmoji(e) => {
+                user_id: Never,
+                guild_id: e.reaction.guild_id.into(),
+                channel_id: Some(e.reaction.channel_id),
+                message_id: Some(e.reaction.message_id),
+            },
             Self::Ready, Self::Ready(e) => {
                 user_id: Never,
                 guild_id: Never,
@@ -1517,6 +1697,7 @@ impl Event {
             Self::ReactionAdd(_) => EventType::ReactionAdd,
             Self::ReactionRemove(_) => EventType::ReactionRemove,
             Self::ReactionRemoveAll(_) => EventType::ReactionRemoveAll,
+            Self::ReactionRemoveEmoji(_) => EventType::ReactionRemoveEmoji,
             Self::Ready(_) => EventType::Ready,
             Self::Resumed(_) => EventType::Resumed,
             Self::TypingStart(_) => EventType::TypingStart,
@@ -1613,91 +1794,121 @@ impl<T> TryFrom<RelatedId<T>> for Option<T> {
 ///
 /// Returns [`Error::Json`] if there is an error in deserializing the event data.
 pub fn deserialize_event_with_type(kind: EventType, v: Value) -> Result<Event> {
+    Ok(deserialize_event(kind, v)?)
+}
+
+/// Deserializes the data of an event of the given type from any deserializer,
+/// such as the deferred `d` field of a gateway payload.
+fn deserialize_event<'de, D: Deserializer<'de>>(
+    kind: EventType,
+    d: D,
+) -> StdResult<Event, D::Error> {
     Ok(match kind {
         EventType::ApplicationCommandPermissionsUpdate => {
-            Event::ApplicationCommandPermissionsUpdate(from_value(v)?)
+            Event::ApplicationCommandPermissionsUpdate(Deserialize::deserialize(d)?)
+        },
+        EventType::AutoModerationRuleCreate => {
+            Event::AutoModerationRuleCreate(Deserialize::deserialize(d)?)
+        },
+        EventType::AutoModerationRuleUpdate => {
+            Event::AutoModerationRuleUpdate(Deserialize::deserialize(d)?)
+        },
+        EventType::AutoModerationRuleDelete => {
+            Event::AutoModerationRuleDelete(Deserialize::deserialize(d)?)
         },
-        EventType::AutoModerationRuleCreate => Event::AutoModerationRuleCreate(from_value(v)?),
-        EventType::AutoModerationRuleUpdate => Event::AutoModerationRuleUpdate(from_value(v)?),
-        EventType::AutoModerationRuleDelete => Event::AutoModerationRuleDelete(from_value(v)?),
         EventType::AutoModerationActionExecution => {
-            Event::AutoModerationActionExecution(from_value(v)?)
+            Event::AutoModerationActionExecution(Deserialize::deserialize(d)?)
         },
-        EventType::ChannelCreate => Event::ChannelCreate(from_value(v)?),
-        EventType::ChannelDelete => Event::ChannelDelete(from_value(v)?),
-        EventType::ChannelPinsUpdate => Event::ChannelPinsUpdate(from_value(v)?),
-        EventType::ChannelUpdate => Event::ChannelUpdate(from_value(v)?),
-        EventType::GuildBanAdd => Event::GuildBanAdd(from_value(v)?),
-        EventType::GuildBanRemove => Event::GuildBanRemove(from_value(v)?),
+        EventType::ChannelCreate => Event::ChannelCreate(Deserialize::deserialize(d)?),
+        EventType::ChannelDelete => Event::ChannelDelete(Deserialize::deserialize(d)?),
+        EventType::ChannelPinsUpdate => Event::ChannelPinsUpdate(Deserialize::deserialize(d)?),
+        EventType::ChannelUpdate => Event::ChannelUpdate(Deserialize::deserialize(d)?),
+        EventType::GuildBanAdd => Event::GuildBanAdd(Deserialize::deserialize(d)?),
+        EventType::GuildBanRemove => Event::GuildBanRemove(Deserialize::deserialize(d)?),
         EventType::GuildCreate | EventType::GuildUnavailable => {
             // GuildUnavailable isn't actually received from the gateway, so it
             // can be lumped in with GuildCreate's arm.
 
+            let v = Value::deserialize(d)?;
+
             if v.get("unavailable").and_then(Value::as_bool).unwrap_or(false) {
-                Event::GuildUnavailable(from_value(v)?)
+                Event::GuildUnavailable(from_value(v).map_err(DeError::custom)?)
             } else {
-                Event::GuildCreate(from_value(v)?)
+                Event::GuildCreate(from_value(v).map_err(DeError::custom)?)
             }
         },
         EventType::GuildDelete => {
+            let v = Value::deserialize(d)?;
+
             if v.get("unavailable").and_then(Value::as_bool).unwrap_or(false) {
-                Event::GuildUnavailable(from_value(v)?)
+                Event::GuildUnavailable(from_value(v).map_err(DeError::custom)?)
             } else {
-                Event::GuildDelete(from_value(v)?)
+                Event::GuildDelete(from_value(v).map_err(DeError::custom)?)
             }
         },
-        EventType::GuildEmojisUpdate => Event::GuildEmojisUpdate(from_value(v)?),
-        EventType::GuildIntegrationsUpdate => Event::GuildIntegrationsUpdate(from_value(v)?),
-        EventType::GuildMemberAdd => Event::GuildMemberAdd(from_value(v)?),
-        EventType::GuildMemberRemove => Event::GuildMemberRemove(from_value(v)?),
-        EventType::GuildMemberUpdate => Event::GuildMemberUpdate(from_value(v)?),
-        EventType::GuildMembersChunk => Event::GuildMembersChunk(from_value(v)?),
-        EventType::GuildRoleCreate => Event::GuildRoleCreate(from_value(v)?),
-        EventType::GuildRoleDelete => Event::GuildRoleDelete(from_value(v)?),
-        EventType::GuildRoleUpdate => Event::GuildRoleUpdate(from_value(v)?),
-        EventType::GuildStickersUpdate => Event::GuildStickersUpdate(from_value(v)?),
-        EventType::InviteCreate => Event::InviteCreate(from_value(v)?),
-        EventType::InviteDelete => Event::InviteDelete(from_value(v)?),
-        EventType::GuildUpdate => Event::GuildUpdate(from_value(v)?),
-        EventType::MessageCreate => Event::MessageCreate(from_value(v)?),
-        EventType::MessageDelete => Event::MessageDelete(from_value(v)?),
-        EventType::MessageDeleteBulk => Event::MessageDeleteBulk(from_value(v)?),
-        EventType::ReactionAdd => Event::ReactionAdd(from_value(v)?),
-        EventType::ReactionRemove => Event::ReactionRemove(from_value(v)?),
-        EventType::ReactionRemoveAll => Event::ReactionRemoveAll(from_value(v)?),
-        EventType::MessageUpdate => Event::MessageUpdate(from_value(v)?),
-        EventType::PresenceUpdate => Event::PresenceUpdate(from_value(v)?),
-        EventType::PresencesReplace => Event::PresencesReplace(from_value(v)?),
-        EventType::Ready => Event::Ready(from_value(v)?),
-        EventType::Resumed => Event::Resumed(from_value(v)?),
-        EventType::TypingStart => Event::TypingStart(from_value(v)?),
-        EventType::UserUpdate => Event::UserUpdate(from_value(v)?),
-        EventType::VoiceServerUpdate => Event::VoiceServerUpdate(from_value(v)?),
-        EventType::VoiceStateUpdate => Event::VoiceStateUpdate(from_value(v)?),
-        EventType::WebhookUpdate => Event::WebhookUpdate(from_value(v)?),
-        EventType::InteractionCreate => Event::InteractionCreate(from_value(v)?),
-        EventType::IntegrationCreate => Event::IntegrationCreate(from_value(v)?),
-        EventType::IntegrationUpdate => Event::IntegrationUpdate(from_value(v)?),
-        EventType::IntegrationDelete => Event::IntegrationDelete(from_value(v)?),
-        EventType::StageInstanceCreate => Event::StageInstanceCreate(from_value(v)?),
-        EventType::StageInstanceUpdate => Event::StageInstanceUpdate(from_value(v)?),
-        EventType::StageInstanceDelete => Event::StageInstanceDelete(from_value(v)?),
-        EventType::ThreadCreate => Event::ThreadCreate(from_value(v)?),
-        EventType::ThreadUpdate => Event::ThreadUpdate(from_value(v)?),
-        EventType::ThreadDelete => Event::ThreadDelete(from_value(v)?),
-        EventType::ThreadListSync => Event::ThreadListSync(from_value(v)?),
-        EventType::ThreadMemberUpdate => Event::ThreadMemberUpdate(from_value(v)?),
-        EventType::ThreadMembersUpdate => Event::ThreadMembersUpdate(from_value(v)?),
-        EventType::GuildScheduledEventCreate => Event::GuildScheduledEventCreate(from_value(v)?),
-        EventType::GuildScheduledEventUpdate => Event::GuildScheduledEventUpdate(from_value(v)?),
-        EventType::GuildScheduledEventDelete => Event::GuildScheduledEventDelete(from_value(v)?),
-        EventType::GuildScheduledEventUserAdd => Event::GuildScheduledEventUserAdd(from_value(v)?),
+        EventType::GuildEmojisUpdate => Event::GuildEmojisUpdate(Deserialize::deserialize(d)?),
+        EventType::GuildIntegrationsUpdate => {
+            Event::GuildIntegrationsUpdate(Deserialize::deserialize(d)?)
+        },
+        EventType::GuildMemberAdd => Event::GuildMemberAdd(Deserialize::deserialize(d)?),
+        EventType::GuildMemberRemove => Event::GuildMemberRemove(Deserialize::deserialize(d)?),
+        EventType::GuildMemberUpdate => Event::GuildMemberUpdate(Deserialize::deserialize(d)?),
+        EventType::GuildMembersChunk => Event::GuildMembersChunk(Deserialize::deserialize(d)?),
+        EventType::GuildRoleCreate => Event::GuildRoleCreate(Deserialize::deserialize(d)?),
+        EventType::GuildRoleDelete => Event::GuildRoleDelete(Deserialize::deserialize(d)?),
+        EventType::GuildRoleUpdate => Event::GuildRoleUpdate(Deserialize::deserialize(d)?),
+        EventType::GuildStickersUpdate => Event::GuildStickersUpdate(Deserialize::deserialize(d)?),
+        EventType::InviteCreate => Event::InviteCreate(Deserialize::deserialize(d)?),
+        EventType::InviteDelete => Event::InviteDelete(Deserialize::deserialize(d)?),
+        EventType::GuildUpdate => Event::GuildUpdate(Deserialize::deserialize(d)?),
+        EventType::MessageCreate => Event::MessageCreate(Deserialize::deserialize(d)?),
+        EventType::MessageDelete => Event::MessageDelete(Deserialize::deserialize(d)?),
+        EventType::MessageDeleteBulk => Event::MessageDeleteBulk(Deserialize::deserialize(d)?),
+        EventType::ReactionAdd => Event::ReactionAdd(Deserialize::deserialize(d)?),
+        EventType::ReactionRemove => Event::ReactionRemove(Deserialize::deserialize(d)?),
+        EventType::ReactionRemoveAll => Event::ReactionRemoveAll(Deserialize::deserialize(d)?),
+        EventType::ReactionRemoveEmoji => Event::ReactionRemoveEmoji(Deserialize::deserialize(d)?),
+        EventType::MessageUpdate => Event::MessageUpdate(Deserialize::deserialize(d)?),
+        EventType::PresenceUpdate => Event::PresenceUpdate(Deserialize::deserialize(d)?),
+        EventType::PresencesReplace => Event::PresencesReplace(Deserialize::deserialize(d)?),
+        EventType::Ready => Event::Ready(Deserialize::deserialize(d)?),
+        EventType::Resumed => Event::Resumed(Deserialize::deserialize(d)?),
+        EventType::TypingStart => Event::TypingStart(Deserialize::deserialize(d)?),
+        EventType::UserUpdate => Event::UserUpdate(Deserialize::deserialize(d)?),
+        EventType::VoiceServerUpdate => Event::VoiceServerUpdate(Deserialize::deserialize(d)?),
+        EventType::VoiceStateUpdate => Event::VoiceStateUpdate(Deserialize::deserialize(d)?),
+        EventType::WebhookUpdate => Event::WebhookUpdate(Deserialize::deserialize(d)?),
+        EventType::InteractionCreate => Event::InteractionCreate(Deserialize::deserialize(d)?),
+        EventType::IntegrationCreate => Event::IntegrationCreate(Deserialize::deserialize(d)?),
+        EventType::IntegrationUpdate => Event::IntegrationUpdate(Deserialize::deserialize(d)?),
+        EventType::IntegrationDelete => Event::IntegrationDelete(Deserialize::deserialize(d)?),
+        EventType::StageInstanceCreate => Event::StageInstanceCreate(Deserialize::deserialize(d)?),
+        EventType::StageInstanceUpdate => Event::StageInstanceUpdate(Deserialize::deserialize(d)?),
+        EventType::StageInstanceDelete => Event::StageInstanceDelete(Deserialize::deserialize(d)?),
+        EventType::ThreadCreate => Event::ThreadCreate(Deserialize::deserialize(d)?),
+        EventType::ThreadUpdate => Event::ThreadUpdate(Deserialize::deserialize(d)?),
+        EventType::ThreadDelete => Event::ThreadDelete(Deserialize::deserialize(d)?),
+        EventType::ThreadListSync => Event::ThreadListSync(Deserialize::deserialize(d)?),
+        EventType::ThreadMemberUpdate => Event::ThreadMemberUpdate(Deserialize::deserialize(d)?),
+        EventType::ThreadMembersUpdate => Event::ThreadMembersUpdate(Deserialize::deserialize(d)?),
+        EventType::GuildScheduledEventCreate => {
+            Event::GuildScheduledEventCreate(Deserialize::deserialize(d)?)
+        },
+        EventType::GuildScheduledEventUpdate => {
+            Event::GuildScheduledEventUpdate(Deserialize::deserialize(d)?)
+        },
+        EventType::GuildScheduledEventDelete => {
+            Event::GuildScheduledEventDelete(Deserialize::deserialize(d)?)
+        },
+        EventType::GuildScheduledEventUserAdd => {
+            Event::GuildScheduledEventUserAdd(Deserialize::deserialize(d)?)
+        },
         EventType::GuildScheduledEventUserRemove => {
-            Event::GuildScheduledEventUserRemove(from_value(v)?)
+            Event::GuildScheduledEventUserRemove(Deserialize::deserialize(d)?)
         },
         EventType::Other(kind) => Event::Unknown(UnknownEvent {
             kind,
-            value: v,
+            value: Value::deserialize(d)?,
         }),
     })
 }
@@ -1858,6 +2069,10 @@ pub enum EventType {
     ///
     /// This maps to [`ReactionRemoveAllEvent`].
     ReactionRemoveAll,
+    /// Indicator that a reaction remove emoji payload was received.
+    ///
+    /// This maps to [`ReactionRemoveEmojiEvent`].
+    ReactionRemoveEmoji,
     /// Indicator that a ready payload was received.
     ///
     /// This maps to [`ReadyEvent`].
@@ -2057,6 +2272,7 @@ impl EventType {
     const MESSAGE_REACTION_ADD: &'static str = "MESSAGE_REACTION_ADD";
     const MESSAGE_REACTION_REMOVE: &'static str = "MESSAGE_REACTION_REMOVE";
     const MESSAGE_REACTION_REMOVE_ALL: &'static str = "MESSAGE_REACTION_REMOVE_ALL";
+    const MESSAGE_REACTION_REMOVE_EMOJI: &'static str = "MESSAGE_REACTION_REMOVE_EMOJI";
     const MESSAGE_UPDATE: &'static str = "MESSAGE_UPDATE";
     const PRESENCE_UPDATE: &'static str = "PRESENCE_UPDATE";
     const PRESENCES_REPLACE: &'static str = "PRESENCES_REPLACE";
@@ -2126,6 +2342,7 @@ impl EventType {
             Self::ReactionAdd => Some(Self::MESSAGE_REACTION_ADD),
             Self::ReactionRemove => Some(Self::MESSAGE_REACTION_REMOVE),
             Self::ReactionRemoveAll => Some(Self::MESSAGE_REACTION_REMOVE_ALL),
+            Self::ReactionRemoveEmoji => Some(Self::MESSAGE_REACTION_REMOVE_EMOJI),
             Self::MessageUpdate => Some(Self::MESSAGE_UPDATE),
             Self::PresenceUpdate => Some(Self::PRESENCE_UPDATE),
             Self::PresencesReplace => Some(Self::PRESENCES_REPLACE),
@@ -2220,6 +2437,7 @@ impl<'de> Deserialize<'de> for EventType {
                     EventType::MESSAGE_REACTION_ADD => EventType::ReactionAdd,
                     EventType::MESSAGE_REACTION_REMOVE => EventType::ReactionRemove,
                     EventType::MESSAGE_REACTION_REMOVE_ALL => EventType::ReactionRemoveAll,
+                    EventType::MESSAGE_REACTION_REMOVE_EMOJI => EventType::ReactionRemoveEmoji,
                     EventType::MESSAGE_UPDATE => EventType::MessageUpdate,
                     EventType::PRESENCE_UPDATE => EventType::PresenceUpdate,
                     EventType::PRESENCES_REPLACE => EventType::PresencesReplace,
@@ -2257,3 +2475,67 @@ impl<'de> Deserialize<'de> for EventType {
         deserializer.deserialize_str(EventTypeVisitor)
     }
 }
+
+#[cfg(all(test, feature = "gateway"))]
+mod tests {
+    use super::{Event, GatewayEvent};
+
+    #[test]
+    fn test_gateway_event_from_payload() {
+        let mut hello =
+            r#"{"op":10,"d":{"heartbeat_interval":41250},"s":null,"t":null}"#.to_owned();
+        assert!(matches!(GatewayEvent::from_payload(&mut hello), Ok(GatewayEvent::Hello(41250))));
+
+        let mut invalid_session = r#"{"op":9,"d":true}"#.to_owned();
+        assert!(matches!(
+            GatewayEvent::from_payload(&mut invalid_session),
+            Ok(GatewayEvent::InvalidateSession(true))
+        ));
+
+        let mut unavailable =
+            r#"{"op":0,"s":3,"t":"GUILD_DELETE","d":{"id":"1","unavailable":true}}"#.to_owned();
+        assert!(matches!(
+            GatewayEvent::from_payload(&mut unavailable),
+            Ok(GatewayEvent::Dispatch(3, Event::GuildUnavailable(_)))
+        ));
+
+        let mut unknown = r#"{"op":0,"s":4,"t":"SOME_NEW_EVENT","d":{"a":1}}"#.to_owned();
+        match GatewayEvent::from_payload(&mut unknown) {
+            Ok(GatewayEvent::Dispatch(4, Event::Unknown(event))) => {
+                assert_eq!(event.kind, "SOME_NEW_EVENT");
+                assert_eq!(event.value["a"], 1);
+            },
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let mut missing_data = r#"{"op":0,"s":5,"t":"MESSAGE_CREATE"}"#.to_owned();
+        assert!(GatewayEvent::from_payload(&mut missing_data).is_err());
+    }
+
+    #[cfg(feature = "etf")]
+    #[test]
+    fn test_gateway_event_from_etf() {
+        use crate::gateway::etf;
+        use crate::json::json;
+
+        // Snowflakes are integers in ETF payloads.
+        let payload = etf::to_vec(&json!({
+            "op": 0,
+            "s": 3,
+            "t": "GUILD_ROLE_DELETE",
+            "d": {"guild_id": 381_880_193_251_409_931_u64, "role_id": 1_031_539_564_291_411_968_u64},
+        }))
+        .unwrap();
+
+        match GatewayEvent::from_etf(&payload) {
+            Ok(GatewayEvent::Dispatch(3, Event::GuildRoleDelete(event))) => {
+                assert_eq!(event.guild_id.0, 381_880_193_251_409_931);
+                assert_eq!(event.role_id.0, 1_031_539_564_291_411_968);
+            },
+            other => panic!("unexpected result: {:?}", other),
+        }
+
+        let payload = etf::to_vec(&json!({"op": 10, "d": {"heartbeat_interval": 41250}})).unwrap();
+        assert!(matches!(GatewayEvent::from_etf(&payload), Ok(GatewayEvent::Hello(41250))));
+    }
+}
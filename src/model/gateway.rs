@@ -528,6 +528,8 @@ pub struct Ready {
     pub presences: HashMap<UserId, Presence>,
     #[serde(default, with = "private_channels")]
     pub private_channels: HashMap<ChannelId, Channel>,
+    /// The gateway URL to connect to when resuming the session.
+    pub resume_gateway_url: Option<String>,
     pub session_id: String,
     pub shard: Option<[u64; 2]>,
     #[serde(default, rename = "_trace")]
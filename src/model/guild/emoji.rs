@@ -165,15 +165,13 @@ impl Emoji {
     #[cfg(feature = "cache")]
     #[must_use]
     pub fn find_guild_id(&self, cache: impl AsRef<Cache>) -> Option<GuildId> {
-        for guild_entry in cache.as_ref().guilds.iter() {
-            let guild = guild_entry.value();
-
+        cache.as_ref().guilds.find_map(|_, guild| {
             if guild.emojis.contains_key(&self.id) {
-                return Some(guild.id);
+                Some(guild.id)
+            } else {
+                None
             }
-        }
-
-        None
+        })
     }
 
     /// Generates a URL to the emoji's image.
@@ -1715,7 +1715,12 @@ impl GuildId {
     /// Get the widget image URL.
     #[must_use]
     pub fn widget_image_url(&self, style: GuildWidgetStyle) -> String {
-        api!("/guilds/{}/widget.png?style={}", self.0, style)
+        format!(
+            "{}/guilds/{}/widget.png?style={}",
+            crate::internal::base_urls::api(),
+            self.0,
+            style
+        )
     }
 
     /// Gets the guild active threads.
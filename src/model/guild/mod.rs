@@ -262,6 +262,9 @@ pub struct Guild {
     /// All active threads in this guild that current user has permission to view.
     #[serde(default)]
     pub threads: Vec<GuildChannel>,
+    /// The scheduled events in this guild.
+    #[serde(default, rename = "guild_scheduled_events")]
+    pub scheduled_events: Vec<ScheduledEvent>,
     /// All of the guild's custom stickers.
     #[serde(serialize_with = "serialize_map_values")]
     pub stickers: HashMap<StickerId, Sticker>,
@@ -2840,37 +2843,35 @@ impl<'de> Deserialize<'de> for Guild {
             .and_then(String::deserialize)
             .map_err(DeError::custom)?;
 
-        let welcome_screen = map
-            .remove("welcome_screen")
-            .map(GuildWelcomeScreen::deserialize)
-            .transpose()
-            .map_err(DeError::custom)?;
+        let welcome_screen = match map.remove("welcome_screen") {
+            Some(v) => Option::<GuildWelcomeScreen>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
 
-        let approximate_member_count = map
-            .remove("approximate_member_count")
-            .map(u64::deserialize)
-            .transpose()
-            .map_err(DeError::custom)?;
+        let approximate_member_count = match map.remove("approximate_member_count") {
+            Some(v) => Option::<u64>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
 
-        let approximate_presence_count = map
-            .remove("approximate_presence_count")
-            .map(u64::deserialize)
-            .transpose()
-            .map_err(DeError::custom)?;
+        let approximate_presence_count = match map.remove("approximate_presence_count") {
+            Some(v) => Option::<u64>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
 
-        let max_video_channel_users = map
-            .remove("max_video_channel_users")
-            .map(u64::deserialize)
-            .transpose()
-            .map_err(DeError::custom)?;
+        let max_video_channel_users = match map.remove("max_video_channel_users") {
+            Some(v) => Option::<u64>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
 
         let max_presences = match map.remove("max_presences") {
             Some(v) => Option::<u64>::deserialize(v).map_err(DeError::custom)?,
             None => None,
         };
 
-        let max_members =
-            map.remove("max_members").map(u64::deserialize).transpose().map_err(DeError::custom)?;
+        let max_members = match map.remove("max_members") {
+            Some(v) => Option::<u64>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
 
         let discovery_splash = match map.remove("discovery_splash") {
             Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
@@ -2919,6 +2920,11 @@ impl<'de> Deserialize<'de> for Guild {
             None => Vec::new(),
         };
 
+        let scheduled_events = match map.remove("guild_scheduled_events") {
+            Some(v) => Vec::<ScheduledEvent>::deserialize(v).map_err(DeError::custom)?,
+            None => Vec::new(),
+        };
+
         let stickers = map
             .remove("stickers")
             .ok_or_else(|| DeError::custom("expected guild stickers"))
@@ -2970,6 +2976,7 @@ impl<'de> Deserialize<'de> for Guild {
             widget_channel_id,
             stage_instances,
             threads,
+            scheduled_events,
             stickers,
         })
     }
@@ -3334,6 +3341,7 @@ mod test {
                 public_updates_channel_id: None,
                 stage_instances: vec![],
                 threads: vec![],
+                scheduled_events: vec![],
                 stickers: hm7,
             }
         }
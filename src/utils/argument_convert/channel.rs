@@ -51,8 +51,7 @@ async fn lookup_channel_global(
     }
 
     #[cfg(feature = "cache")]
-    if let Some(channel) = ctx.cache.channels.iter().find_map(|m| {
-        let channel = m.value();
+    if let Some(channel) = ctx.cache.channels.find_map(|_, channel| {
         if channel.name.eq_ignore_ascii_case(s) {
             Some(channel.clone())
         } else {
@@ -44,8 +44,7 @@ impl ArgumentConvert for Guild {
         let lookup_by_id = || guilds.get(&GuildId(s.parse().ok()?)).map(|g| g.clone());
 
         let lookup_by_name = || {
-            guilds.iter().find_map(|m| {
-                let guild = m.value();
+            guilds.find_map(|_, guild| {
                 if guild.name.eq_ignore_ascii_case(s) {
                     Some(guild.clone())
                 } else {
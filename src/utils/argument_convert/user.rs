@@ -35,8 +35,7 @@ fn lookup_by_global_cache(ctx: &Context, s: &str) -> Option<User> {
 
     let lookup_by_name_and_discrim = || {
         let (name, discrim) = crate::utils::parse_user_tag(s)?;
-        users.iter().find_map(|m| {
-            let user = m.value();
+        users.find_map(|_, user| {
             if user.discriminator == discrim && user.name.eq_ignore_ascii_case(name) {
                 Some(user.clone())
             } else {
@@ -46,8 +45,7 @@ fn lookup_by_global_cache(ctx: &Context, s: &str) -> Option<User> {
     };
 
     let lookup_by_name = || {
-        users.iter().find_map(|m| {
-            let user = m.value();
+        users.find_map(|_, user| {
             if user.name == s {
                 Some(user.clone())
             } else {
@@ -352,6 +352,7 @@ mod tests {
             widget_channel_id: None,
             stage_instances: vec![],
             threads: vec![],
+            scheduled_events: vec![],
             stickers: HashMap::new(),
         };
 
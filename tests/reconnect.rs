@@ -3,7 +3,6 @@ use std::{sync::{Arc, Mutex}, env, time::Duration};
 use serenity::{prelude::{GatewayIntents, EventHandler, Context}, http::{HttpBuilder, Http}, client::ClientBuilder, model::prelude::{Message, ChannelId}, async_trait};
 
 use rand::prelude::*;
-use std::sync::atomic::AtomicU64;
 
 const MINI_WAIT: u64 = 1;
 
@@ -73,13 +72,9 @@ async fn generic(when_to_send_message: WhenSendMessage, resume: bool, test_messa
     }
 
     let messages = Arc::new(Mutex::new(Vec::new()));
-    let session_id = Arc::new(Mutex::new(None));
-    let seq_num = Arc::new(AtomicU64::new(0));
 
     let mut discord_client = ClientBuilder::new_with_http(new_http(), intents)
         .event_handler(MyHandler { messages: messages.clone() })
-        .session_id(Arc::clone(&session_id))
-        .seq_num(Arc::clone(&seq_num))
         .await
         .expect("Error creating client");
 
@@ -108,10 +103,8 @@ async fn generic(when_to_send_message: WhenSendMessage, resume: bool, test_messa
     tokio::time::sleep(Duration::from_secs(3)).await;
 
     //tokio::time::sleep(Duration::from_secs(1)).await;
-    // Stop the client.
-    shard_manager.lock().await.shutdown_all().await;
-
-    //let state = ???;
+    // Stop the client, keeping the sessions resumable.
+    let resume_info = shard_manager.lock().await.suspend_all().await;
 
     tokio::time::sleep(Duration::from_secs(MINI_WAIT)).await;
     if matches!(when_to_send_message, WhenSendMessage::StartedThenStopped) {
@@ -122,8 +115,7 @@ async fn generic(when_to_send_message: WhenSendMessage, resume: bool, test_messa
     if resume {
         let mut discord_client = ClientBuilder::new_with_http(new_http(), intents)
             .event_handler(MyHandler { messages: messages.clone() })
-            .session_id(session_id)
-            .seq_num(seq_num)
+            .resume_shards(resume_info)
             .await
             .expect("Error resuming client");
 